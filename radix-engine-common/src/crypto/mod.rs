@@ -1,11 +1,17 @@
 mod blake2b;
 mod hash;
+mod keccak256;
 mod public_key;
+mod public_key_bls12381;
 mod public_key_ecdsa_secp256k1;
 mod public_key_eddsa_ed25519;
+mod sha256;
 
 pub use self::blake2b::*;
 pub use self::hash::*;
+pub use self::keccak256::*;
 pub use self::public_key::*;
+pub use self::public_key_bls12381::*;
 pub use self::public_key_ecdsa_secp256k1::*;
 pub use self::public_key_eddsa_ed25519::*;
+pub use self::sha256::*;
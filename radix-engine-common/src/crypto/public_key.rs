@@ -12,6 +12,7 @@ use sbor::*;
 pub enum PublicKey {
     EcdsaSecp256k1(EcdsaSecp256k1PublicKey),
     EddsaEd25519(EddsaEd25519PublicKey),
+    Bls12381(Bls12381G1PublicKey),
 }
 
 impl From<EcdsaSecp256k1PublicKey> for PublicKey {
@@ -25,3 +26,9 @@ impl From<EddsaEd25519PublicKey> for PublicKey {
         Self::EddsaEd25519(public_key)
     }
 }
+
+impl From<Bls12381G1PublicKey> for PublicKey {
+    fn from(public_key: Bls12381G1PublicKey) -> Self {
+        Self::Bls12381(public_key)
+    }
+}
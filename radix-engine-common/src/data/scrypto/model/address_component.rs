@@ -1,5 +1,5 @@
 use crate::address::{AddressDisplayContext, AddressError, EntityType, NO_NETWORK};
-use crate::crypto::{hash, PublicKey};
+use crate::crypto::{hash, EcdsaSecp256k1PublicKey, EddsaEd25519PublicKey, PublicKey};
 use crate::data::manifest::ManifestCustomValueKind;
 use crate::data::scrypto::*;
 use crate::well_known_scrypto_custom_type;
@@ -111,37 +111,70 @@ impl ComponentAddress {
         Self::try_from(bytes.as_ref())
     }
 
-    pub fn virtual_account_from_public_key<P: Into<PublicKey> + Clone>(
+    /// Returns the address of the virtual account controlled by the given public key.
+    pub fn virtual_account_from_public_key<P: VirtualEntityPublicKey>(
         public_key: &P,
     ) -> ComponentAddress {
-        match public_key.clone().into() {
-            PublicKey::EcdsaSecp256k1(public_key) => {
-                ComponentAddress::EcdsaSecp256k1VirtualAccount(
-                    hash(public_key.to_vec()).lower_26_bytes(),
-                )
-            }
-            PublicKey::EddsaEd25519(public_key) => ComponentAddress::EddsaEd25519VirtualAccount(
-                hash(public_key.to_vec()).lower_26_bytes(),
-            ),
-        }
+        public_key.virtual_account_address()
     }
 
-    pub fn virtual_identity_from_public_key<P: Into<PublicKey> + Clone>(
+    /// Returns the address of the virtual identity controlled by the given public key.
+    pub fn virtual_identity_from_public_key<P: VirtualEntityPublicKey>(
         public_key: &P,
     ) -> ComponentAddress {
-        match public_key.clone().into() {
-            PublicKey::EcdsaSecp256k1(public_key) => {
-                ComponentAddress::EcdsaSecp256k1VirtualIdentity(
-                    hash(public_key.to_vec()).lower_26_bytes(),
-                )
-            }
-            PublicKey::EddsaEd25519(public_key) => ComponentAddress::EddsaEd25519VirtualIdentity(
-                hash(public_key.to_vec()).lower_26_bytes(),
-            ),
+        public_key.virtual_identity_address()
+    }
+
+    /// Returns the address of the virtual account controlled by the given public key, or `None`
+    /// if it is a BLS12-381 key (as there are no virtual accounts for those).
+    pub fn try_virtual_account_from_public_key(public_key: &PublicKey) -> Option<ComponentAddress> {
+        match public_key {
+            PublicKey::EcdsaSecp256k1(public_key) => Some(public_key.virtual_account_address()),
+            PublicKey::EddsaEd25519(public_key) => Some(public_key.virtual_account_address()),
+            PublicKey::Bls12381(..) => None,
+        }
+    }
+
+    /// Returns the address of the virtual identity controlled by the given public key, or `None`
+    /// if it is a BLS12-381 key (as there are no virtual identities for those).
+    pub fn try_virtual_identity_from_public_key(
+        public_key: &PublicKey,
+    ) -> Option<ComponentAddress> {
+        match public_key {
+            PublicKey::EcdsaSecp256k1(public_key) => Some(public_key.virtual_identity_address()),
+            PublicKey::EddsaEd25519(public_key) => Some(public_key.virtual_identity_address()),
+            PublicKey::Bls12381(..) => None,
         }
     }
 }
 
+/// A public key type which can control virtual accounts and identities.
+pub trait VirtualEntityPublicKey {
+    fn virtual_account_address(&self) -> ComponentAddress;
+
+    fn virtual_identity_address(&self) -> ComponentAddress;
+}
+
+impl VirtualEntityPublicKey for EcdsaSecp256k1PublicKey {
+    fn virtual_account_address(&self) -> ComponentAddress {
+        ComponentAddress::EcdsaSecp256k1VirtualAccount(hash(self.to_vec()).lower_26_bytes())
+    }
+
+    fn virtual_identity_address(&self) -> ComponentAddress {
+        ComponentAddress::EcdsaSecp256k1VirtualIdentity(hash(self.to_vec()).lower_26_bytes())
+    }
+}
+
+impl VirtualEntityPublicKey for EddsaEd25519PublicKey {
+    fn virtual_account_address(&self) -> ComponentAddress {
+        ComponentAddress::EddsaEd25519VirtualAccount(hash(self.to_vec()).lower_26_bytes())
+    }
+
+    fn virtual_identity_address(&self) -> ComponentAddress {
+        ComponentAddress::EddsaEd25519VirtualIdentity(hash(self.to_vec()).lower_26_bytes())
+    }
+}
+
 //========
 // binary
 //========
@@ -9,6 +9,11 @@ use sbor::rust::prelude::*;
 #[sbor(transparent)]
 pub struct ComponentStateSubstate(pub ScryptoValue);
 
+/// The package version a component's state was last migrated to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+#[sbor(transparent)]
+pub struct ComponentStateVersionSubstate(pub u32);
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct ComponentRoyaltyConfigSubstate {
     pub royalty_config: RoyaltyConfig,
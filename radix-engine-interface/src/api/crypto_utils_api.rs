@@ -0,0 +1,56 @@
+use radix_engine_common::crypto::{
+    Bls12381G1PublicKey, EcdsaSecp256k1PublicKey, EddsaEd25519PublicKey, Hash,
+};
+use sbor::rust::vec::Vec;
+
+/// Cryptographic primitives, run natively rather than in WASM.
+pub trait ClientCryptoUtilsApi<E> {
+    /// Verifies an ECDSA secp256k1 signature of the given message, which is hashed with `hash()`
+    /// first, just like a transaction is before being signed.
+    ///
+    /// The signature is the 65 bytes Radix signs transactions with: the recovery id first,
+    /// followed by the 64-byte compact `(r, s)`. Note this differs from the Ethereum layout,
+    /// which puts the recovery id (`v`) last. Malformed signatures are reported as invalid.
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message: Vec<u8>,
+        public_key: EcdsaSecp256k1PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, E>;
+
+    /// Verifies a 64-byte EdDSA Ed25519 signature of the given message, which is hashed with
+    /// `hash()` first, just like a transaction is before being signed. Malformed signatures are
+    /// reported as invalid.
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: EddsaEd25519PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, E>;
+
+    /// Verifies a BLS12-381 G2 signature of the given message hash. Malformed signatures are
+    /// reported as invalid.
+    fn verify_bls12381(
+        &mut self,
+        message_hash: Hash,
+        public_key: Bls12381G1PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, E>;
+
+    /// Verifies an aggregate BLS12-381 G2 signature of the same message hash by each of the given
+    /// public keys, each paired with a proof of its possession (i.e. a G2 signature of the key
+    /// itself, under the proof-of-possession ciphersuite). Invalid proofs, as well as malformed
+    /// signatures or keys, are reported as invalid.
+    fn aggregate_verify_bls12381(
+        &mut self,
+        message_hash: Hash,
+        public_keys: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+        signature: Vec<u8>,
+    ) -> Result<bool, E>;
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Hash, E>;
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Hash, E>;
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Hash, E>;
+}
@@ -2,7 +2,11 @@ use crate::api::types::*;
 use crate::blueprints::resource::LiquidFungibleResource;
 
 pub trait ClientCostingApi<E> {
-    fn consume_cost_units(&mut self, units: u32, reason: ClientCostingReason) -> Result<(), E>;
+    fn consume_cost_units(
+        &mut self,
+        units: ClientCostUnits,
+        reason: ClientCostingReason,
+    ) -> Result<(), E>;
 
     fn credit_cost_units(
         &mut self,
@@ -1,6 +1,15 @@
 use crate::api::types::Level;
 use crate::sbor::rust::string::String;
+use crate::sbor::rust::vec::Vec;
 
 pub trait ClientLoggerApi<E> {
     fn log_message(&mut self, level: Level, message: String) -> Result<(), E>;
+
+    /// Logs a message along with key/value fields (e.g. for indexing by log processors).
+    fn log_structured_message(
+        &mut self,
+        level: Level,
+        message: String,
+        fields: Vec<(String, String)>,
+    ) -> Result<(), E>;
 }
@@ -56,4 +56,24 @@ pub trait ClientSubstateApi<E: Debug> {
         self.sys_write_substate(lock_handle, buf)
     }
     fn sys_drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), E>;
+
+    /// Removes an entry from a key-value store, returning the value it had (an encoded
+    /// `Option<ScryptoValue>`, which is `None` if there was no such entry)
+    fn sys_remove_key_value_store_entry(
+        &mut self,
+        node_id: RENodeId,
+        key: Vec<u8>,
+    ) -> Result<Vec<u8>, E>;
+
+    /// Lists the keys of the entries of a key-value store, in ascending order of their encoding,
+    /// starting from the given key (inclusive, if given), up to the given limit
+    fn sys_list_key_value_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        from: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<Vec<u8>>, E>;
+
+    /// Returns the number of entries of a key-value store
+    fn sys_count_key_value_store_entries(&mut self, node_id: RENodeId) -> Result<u32, E>;
 }
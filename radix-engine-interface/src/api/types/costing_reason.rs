@@ -4,3 +4,13 @@ pub enum ClientCostingReason {
     RunNative,
     RunSystem,
 }
+
+/// The cost units to consume, either as an exact amount or as one of the fixed fee tiers, which
+/// are priced by the fee table in effect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClientCostUnits {
+    Exact(u32),
+    FixedLowFee,
+    FixedMediumFee,
+    FixedHighFee,
+}
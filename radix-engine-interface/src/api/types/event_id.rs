@@ -25,6 +25,16 @@ pub enum Emitter {
     Method(RENodeId, NodeModuleId),
 }
 
+impl Emitter {
+    /// Returns the RENode which emitted the event: the object for methods, or the package for
+    /// functions.
+    pub fn node_id(&self) -> &RENodeId {
+        match self {
+            Self::Function(node_id, ..) | Self::Method(node_id, ..) => node_id,
+        }
+    }
+}
+
 impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for Emitter {
     type Error = fmt::Error;
 
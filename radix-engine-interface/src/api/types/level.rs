@@ -3,7 +3,8 @@ use sbor::rust::fmt;
 use sbor::rust::fmt::Debug;
 
 /// Represents the level of a log message.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Sbor)]
+/// Levels are ordered from the most severe (`Error`) to the most verbose (`Trace`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Sbor)]
 pub enum Level {
     Error,
     Warn,
@@ -196,6 +196,9 @@ pub enum RoyaltyOffset {
 pub enum ComponentOffset {
     /// Component application state at offset `0x00`.
     State0,
+    /// Version of the package code the application state was last migrated to. Only present
+    /// for components of upgradeable packages.
+    StateVersion,
 }
 
 #[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -231,6 +234,7 @@ pub enum EpochManagerOffset {
     EpochManager,
     CurrentValidatorSet,
     PreparingValidatorSet,
+    FeeTable,
 }
 
 #[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord)]
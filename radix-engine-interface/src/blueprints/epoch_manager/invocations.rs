@@ -79,6 +79,8 @@ pub struct EpochManagerSetEpochInput {
 
 pub type EpochManagerSetEpochOutput = ();
 
+pub const EPOCH_MANAGER_SET_FEE_TABLE_IDENT: &str = "set_fee_table";
+
 pub const EPOCH_MANAGER_NEXT_ROUND_IDENT: &str = "next_round";
 
 #[derive(Debug, Clone, Eq, PartialEq, Sbor)]
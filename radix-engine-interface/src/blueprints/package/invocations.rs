@@ -23,6 +23,24 @@ pub struct PackagePublishWasmInput {
 
 pub type PackagePublishWasmOutput = PackageAddress;
 
+pub const PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT: &str = "publish_wasm_upgradeable";
+
+pub type PackagePublishWasmUpgradeableInput = PackagePublishWasmInput;
+
+pub type PackagePublishWasmUpgradeableOutput = PackageAddress;
+
+pub const PACKAGE_UPGRADE_WASM_IDENT: &str = "upgrade_wasm";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct PackageUpgradeWasmInput {
+    pub code: Vec<u8>,
+    pub schema: PackageSchema,
+    /// Per blueprint, the function which migrates component state from the previous version.
+    pub migrations: BTreeMap<String, String>,
+}
+
+pub type PackageUpgradeWasmOutput = ();
+
 pub const PACKAGE_PUBLISH_NATIVE_IDENT: &str = "publish_native";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
@@ -43,6 +43,37 @@ pub struct PackageInfoSubstate {
     pub schema: PackageSchema,
     pub dependent_resources: BTreeSet<ResourceAddress>,
     pub dependent_components: BTreeSet<ComponentAddress>,
+    /// Present only for packages published as upgradeable.
+    pub upgrade_info: Option<PackageUpgradeInfo>,
+}
+
+/// Upgrade history of an upgradeable package.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct PackageUpgradeInfo {
+    /// The version of the current code, starting at `0` on publish.
+    pub version: u32,
+    /// Migrations per blueprint, keyed by the version they migrate component state from.
+    pub migrations: BTreeMap<String, BTreeMap<u32, PackageMigration>>,
+}
+
+impl PackageUpgradeInfo {
+    pub fn migration(&self, blueprint: &str, from_version: u32) -> Option<&String> {
+        self.migrations
+            .get(blueprint)
+            .and_then(|migrations| migrations.get(&from_version))
+            .map(|migration| &migration.function)
+    }
+}
+
+/// A migration function, along with the state types it converts between (which later code has
+/// to keep accepting and returning, for the components which haven't been migrated yet).
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct PackageMigration {
+    pub function: String,
+    pub old_state_schema: ScryptoSchema,
+    pub old_state_type: LocalTypeIndex,
+    pub new_state_schema: ScryptoSchema,
+    pub new_state_type: LocalTypeIndex,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -2,6 +2,7 @@ use crate::api::node_modules::metadata::*;
 use crate::api::types::NodeModuleId;
 use crate::blueprints::package::PACKAGE_CLAIM_ROYALTY_IDENT;
 use crate::blueprints::package::PACKAGE_SET_ROYALTY_CONFIG_IDENT;
+use crate::blueprints::package::PACKAGE_UPGRADE_WASM_IDENT;
 use crate::blueprints::resource::*;
 use crate::rule;
 use crate::*;
@@ -253,6 +254,11 @@ pub fn package_access_rules_from_owner_badge(
         rule!(require(owner_badge.clone())),
         rule!(require(owner_badge.clone())),
     );
+    access_rules.set_access_rule_and_mutability(
+        MethodKey::new(NodeModuleId::SELF, PACKAGE_UPGRADE_WASM_IDENT.to_string()),
+        rule!(require(owner_badge.clone())),
+        rule!(require(owner_badge.clone())),
+    );
     access_rules
 }
 
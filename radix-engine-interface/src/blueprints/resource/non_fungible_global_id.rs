@@ -128,6 +128,10 @@ impl FromPublicKey for NonFungibleGlobalId {
                 EDDSA_ED25519_TOKEN,
                 NonFungibleLocalId::bytes(hash(public_key.to_vec()).lower_26_bytes()).unwrap(),
             ),
+            PublicKey::Bls12381(public_key) => NonFungibleGlobalId::new(
+                BLS12381_TOKEN,
+                NonFungibleLocalId::bytes(hash(public_key.to_vec()).lower_26_bytes()).unwrap(),
+            ),
         }
     }
 }
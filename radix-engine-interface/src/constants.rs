@@ -11,6 +11,7 @@ pub const SYSTEM_TOKEN: ResourceAddress = vanity_address!(EntityType::NonFungibl
 pub const PACKAGE_TOKEN: ResourceAddress = vanity_address!(EntityType::NonFungibleResource, 3);
 pub const OLYMPIA_VALIDATOR_TOKEN: ResourceAddress =
     vanity_address!(EntityType::NonFungibleResource, 4);
+pub const BLS12381_TOKEN: ResourceAddress = vanity_address!(EntityType::NonFungibleResource, 5);
 
 /// The address of the faucet package.
 pub const PACKAGE_PACKAGE: PackageAddress = vanity_address!(EntityType::Package, 0);
@@ -0,0 +1,310 @@
+use radix_engine::state_manager::{CommitSubscriber, CommittedTransaction};
+use radix_engine::types::*;
+use radix_engine_interface::api::types::RENodeId;
+
+/// The position of an event in the ledger: the state version reached by committing the
+/// transaction which emitted it, and its index among that transaction's events.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, ScryptoSbor)]
+pub struct EventCursor {
+    pub state_version: u64,
+    pub index: u32,
+}
+
+impl EventCursor {
+    /// The length of `to_bytes()`.
+    pub const LENGTH: usize = 12;
+
+    /// The position of the first event committed at or after the given state version.
+    pub fn at_state_version(state_version: u64) -> Self {
+        Self {
+            state_version,
+            index: 0,
+        }
+    }
+
+    /// Encodes the cursor in big-endian, so that the encodings sort like the cursors.
+    pub fn to_bytes(&self) -> [u8; Self::LENGTH] {
+        let mut bytes = [0u8; Self::LENGTH];
+        bytes[..8].copy_from_slice(&self.state_version.to_be_bytes());
+        bytes[8..].copy_from_slice(&self.index.to_be_bytes());
+        bytes
+    }
+
+    /// Decodes a cursor encoded by `to_bytes()`.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            state_version: u64::from_be_bytes(bytes[..8].try_into().unwrap()),
+            index: u32::from_be_bytes(bytes[8..Self::LENGTH].try_into().unwrap()),
+        }
+    }
+}
+
+/// An application event, as kept by an `EventStore`.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct StoredEvent {
+    pub cursor: EventCursor,
+    pub transaction_hash: Hash,
+    pub event_type_identifier: EventTypeIdentifier,
+    pub event_data: Vec<u8>,
+}
+
+impl StoredEvent {
+    /// Returns the events emitted by the committed transaction, in emission order.
+    pub fn from_committed_transaction(committed_transaction: &CommittedTransaction) -> Vec<Self> {
+        committed_transaction
+            .application_events
+            .iter()
+            .enumerate()
+            .map(|(index, (event_type_identifier, event_data))| Self {
+                cursor: EventCursor {
+                    state_version: committed_transaction.state_version,
+                    index: index as u32,
+                },
+                transaction_hash: committed_transaction.transaction_hash,
+                event_type_identifier: event_type_identifier.clone(),
+                event_data: event_data.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Selects a page of the stored events, in ledger order. All the criteria that are set must
+/// match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventQuery {
+    pub emitter: Option<RENodeId>,
+    pub event_type: Option<EventTypeIdentifier>,
+    /// The position of the first event to return (inclusive).
+    pub from: EventCursor,
+    /// The last state version to return events of (inclusive).
+    pub to_state_version: Option<u64>,
+    /// The maximum number of events to return; a limit of 0 is treated as 1, so that following
+    /// the `next_cursor` of each page always makes progress.
+    pub limit: usize,
+}
+
+impl EventQuery {
+    /// Selects the first `limit` events of the ledger.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            emitter: None,
+            event_type: None,
+            from: EventCursor::at_state_version(0),
+            to_state_version: None,
+            limit,
+        }
+    }
+
+    /// Only selects the events emitted by the given node (the object for method emitters, or
+    /// the package for function emitters).
+    pub fn emitter(mut self, node_id: RENodeId) -> Self {
+        self.emitter = Some(node_id);
+        self
+    }
+
+    /// Only selects the events of the given type.
+    pub fn event_type(mut self, event_type_identifier: EventTypeIdentifier) -> Self {
+        self.event_type = Some(event_type_identifier);
+        self
+    }
+
+    /// Only selects the events committed at or after the given state version.
+    pub fn since(mut self, state_version: u64) -> Self {
+        self.from = EventCursor::at_state_version(state_version);
+        self
+    }
+
+    /// Only selects the events committed at or before the given state version.
+    pub fn until(mut self, state_version: u64) -> Self {
+        self.to_state_version = Some(state_version);
+        self
+    }
+
+    /// Starts from the given position, e.g. the `next_cursor` of a previous page.
+    pub fn from_cursor(mut self, cursor: EventCursor) -> Self {
+        self.from = cursor;
+        self
+    }
+
+    /// Returns the key prefix of the index to serve the query from, or `None` if the query has to
+    /// be served from all the events (see `EventIndex`).
+    fn index_prefix(&self) -> Option<(EventIndex, Vec<u8>)> {
+        if let Some(event_type) = &self.event_type {
+            Some((
+                EventIndex::ByType,
+                encode_event_type_index_prefix(event_type),
+            ))
+        } else {
+            self.emitter
+                .as_ref()
+                .map(|node_id| (EventIndex::ByEmitter, encode_emitter_index_prefix(node_id)))
+        }
+    }
+
+    /// Returns whether the query cannot match any event, regardless of the store's contents.
+    fn is_contradictory(&self) -> bool {
+        match (&self.emitter, &self.event_type) {
+            (Some(node_id), Some(event_type)) => event_type.0.node_id() != node_id,
+            _ => false,
+        }
+    }
+}
+
+/// A page of the events selected by an `EventQuery`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventPage {
+    pub events: Vec<StoredEvent>,
+    /// The position of the first event of the next page, or `None` if this is the last page.
+    pub next_cursor: Option<EventCursor>,
+}
+
+/// A store of the application events of the committed transactions, indexed by emitter node,
+/// event type and state version.
+pub trait EventStore {
+    fn query_events(&self, query: &EventQuery) -> EventPage;
+}
+
+/// The indices maintained by every `EventStore`, next to the events themselves (which are keyed
+/// by `EventCursor::to_bytes()`).
+/// An index entry's key is the encoding of the indexed value, followed by the cursor of the
+/// event. Since SBOR encodings are self-delimiting, the entries of a single value are adjacent
+/// and ordered by cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventIndex {
+    ByEmitter,
+    ByType,
+}
+
+pub fn encode_emitter_index_prefix(node_id: &RENodeId) -> Vec<u8> {
+    scrypto_encode(node_id).expect("Could not encode emitter")
+}
+
+pub fn encode_event_type_index_prefix(event_type_identifier: &EventTypeIdentifier) -> Vec<u8> {
+    scrypto_encode(event_type_identifier).expect("Could not encode event type")
+}
+
+/// Returns the keys of the index entries of the given event (see `EventIndex`).
+pub fn encode_event_index_keys(event: &StoredEvent) -> Vec<(EventIndex, Vec<u8>)> {
+    let cursor = event.cursor.to_bytes();
+    let mut emitter_key = encode_emitter_index_prefix(event.event_type_identifier.0.node_id());
+    emitter_key.extend_from_slice(&cursor);
+    let mut type_key = encode_event_type_index_prefix(&event.event_type_identifier);
+    type_key.extend_from_slice(&cursor);
+    vec![
+        (EventIndex::ByEmitter, emitter_key),
+        (EventIndex::ByType, type_key),
+    ]
+}
+
+/// Implements `EventStore::query_events()` on top of ordered key-value collections.
+///
+/// `iterate_index` has to return the keys of the given index from the given key (inclusive),
+/// in order; `iterate_events` has to return the events from the given cursor (inclusive), in
+/// order; `get_event` has to return the event at the given cursor.
+pub fn select_events<I, E>(
+    query: &EventQuery,
+    iterate_index: impl FnOnce(EventIndex, Vec<u8>) -> I,
+    iterate_events: impl FnOnce(EventCursor) -> E,
+    get_event: impl Fn(EventCursor) -> StoredEvent,
+) -> EventPage
+where
+    I: Iterator<Item = Vec<u8>>,
+    E: Iterator<Item = StoredEvent>,
+{
+    if query.is_contradictory() {
+        return EventPage {
+            events: Vec::new(),
+            next_cursor: None,
+        };
+    }
+
+    match query.index_prefix() {
+        Some((index, prefix)) => {
+            let mut start = prefix.clone();
+            start.extend_from_slice(&query.from.to_bytes());
+            let events = iterate_index(index, start)
+                .take_while(|key| key.starts_with(&prefix))
+                .map(|key| {
+                    get_event(EventCursor::from_bytes(
+                        &key[key.len() - EventCursor::LENGTH..],
+                    ))
+                });
+            paginate(query, events)
+        }
+        None => paginate(query, iterate_events(query.from)),
+    }
+}
+
+fn paginate<I: Iterator<Item = StoredEvent>>(query: &EventQuery, events: I) -> EventPage {
+    let limit = query.limit.max(1);
+    let mut page = Vec::new();
+    for event in events {
+        if let Some(to_state_version) = query.to_state_version {
+            if event.cursor.state_version > to_state_version {
+                break;
+            }
+        }
+        if page.len() == limit {
+            return EventPage {
+                events: page,
+                next_cursor: Some(event.cursor),
+            };
+        }
+        page.push(event);
+    }
+    EventPage {
+        events: page,
+        next_cursor: None,
+    }
+}
+
+/// An event store which keeps all the events in host memory.
+/// It is filled by subscribing it to a `CommitFeed` (or by replaying a `CommitLogStore` into it).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InMemoryEventStore {
+    events: BTreeMap<EventCursor, StoredEvent>,
+    emitter_index: BTreeSet<Vec<u8>>,
+    type_index: BTreeSet<Vec<u8>>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CommitSubscriber for InMemoryEventStore {
+    fn on_commit(&mut self, committed_transaction: &CommittedTransaction) {
+        for event in StoredEvent::from_committed_transaction(committed_transaction) {
+            for (index, key) in encode_event_index_keys(&event) {
+                match index {
+                    EventIndex::ByEmitter => self.emitter_index.insert(key),
+                    EventIndex::ByType => self.type_index.insert(key),
+                };
+            }
+            self.events.insert(event.cursor, event);
+        }
+    }
+}
+
+impl EventStore for InMemoryEventStore {
+    fn query_events(&self, query: &EventQuery) -> EventPage {
+        select_events(
+            query,
+            |index, start| {
+                let index = match index {
+                    EventIndex::ByEmitter => &self.emitter_index,
+                    EventIndex::ByType => &self.type_index,
+                };
+                index.range(start..).cloned()
+            },
+            |from| self.events.range(from..).map(|(_, event)| event.clone()),
+            |cursor| {
+                self.events
+                    .get(&cursor)
+                    .cloned()
+                    .expect("Indexed event not found")
+            },
+        )
+    }
+}
@@ -1,21 +1,25 @@
 use jellyfish::JellyfishMerkleTree;
+use radix_engine::ledger::OutputId;
 use radix_engine_interface::api::types::{NodeModuleId, RENodeId, SubstateId, SubstateOffset};
 use radix_engine_interface::crypto::{hash, Hash};
 use radix_engine_interface::data::scrypto::scrypto_encode;
 use radix_engine_interface::*;
-use sbor::rust::collections::{index_map_new, IndexMap};
+use sbor::rust::collections::{index_map_new, HashSet, IndexMap};
 use sbor::rust::vec::Vec;
 use tree_store::{
-    Payload, ReNodeModulePayload, ReadableTreeStore, TreeNode, TreeStore, WriteableTreeStore,
+    Payload, PrunableTreeStore, ReNodeModulePayload, ReadableTreeStore, TreeNode, TreeStore,
+    WriteableTreeStore,
 };
 use types::{NibblePath, NodeKey, Version};
 
+// Re-exports
+pub use types::{ProofVerificationError, SparseMerkleLeafNode, SparseMerkleProof};
+
 pub mod hash_tree_facade;
 pub mod tree_store;
 
-// The sources copied from Aptos (the `jellyfish` and `types` modules) contain support for
-// generating proofs, which we plan to use in near future. Hence, we do not delete that code, but
-// suppress warnings.
+// The sources copied from Aptos (the `jellyfish` and `types` modules) contain more features than
+// we currently use (e.g. range proofs). Hence, we do not delete that code, but suppress warnings.
 
 #[allow(dead_code)]
 mod jellyfish;
@@ -44,6 +48,30 @@ impl<I, V> IdChange<I, V> {
 /// A top-level `IdChange`, representing an actual change of a specific substate's hashed value.
 pub type SubstateHashChange = IdChange<SubstateId, Hash>;
 
+/// Translates the substates brought down (`inputs`) and up (`outputs`) by a commit into the
+/// changes of the state hash tree's leaves.
+/// A substate which went down without coming back up is deleted from the tree.
+pub fn to_substate_hash_changes(
+    inputs: &[OutputId],
+    outputs: &[OutputId],
+) -> Vec<SubstateHashChange> {
+    let up_substate_ids = outputs
+        .iter()
+        .map(|output_id| &output_id.substate_id)
+        .collect::<HashSet<&SubstateId>>();
+    let deletes = inputs
+        .iter()
+        .filter(|output_id| !up_substate_ids.contains(&output_id.substate_id))
+        .map(|output_id| SubstateHashChange::new(output_id.substate_id.clone(), None));
+    outputs
+        .iter()
+        .map(|output_id| {
+            SubstateHashChange::new(output_id.substate_id.clone(), Some(output_id.substate_hash))
+        })
+        .chain(deletes)
+        .collect()
+}
+
 /// Inserts a new set of nodes at version `current_version` + 1 into the "nested JMT" persisted
 /// within the given `store`.
 /// In a traditional JMT, this inserts a new leaf node for each given "change", together with an
@@ -74,6 +102,138 @@ pub fn put_at_next_version<S: TreeStore<ReNodeModulePayload> + TreeStore<Substat
     put_re_node_changes(store, current_version, nested_root_changes)
 }
 
+/// Physically deletes all tree nodes which are not needed for reading any state version greater
+/// or equal to the given `oldest_retained_version` (i.e. the nodes which became stale at or before
+/// that version, according to the stale node index maintained by the `store`).
+/// After this operation, the versions older than `oldest_retained_version` cannot be read (nor
+/// proven) anymore.
+/// Returns the number of deleted nodes.
+pub fn prune_stale_nodes<S: PrunableTreeStore>(
+    store: &mut S,
+    oldest_retained_version: Version,
+) -> usize {
+    store.delete_stale_nodes(oldest_retained_version)
+}
+
+/// A Merkle proof of a specific substate's hashed value being (or not being) a part of the state
+/// represented by a root hash of the "nested JMT".
+/// The proof consists of 2 layers, mirroring the structure of the tree: the upper-layer proof
+/// establishes the ReNodeModule's leaf (i.e. the root hash of its nested tree), and the
+/// nested-layer proof establishes the substate's leaf within that nested tree.
+#[derive(Clone, Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct SubstateProof {
+    /// A proof of inclusion or exclusion of the substate's ReNodeModule in the upper-layer tree.
+    pub re_node_module_proof: SparseMerkleProof,
+    /// A proof of inclusion or exclusion of the substate's offset in the nested tree, or `None`
+    /// if the entire ReNodeModule does not exist (which is then proven by the upper layer alone).
+    pub substate_proof: Option<SparseMerkleProof>,
+}
+
+/// An error of generating a `SubstateProof` (see `get_substate_proof()`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofGenerationError {
+    /// A root node for the requested version does not exist (e.g. it was never created or was
+    /// already pruned).
+    VersionNotFound(Version),
+    /// The nodes read from the store do not form a valid tree (e.g. a referenced node is missing).
+    InconsistentTree,
+}
+
+/// Finds the hashed value of the given substate at the given state `version` and generates a
+/// Merkle proof of its inclusion (or, when the substate does not exist at that version, of its
+/// exclusion).
+/// The returned proof can be checked against the root hash returned by `put_at_next_version()`
+/// for the same `version` (see `verify_substate_proof()`).
+pub fn get_substate_proof<
+    S: ReadableTreeStore<ReNodeModulePayload> + ReadableTreeStore<SubstateOffset>,
+>(
+    store: &S,
+    version: Version,
+    substate_id: &SubstateId,
+) -> Result<(Option<Hash>, SubstateProof), ProofGenerationError> {
+    if ReadableTreeStore::<ReNodeModulePayload>::get_node(store, &NodeKey::new_empty_path(version))
+        .is_none()
+    {
+        return Err(ProofGenerationError::VersionNotFound(version));
+    }
+
+    let re_node_module = ReNodeModule::new(substate_id.0, substate_id.1);
+    let (re_node_module_leaf, re_node_module_proof) =
+        JellyfishMerkleTree::<S, ReNodeModulePayload>::new(store)
+            .get_with_proof(hash(scrypto_encode(&re_node_module).unwrap()), version)
+            .map_err(|_| ProofGenerationError::InconsistentTree)?;
+
+    let (payload, subtree_version) = match re_node_module_leaf {
+        Some((_, (payload, subtree_version))) => (payload, subtree_version),
+        None => {
+            return Ok((
+                None,
+                SubstateProof {
+                    re_node_module_proof,
+                    substate_proof: None,
+                },
+            ))
+        }
+    };
+
+    let subtree_reader = NestedTreeReader::new(store, &re_node_module, payload.substates_root);
+    let (substate_leaf, substate_proof) =
+        JellyfishMerkleTree::<NestedTreeReader<S>, SubstateOffset>::new(&subtree_reader)
+            .get_with_proof(
+                hash(scrypto_encode(&substate_id.2).unwrap()),
+                subtree_version,
+            )
+            .map_err(|_| ProofGenerationError::InconsistentTree)?;
+    Ok((
+        substate_leaf.map(|(value_hash, _)| value_hash),
+        SubstateProof {
+            re_node_module_proof,
+            substate_proof: Some(substate_proof),
+        },
+    ))
+}
+
+/// Verifies that the given `proof` shows the given substate's hashed value (or its absence, if
+/// `None`) within a state having the given `expected_root_hash`.
+/// This function does not need access to any `TreeStore` - it is meant to be used by external
+/// parties who only trust the root hash.
+pub fn verify_substate_proof(
+    proof: &SubstateProof,
+    expected_root_hash: Hash,
+    substate_id: &SubstateId,
+    substate_value_hash: Option<Hash>,
+) -> Result<(), ProofVerificationError> {
+    let re_node_module_key =
+        hash(scrypto_encode(&ReNodeModule::new(substate_id.0, substate_id.1)).unwrap());
+    let Some(substate_proof) = &proof.substate_proof else {
+        // the entire ReNodeModule must be proven absent
+        if substate_value_hash.is_some() {
+            return Err(ProofVerificationError::ExpectedInclusionProof);
+        }
+        return proof.re_node_module_proof.verify_by_hash(
+            expected_root_hash,
+            re_node_module_key,
+            None,
+        );
+    };
+
+    // the nested tree's root hash is exactly the value hash of the ReNodeModule's leaf
+    let nested_root_hash = match proof.re_node_module_proof.leaf() {
+        Some(leaf) if leaf.key() == re_node_module_key => leaf.value_hash(),
+        _ => return Err(ProofVerificationError::ExpectedInclusionProof),
+    };
+    substate_proof.verify_by_hash(
+        nested_root_hash,
+        hash(scrypto_encode(&substate_id.2).unwrap()),
+        substate_value_hash,
+    )?;
+    proof.re_node_module_proof.verify_by_hash(
+        expected_root_hash,
+        re_node_module_key,
+        Some(nested_root_hash),
+    )
+}
+
 // only internals below
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ScryptoSbor)]
@@ -202,7 +362,7 @@ fn put_changes<S: TreeStore<P>, P: Payload>(
         store.insert_node(key, node)
     }
     for key in update_result.stale_node_index_batch.into_iter().flatten() {
-        store.record_stale_node(key.node_key);
+        store.record_stale_node(key.node_key, key.stale_since_version);
     }
     root_hash
 }
@@ -263,14 +423,7 @@ impl<'s, S> NestedTreeStore<'s, S> {
     }
 
     fn prefixed(&self, key: &NodeKey) -> NodeKey {
-        NodeKey::new(
-            key.version(),
-            NibblePath::from_iter(
-                self.parent_path
-                    .nibbles()
-                    .chain(key.nibble_path().nibbles()),
-            ),
-        )
+        prefixed(&self.parent_path, key)
     }
 }
 
@@ -297,11 +450,55 @@ impl<'s, S: WriteableTreeStore<SubstateOffset>> WriteableTreeStore<SubstateOffse
         }
     }
 
-    fn record_stale_node(&mut self, key: NodeKey) {
+    fn record_stale_node(&mut self, key: NodeKey, stale_since_version: Version) {
         if key.nibble_path().is_empty() {
             self.current_root = None;
         } else {
-            self.underlying.record_stale_node(self.prefixed(&key));
+            self.underlying
+                .record_stale_node(self.prefixed(&key), stale_since_version);
+        }
+    }
+}
+
+/// A read-only counterpart of the `NestedTreeStore`, used for traversing an existing nested tree
+/// (e.g. when generating proofs).
+struct NestedTreeReader<'s, S> {
+    underlying: &'s S,
+    parent_path: NibblePath,
+    root: TreeNode<SubstateOffset>,
+}
+
+impl<'s, S> NestedTreeReader<'s, S> {
+    pub fn new(
+        underlying: &'s S,
+        re_node_module: &ReNodeModule,
+        root: TreeNode<SubstateOffset>,
+    ) -> NestedTreeReader<'s, S> {
+        NestedTreeReader {
+            underlying,
+            parent_path: NibblePath::new_even(
+                hash(scrypto_encode(re_node_module).unwrap()).to_vec(),
+            ),
+            root,
+        }
+    }
+}
+
+impl<'s, S: ReadableTreeStore<SubstateOffset>> ReadableTreeStore<SubstateOffset>
+    for NestedTreeReader<'s, S>
+{
+    fn get_node(&self, key: &NodeKey) -> Option<TreeNode<SubstateOffset>> {
+        if key.nibble_path().is_empty() {
+            Some(self.root.clone())
+        } else {
+            self.underlying.get_node(&prefixed(&self.parent_path, key))
         }
     }
 }
+
+fn prefixed(parent_path: &NibblePath, key: &NodeKey) -> NodeKey {
+    NodeKey::new(
+        key.version(),
+        NibblePath::from_iter(parent_path.nibbles().chain(key.nibble_path().nibbles())),
+    )
+}
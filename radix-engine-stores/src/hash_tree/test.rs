@@ -1,9 +1,13 @@
-use super::types::{Nibble, NibblePath, Version, SPARSE_MERKLE_PLACEHOLDER_HASH};
+use super::types::{Nibble, NibblePath, NodeKey, Version, SPARSE_MERKLE_PLACEHOLDER_HASH};
 use crate::hash_tree::tree_store::{
-    SerializedInMemoryTreeStore, TreeChildEntry, TreeInternalNode, TreeLeafNode, TreeNode,
-    TypedInMemoryTreeStore,
+    decode_key, decode_stale_node, encode_key, encode_stale_node, ReNodeModulePayload,
+    ReadableTreeStore, SerializedInMemoryTreeStore, StaleNode, TreeChildEntry, TreeInternalNode,
+    TreeLeafNode, TreeNode, TypedInMemoryTreeStore,
+};
+use crate::hash_tree::{
+    get_substate_proof, prune_stale_nodes, put_at_next_version, verify_substate_proof,
+    ProofGenerationError, ProofVerificationError, SubstateHashChange, SubstateProof,
 };
-use crate::hash_tree::{put_at_next_version, SubstateHashChange};
 use itertools::Itertools;
 use radix_engine::system::node_substates::PersistedSubstate;
 use radix_engine::types::PackageAddress;
@@ -213,7 +217,12 @@ fn deletes_re_node_layer_leaf_when_all_its_substates_deleted() {
         store
             .root_tree_nodes
             .iter()
-            .filter(|(key, _)| !store.stale_key_buffer.contains(key))
+            .filter(|(key, _)| {
+                !store
+                    .stale_node_index
+                    .iter()
+                    .any(|stale_node| stale_node.key == **key)
+            })
             .filter(|(_, node)| matches!(node, TreeNode::Leaf(TreeLeafNode { .. })))
             .count()
     }
@@ -284,9 +293,9 @@ fn records_stale_tree_node_keys() {
         vec![change(3, NodeModuleId::SELF, 9, Some(80))],
     );
     let stale_versions = store
-        .stale_key_buffer
+        .stale_node_index
         .iter()
-        .map(|key| key.version())
+        .map(|stale_node| stale_node.key.version())
         .unique()
         .sorted()
         .collect::<Vec<Version>>();
@@ -363,6 +372,284 @@ fn serialized_keys_are_strictly_increasing() {
     assert!(next_key > previous_key);
 }
 
+#[test]
+fn records_stale_since_version_of_each_stale_node() {
+    let mut store = TypedInMemoryTreeStore::new();
+    put_at_next_version(
+        &mut store,
+        None,
+        vec![change(4, NodeModuleId::SELF, 6, Some(30))],
+    );
+    put_at_next_version(
+        &mut store,
+        Some(1),
+        vec![change(4, NodeModuleId::SELF, 6, Some(40))],
+    );
+    assert!(!store.stale_node_index.is_empty());
+    assert!(store
+        .stale_node_index
+        .iter()
+        .all(|stale_node| stale_node.stale_since_version == 2 && stale_node.key.version() == 1));
+}
+
+#[test]
+fn pruning_deletes_only_nodes_stale_at_or_before_retained_version() {
+    let mut store = TypedInMemoryTreeStore::new();
+    put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(3, NodeModuleId::SELF, 4, Some(40)),
+        ],
+    );
+    put_at_next_version(
+        &mut store,
+        Some(1),
+        vec![change(1, NodeModuleId::SELF, 2, Some(50))],
+    );
+    put_at_next_version(
+        &mut store,
+        Some(2),
+        vec![change(3, NodeModuleId::SELF, 4, Some(60))],
+    );
+    let node_count_before = store.root_tree_nodes.len() + store.sub_tree_nodes.len();
+
+    let pruned_count = prune_stale_nodes(&mut store, 2);
+
+    assert!(pruned_count > 0);
+    assert_eq!(
+        store.root_tree_nodes.len() + store.sub_tree_nodes.len(),
+        node_count_before - pruned_count
+    );
+    assert!(store
+        .stale_node_index
+        .iter()
+        .all(|stale_node| stale_node.stale_since_version == 3));
+    assert!(ReadableTreeStore::<ReNodeModulePayload>::get_node(
+        &store,
+        &NodeKey::new_empty_path(1)
+    )
+    .is_none());
+    assert!(ReadableTreeStore::<ReNodeModulePayload>::get_node(
+        &store,
+        &NodeKey::new_empty_path(2)
+    )
+    .is_some());
+}
+
+#[test]
+fn pruned_store_still_serves_retained_versions() {
+    let mut full_store = SerializedInMemoryTreeStore::new();
+    let mut pruned_store = SerializedInMemoryTreeStore::new();
+    let batches = vec![
+        vec![
+            (1, NodeModuleId::SELF, 2, Some(30)),
+            (1, NodeModuleId::Metadata, 3, Some(40)),
+        ],
+        vec![(1, NodeModuleId::SELF, 2, Some(31))],
+        vec![
+            (1, NodeModuleId::Metadata, 3, None),
+            (5, NodeModuleId::SELF, 1, Some(20)),
+        ],
+        vec![(5, NodeModuleId::SELF, 1, Some(21))],
+    ];
+    let mut current_version = None;
+    for batch in batches {
+        let changes = || {
+            batch
+                .iter()
+                .map(
+                    |(re_node_id_seed, node_module_id, offset_seed, value_seed)| {
+                        change(*re_node_id_seed, *node_module_id, *offset_seed, *value_seed)
+                    },
+                )
+                .collect::<Vec<_>>()
+        };
+        let full_hash = put_at_next_version(&mut full_store, current_version, changes());
+        let pruned_hash = put_at_next_version(&mut pruned_store, current_version, changes());
+        assert_eq!(full_hash, pruned_hash);
+        let next_version = current_version.unwrap_or(0) + 1;
+        prune_stale_nodes(&mut pruned_store, next_version);
+        current_version = Some(next_version);
+    }
+    assert!(pruned_store.memory.len() < full_store.memory.len());
+
+    let substate_id = substate_id(5, NodeModuleId::SELF, 1);
+    let (found_hash, _) = get_substate_proof(&pruned_store, 4, &substate_id).unwrap();
+    assert_eq!(found_hash, Some(value_hash(21)));
+    assert_eq!(
+        get_substate_proof(&pruned_store, 3, &substate_id),
+        Err(ProofGenerationError::VersionNotFound(3))
+    );
+    assert_eq!(
+        get_substate_proof(&full_store, 5, &substate_id),
+        Err(ProofGenerationError::VersionNotFound(5))
+    );
+}
+
+#[test]
+fn stale_node_encoding_is_reversible() {
+    let stale_node = StaleNode {
+        stale_since_version: 77,
+        key: NodeKey::new(13, nibbles("a1b")),
+    };
+    let key_bytes = encode_key(&stale_node.key);
+    assert_eq!(decode_key(&key_bytes), stale_node.key);
+    let stale_node_bytes = encode_stale_node(&stale_node);
+    assert_eq!(decode_stale_node(&stale_node_bytes), stale_node);
+}
+
+#[test]
+fn proof_of_existing_substate_verifies() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(1, NodeModuleId::SELF, 3, Some(40)),
+            change(4, NodeModuleId::Metadata, 2, Some(50)),
+        ],
+    );
+    let substate_id = substate_id(1, NodeModuleId::SELF, 3);
+    let (found_hash, proof) = get_substate_proof(&store, 1, &substate_id).unwrap();
+    assert_eq!(found_hash, Some(value_hash(40)));
+    assert_eq!(
+        verify_substate_proof(&proof, root_hash, &substate_id, Some(value_hash(40))),
+        Ok(())
+    );
+}
+
+#[test]
+fn proof_of_existing_substate_does_not_verify_different_value() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(5, NodeModuleId::SELF, 2, Some(40)),
+        ],
+    );
+    let substate_id = substate_id(1, NodeModuleId::SELF, 2);
+    let (_, proof) = get_substate_proof(&store, 1, &substate_id).unwrap();
+    assert_eq!(
+        verify_substate_proof(&proof, root_hash, &substate_id, Some(value_hash(31))),
+        Err(ProofVerificationError::ValueHashMismatch)
+    );
+    assert_eq!(
+        verify_substate_proof(&proof, root_hash, &substate_id, None),
+        Err(ProofVerificationError::ExpectedNonInclusionProof)
+    );
+}
+
+#[test]
+fn proof_of_missing_substate_in_existing_re_node_module_verifies() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(1, NodeModuleId::SELF, 3, Some(40)),
+        ],
+    );
+    let substate_id = substate_id(1, NodeModuleId::SELF, 7);
+    let (found_hash, proof) = get_substate_proof(&store, 1, &substate_id).unwrap();
+    assert_eq!(found_hash, None);
+    assert!(proof.substate_proof.is_some());
+    assert_eq!(
+        verify_substate_proof(&proof, root_hash, &substate_id, None),
+        Ok(())
+    );
+    assert!(verify_substate_proof(&proof, root_hash, &substate_id, Some(value_hash(40))).is_err());
+}
+
+#[test]
+fn proof_of_missing_re_node_module_verifies() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(2, NodeModuleId::SELF, 2, Some(40)),
+        ],
+    );
+    let substate_id = substate_id(1, NodeModuleId::Metadata, 2);
+    let (found_hash, proof) = get_substate_proof(&store, 1, &substate_id).unwrap();
+    assert_eq!(found_hash, None);
+    assert!(proof.substate_proof.is_none());
+    assert_eq!(
+        verify_substate_proof(&proof, root_hash, &substate_id, None),
+        Ok(())
+    );
+}
+
+#[test]
+fn proof_of_empty_state_verifies() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(&mut store, None, vec![]);
+    let substate_id = substate_id(1, NodeModuleId::SELF, 2);
+    let (found_hash, proof) = get_substate_proof(&store, 1, &substate_id).unwrap();
+    assert_eq!(found_hash, None);
+    assert_eq!(
+        verify_substate_proof(&proof, root_hash, &substate_id, None),
+        Ok(())
+    );
+}
+
+#[test]
+fn proof_at_past_version_verifies_only_against_past_root() {
+    let mut store = SerializedInMemoryTreeStore::new();
+    let root_hash_v1 = put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(3, NodeModuleId::SELF, 4, Some(50)),
+        ],
+    );
+    let root_hash_v2 = put_at_next_version(
+        &mut store,
+        Some(1),
+        vec![change(1, NodeModuleId::SELF, 2, Some(60))],
+    );
+    let substate_id = substate_id(1, NodeModuleId::SELF, 2);
+    let (found_hash, proof) = get_substate_proof(&store, 1, &substate_id).unwrap();
+    assert_eq!(found_hash, Some(value_hash(30)));
+    assert_eq!(
+        verify_substate_proof(&proof, root_hash_v1, &substate_id, Some(value_hash(30))),
+        Ok(())
+    );
+    assert!(matches!(
+        verify_substate_proof(&proof, root_hash_v2, &substate_id, Some(value_hash(30))),
+        Err(ProofVerificationError::RootHashMismatch { .. })
+    ));
+}
+
+#[test]
+fn proof_survives_sbor_round_trip() {
+    let mut store = TypedInMemoryTreeStore::new();
+    let root_hash = put_at_next_version(
+        &mut store,
+        None,
+        vec![
+            change(1, NodeModuleId::SELF, 2, Some(30)),
+            change(2, NodeModuleId::SELF, 2, Some(40)),
+        ],
+    );
+    let substate_id = substate_id(2, NodeModuleId::SELF, 2);
+    let (_, proof) = get_substate_proof(&store, 1, &substate_id).unwrap();
+    let decoded: SubstateProof = scrypto_decode(&scrypto_encode(&proof).unwrap()).unwrap();
+    assert_eq!(proof, decoded);
+    assert_eq!(
+        verify_substate_proof(&decoded, root_hash, &substate_id, Some(value_hash(40))),
+        Ok(())
+    );
+}
+
 fn change(
     re_node_id_seed: u8,
     node_module_id: NodeModuleId,
@@ -91,19 +91,40 @@ pub trait WriteableTreeStore<P: Payload> {
 
     /// Marks the given node for a (potential) future removal by an arbitrary
     /// external pruning process.
-    fn record_stale_node(&mut self, key: NodeKey);
+    /// The node is not needed by any tree version greater or equal to `stale_since_version`.
+    fn record_stale_node(&mut self, key: NodeKey, stale_since_version: Version);
 }
 
 /// A complete tree node storage SPI.
 pub trait TreeStore<P: Payload>: ReadableTreeStore<P> + WriteableTreeStore<P> {}
 impl<S: ReadableTreeStore<P> + WriteableTreeStore<P>, P: Payload> TreeStore<P> for S {}
 
+/// The "pruning" part of a physical tree node storage SPI.
+/// Note: this part is payload-agnostic, since a single stale node index is maintained for the
+/// nodes of all layers.
+pub trait PrunableTreeStore {
+    /// Physically deletes all nodes (of any layer) which became stale at or before the given
+    /// version, together with their entries in the stale node index, and returns their count.
+    /// A persistent store should apply all these deletions atomically.
+    fn delete_stale_nodes(&mut self, up_to_version: Version) -> usize;
+}
+
+/// An entry of a stale node index: a node which became stale at a specific version.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct StaleNode {
+    /// Version at which the node became stale (i.e. a first version not referencing it).
+    pub stale_since_version: Version,
+    /// Key of the node.
+    pub key: NodeKey,
+}
+
 /// A `TreeStore` based on memory object copies (i.e. no serialization).
 #[derive(Debug, PartialEq, Eq)]
 pub struct TypedInMemoryTreeStore {
     pub root_tree_nodes: HashMap<NodeKey, TreeNode<ReNodeModulePayload>>,
     pub sub_tree_nodes: HashMap<NodeKey, TreeNode<SubstateOffset>>,
-    pub stale_key_buffer: Vec<NodeKey>,
+    /// Stale nodes, in the order of recording (i.e. by ascending `stale_since_version`).
+    pub stale_node_index: Vec<StaleNode>,
 }
 
 impl TypedInMemoryTreeStore {
@@ -112,7 +133,7 @@ impl TypedInMemoryTreeStore {
         TypedInMemoryTreeStore {
             root_tree_nodes: HashMap::new(),
             sub_tree_nodes: HashMap::new(),
-            stale_key_buffer: Vec::new(),
+            stale_node_index: Vec::new(),
         }
     }
 }
@@ -128,8 +149,11 @@ impl WriteableTreeStore<SubstateOffset> for TypedInMemoryTreeStore {
         self.sub_tree_nodes.insert(key, node);
     }
 
-    fn record_stale_node(&mut self, key: NodeKey) {
-        self.stale_key_buffer.push(key);
+    fn record_stale_node(&mut self, key: NodeKey, stale_since_version: Version) {
+        self.stale_node_index.push(StaleNode {
+            stale_since_version,
+            key,
+        });
     }
 }
 
@@ -144,8 +168,26 @@ impl WriteableTreeStore<ReNodeModulePayload> for TypedInMemoryTreeStore {
         self.root_tree_nodes.insert(key, node);
     }
 
-    fn record_stale_node(&mut self, key: NodeKey) {
-        self.stale_key_buffer.push(key);
+    fn record_stale_node(&mut self, key: NodeKey, stale_since_version: Version) {
+        self.stale_node_index.push(StaleNode {
+            stale_since_version,
+            key,
+        });
+    }
+}
+
+impl PrunableTreeStore for TypedInMemoryTreeStore {
+    fn delete_stale_nodes(&mut self, up_to_version: Version) -> usize {
+        let count = self
+            .stale_node_index
+            .partition_point(|stale_node| stale_node.stale_since_version <= up_to_version);
+        for stale_node in self.stale_node_index.drain(..count) {
+            // the physical keys of both layers never clash (see `ReNodeModulePayload`)
+            if self.root_tree_nodes.remove(&stale_node.key).is_none() {
+                self.sub_tree_nodes.remove(&stale_node.key);
+            }
+        }
+        count
     }
 }
 
@@ -153,7 +195,8 @@ impl WriteableTreeStore<ReNodeModulePayload> for TypedInMemoryTreeStore {
 #[derive(Debug, PartialEq, Eq)]
 pub struct SerializedInMemoryTreeStore {
     pub memory: HashMap<Vec<u8>, Vec<u8>>,
-    pub stale_key_buffer: Vec<Vec<u8>>,
+    /// Stale nodes encoded by `encode_stale_node()`, in the order of recording.
+    pub stale_node_index: Vec<Vec<u8>>,
 }
 
 impl SerializedInMemoryTreeStore {
@@ -161,7 +204,7 @@ impl SerializedInMemoryTreeStore {
     pub fn new() -> Self {
         Self {
             memory: HashMap::new(),
-            stale_key_buffer: Vec::new(),
+            stale_node_index: Vec::new(),
         }
     }
 }
@@ -180,8 +223,24 @@ impl<P: Payload> WriteableTreeStore<P> for SerializedInMemoryTreeStore {
             .insert(encode_key(&key), scrypto_encode(&node).unwrap());
     }
 
-    fn record_stale_node(&mut self, key: NodeKey) {
-        self.stale_key_buffer.push(encode_key(&key));
+    fn record_stale_node(&mut self, key: NodeKey, stale_since_version: Version) {
+        self.stale_node_index.push(encode_stale_node(&StaleNode {
+            stale_since_version,
+            key,
+        }));
+    }
+}
+
+impl PrunableTreeStore for SerializedInMemoryTreeStore {
+    fn delete_stale_nodes(&mut self, up_to_version: Version) -> usize {
+        let count = self
+            .stale_node_index
+            .partition_point(|bytes| decode_stale_node(bytes).stale_since_version <= up_to_version);
+        for bytes in self.stale_node_index.drain(..count) {
+            self.memory
+                .remove(&encode_key(&decode_stale_node(&bytes).key));
+        }
+        count
     }
 }
 
@@ -194,6 +253,36 @@ pub fn encode_key(key: &NodeKey) -> Vec<u8> {
     [version_bytes, nibble_path_bytes, parity_byte].concat()
 }
 
+/// Decodes the node key encoded by `encode_key()`.
+pub fn decode_key(bytes: &[u8]) -> NodeKey {
+    let (version_bytes, rest) = bytes.split_at(8);
+    let (nibble_path_bytes, parity_byte) = rest.split_at(rest.len() - 1);
+    let version = Version::from_be_bytes(version_bytes.try_into().unwrap());
+    let nibble_path_bytes = nibble_path_bytes.to_vec();
+    let nibble_path = if parity_byte[0] == 0 {
+        NibblePath::new_even(nibble_path_bytes)
+    } else {
+        NibblePath::new_odd(nibble_path_bytes)
+    };
+    NodeKey::new(version, nibble_path)
+}
+
+/// Encodes the given stale node index entry in a format friendly to Level-like databases (i.e.
+/// strictly ordered by numeric stale-since version, and then by the node key).
+pub fn encode_stale_node(stale_node: &StaleNode) -> Vec<u8> {
+    let version_bytes = &stale_node.stale_since_version.to_be_bytes();
+    [version_bytes, encode_key(&stale_node.key).as_slice()].concat()
+}
+
+/// Decodes the stale node index entry encoded by `encode_stale_node()`.
+pub fn decode_stale_node(bytes: &[u8]) -> StaleNode {
+    let (version_bytes, key_bytes) = bytes.split_at(8);
+    StaleNode {
+        stale_since_version: Version::from_be_bytes(version_bytes.try_into().unwrap()),
+        key: decode_key(key_bytes),
+    }
+}
+
 // Note: We need completely custom serialization scheme only for the node keys. The remaining
 // structures can simply use SBOR, with only the most efficiency-sensitive parts having custom
 // codecs, implemented below:
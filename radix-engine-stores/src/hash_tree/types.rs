@@ -81,6 +81,7 @@
 
 use itertools::Itertools;
 use radix_engine_interface::crypto::{hash, Hash};
+use radix_engine_interface::*;
 use sbor::rust::collections::hash_map::HashMap;
 use sbor::rust::ops::Range;
 use sbor::rust::string::String;
@@ -145,11 +146,90 @@ impl SparseMerkleProof {
     pub fn siblings(&self) -> &[Hash] {
         &self.siblings
     }
+
+    // SOURCE: https://github.com/aptos-labs/aptos-core/blob/1.0.4/types/src/proof/definition.rs#L216
+    /// If `element_hash` is present, verifies an element whose key is `element_key` and value is
+    /// authenticated by `element_hash` exists in the Sparse Merkle Tree using the provided proof.
+    /// Otherwise verifies the proof is a valid non-inclusion proof that shows this key doesn't
+    /// exist in the tree.
+    pub fn verify_by_hash(
+        &self,
+        expected_root_hash: Hash,
+        element_key: Hash,
+        element_hash: Option<Hash>,
+    ) -> Result<(), ProofVerificationError> {
+        if self.siblings.len() > Hash::LENGTH * 8 {
+            return Err(ProofVerificationError::TooManySiblings(self.siblings.len()));
+        }
+
+        match (element_hash, self.leaf) {
+            (Some(hash), Some(leaf)) => {
+                // This is an inclusion proof, so the key and value hash provided in the proof
+                // should match element_key and element_value_hash. `siblings` should prove the
+                // route from the leaf node to the root.
+                if element_key != leaf.key {
+                    return Err(ProofVerificationError::KeyMismatch);
+                }
+                if hash != leaf.value_hash {
+                    return Err(ProofVerificationError::ValueHashMismatch);
+                }
+            }
+            (Some(_), None) => {
+                return Err(ProofVerificationError::ExpectedInclusionProof);
+            }
+            (None, Some(leaf)) => {
+                // This is a non-inclusion proof. The proof intends to show that if a leaf node
+                // representing `element_key` is inserted, it will break a currently existing leaf
+                // node represented by `proof_key` into a branch. `siblings` should prove the
+                // route from that leaf node to the root.
+                if element_key == leaf.key {
+                    return Err(ProofVerificationError::ExpectedNonInclusionProof);
+                }
+                if element_key.common_prefix_bits_len(&leaf.key) < self.siblings.len() {
+                    return Err(ProofVerificationError::KeyPrefixMismatch);
+                }
+            }
+            (None, None) => {
+                // This is a non-inclusion proof. The proof intends to show that if a leaf node
+                // representing `element_key` is inserted, it will show up at a currently empty
+                // position. `sibling` should prove the route from this empty position to the
+                // root.
+            }
+        }
+
+        let current_hash = self
+            .leaf
+            .map_or(SPARSE_MERKLE_PLACEHOLDER_HASH, |leaf| leaf.hash());
+        let actual_root_hash = self
+            .siblings
+            .iter()
+            .zip(
+                element_key
+                    .iter_bits()
+                    .rev()
+                    .skip(Hash::LENGTH * 8 - self.siblings.len()),
+            )
+            .fold(current_hash, |hash, (sibling_hash, bit)| {
+                if bit {
+                    SparseMerkleInternalNode::new(*sibling_hash, hash).hash()
+                } else {
+                    SparseMerkleInternalNode::new(hash, *sibling_hash).hash()
+                }
+            });
+        if actual_root_hash != expected_root_hash {
+            return Err(ProofVerificationError::RootHashMismatch {
+                expected: expected_root_hash,
+                actual: actual_root_hash,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 /// A proof that can be used to authenticate an element in a Sparse Merkle Tree given trusted root
 /// hash. For example, `TransactionInfoToAccountProof` can be constructed on top of this structure.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, ScryptoSbor)]
 pub struct SparseMerkleProof {
     /// This proof can be used to authenticate whether a given leaf exists in the tree or not.
     ///     - If this is `Some(leaf_node)`
@@ -238,7 +318,7 @@ impl SparseMerkleRangeProof {
 }
 
 // SOURCE: https://github.com/aptos-labs/aptos-core/blob/1.0.4/types/src/proof/mod.rs#L97
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ScryptoSbor)]
 pub struct SparseMerkleLeafNode {
     key: Hash,
     value_hash: Hash,
@@ -336,6 +416,8 @@ pub trait IteratedHash {
     fn iter_bits(&self) -> HashBitIterator<'_>;
 
     fn get_nibble(&self, index: usize) -> Nibble;
+
+    fn common_prefix_bits_len(&self, other: &Hash) -> usize;
 }
 
 impl IteratedHash for Hash {
@@ -350,6 +432,13 @@ impl IteratedHash for Hash {
             self.0[index / 2] & 0x0F
         })
     }
+
+    fn common_prefix_bits_len(&self, other: &Hash) -> usize {
+        self.iter_bits()
+            .zip(other.iter_bits())
+            .take_while(|(x, y)| x == y)
+            .count()
+    }
 }
 
 // SOURCE: https://github.com/aptos-labs/aptos-core/blob/1.0.4/types/src/transaction/mod.rs#L57
@@ -1173,3 +1262,24 @@ pub enum StorageError {
     /// An unexpected I/O error, with a detail message.
     UnexpectedIoError(String),
 }
+
+/// Reason of a failed Merkle proof verification.
+/// Note: Aptos reports these as `anyhow::Error` messages; we use our own enum to allow for no-std
+/// build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofVerificationError {
+    /// The proof has more siblings than there are bits in a key.
+    TooManySiblings(usize),
+    /// An inclusion proof's leaf has a different key than the verified element.
+    KeyMismatch,
+    /// An inclusion proof's leaf has a different value hash than the verified element.
+    ValueHashMismatch,
+    /// An inclusion was expected (i.e. a value hash was given), but the proof has no leaf.
+    ExpectedInclusionProof,
+    /// A non-inclusion was expected, but the proof's leaf is the verified element.
+    ExpectedNonInclusionProof,
+    /// A non-inclusion proof's leaf is not located in the subtree of the verified element.
+    KeyPrefixMismatch,
+    /// The root hash computed from the proof does not match the expected one.
+    RootHashMismatch { expected: Hash, actual: Hash },
+}
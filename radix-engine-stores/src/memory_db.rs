@@ -1,5 +1,6 @@
 use radix_engine::ledger::{
-    OutputValue, QueryableSubstateStore, ReadableSubstateStore, WriteableSubstateStore,
+    select_kv_store_keys, select_substates, OutputValue, QueryableSubstateStore,
+    ReadableSubstateStore, WriteableSubstateStore,
 };
 use radix_engine::system::node_substates::PersistedSubstate;
 use radix_engine::types::*;
@@ -31,6 +32,15 @@ impl ReadableSubstateStore for SerializedInMemorySubstateStore {
             .get(&scrypto_encode(substate_id).expect("Could not encode substate id"))
             .map(|b| scrypto_decode(&b).unwrap())
     }
+
+    fn list_kv_store_keys(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<Vec<u8>> {
+        select_kv_store_keys(self.get_kv_store_entries(kv_store_id), from, limit)
+    }
 }
 
 impl WriteableSubstateStore for SerializedInMemorySubstateStore {
@@ -40,6 +50,11 @@ impl WriteableSubstateStore for SerializedInMemorySubstateStore {
             scrypto_encode(&substate).expect("Could not encode substate"),
         );
     }
+
+    fn remove_substate(&mut self, substate_id: &SubstateId) {
+        self.substates
+            .remove(&scrypto_encode(substate_id).expect("Could not encode substate id"));
+    }
 }
 
 impl QueryableSubstateStore for SerializedInMemorySubstateStore {
@@ -69,4 +84,22 @@ impl QueryableSubstateStore for SerializedInMemorySubstateStore {
             })
             .collect()
     }
+
+    fn list_substates(
+        &self,
+        node_id: &RENodeId,
+        module_id: Option<NodeModuleId>,
+        from: Option<&(NodeModuleId, SubstateOffset)>,
+        limit: usize,
+    ) -> Vec<(SubstateId, OutputValue)> {
+        let substates = self.substates.iter().filter_map(|(key, value)| {
+            let substate_id: SubstateId = scrypto_decode(key).unwrap();
+            if substate_id.0 == *node_id {
+                Some((substate_id, scrypto_decode(value).unwrap()))
+            } else {
+                None
+            }
+        });
+        select_substates(substates, node_id, module_id, from, limit)
+    }
 }
@@ -1,55 +1,357 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use radix_engine::kernel::interpreters::ScryptoInterpreter;
+use radix_engine::state_manager::{CommitLogStore, CommitReceipt, CommittedTransaction, StateDiff};
 use radix_engine::system::node_substates::PersistedSubstate;
+use radix_engine::transaction::CommitResult;
 use radix_engine::types::*;
 use radix_engine::{ledger::*, wasm::WasmEngine};
 use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::data::scrypto::ScryptoDecode;
-use rocksdb::{DBWithThreadMode, Direction, IteratorMode, SingleThreaded, DB};
+use rocksdb::{
+    AsColumnFamilyRef, BoundColumnFamily, ColumnFamily, DBWithThreadMode, Direction, IteratorMode,
+    MultiThreaded, Options, SingleThreaded, SnapshotWithThreadMode, ThreadMode, WriteBatch,
+};
+use self_cell::self_cell;
 
-pub struct RadixEngineDB {
-    db: DBWithThreadMode<SingleThreaded>,
+use crate::event_store::{
+    encode_event_index_keys, select_events, EventCursor, EventIndex, EventPage, EventQuery,
+    EventStore, StoredEvent,
+};
+use crate::hash_tree::tree_store::{
+    decode_stale_node, encode_key, encode_stale_node, NodeKey, Payload, PrunableTreeStore,
+    ReadableTreeStore, StaleNode, TreeNode, Version, WriteableTreeStore,
+};
+use crate::hash_tree::{put_at_next_version, to_substate_hash_changes};
+
+/// Column family holding the physical nodes of the state hash tree.
+const TREE_NODES_CF: &str = "tree_nodes";
+/// Column family holding the stale tree node index (see `PrunableTreeStore`).
+const STALE_TREE_NODES_CF: &str = "stale_tree_nodes";
+/// Column family holding all the past values of the substates (see `VersionedSubstateStore`).
+const SUBSTATE_HISTORY_CF: &str = "substate_history";
+/// Column family holding the log of committed transactions (see `CommitLogStore`), keyed by
+/// their big-endian state versions.
+const COMMIT_LOG_CF: &str = "commit_log";
+/// Column family holding the application events of the logged transactions (see `EventStore`),
+/// keyed by their `EventCursor`s.
+const EVENTS_CF: &str = "events";
+/// Column family holding the index of the events by emitter node (see `EventIndex`).
+const EVENTS_BY_EMITTER_CF: &str = "events_by_emitter";
+/// Column family holding the index of the events by type (see `EventIndex`).
+const EVENTS_BY_TYPE_CF: &str = "events_by_type";
+/// Column family holding the index of the (non-empty) key-value store entries, keyed by the
+/// encoded key-value store ID followed by the raw entry key (see `encode_kv_store_index_key()`).
+const KV_STORE_KEYS_CF: &str = "kv_store_keys";
+/// Column family holding ledger-wide metadata entries.
+const METADATA_CF: &str = "metadata";
+
+/// Metadata key of the current state hash (i.e. a `(Version, Hash)` tuple).
+const STATE_HASH_KEY: &[u8] = b"state_hash";
+/// Metadata key of the `LastCommittedTransaction` marker.
+const LAST_COMMITTED_TRANSACTION_KEY: &[u8] = b"last_committed_transaction";
+/// Metadata key marking that the `KV_STORE_KEYS_CF` index covers all the stored entries.
+const KV_STORE_KEYS_INDEXED_KEY: &[u8] = b"kv_store_keys_indexed";
+
+/// All the modules whose substates are kept in a dedicated column family, in `NodeModuleId`
+/// order.
+const SUBSTATE_MODULES: [NodeModuleId; 6] = [
+    NodeModuleId::SELF,
+    NodeModuleId::TypeInfo,
+    NodeModuleId::Metadata,
+    NodeModuleId::AccessRules,
+    NodeModuleId::AccessRules1,
+    NodeModuleId::ComponentRoyalty,
+];
+
+/// Returns the name of the column family holding the substates of the given module.
+fn substates_cf(module_id: NodeModuleId) -> &'static str {
+    match module_id {
+        NodeModuleId::SELF => "substates_self",
+        NodeModuleId::TypeInfo => "substates_type_info",
+        NodeModuleId::Metadata => "substates_metadata",
+        NodeModuleId::AccessRules => "substates_access_rules",
+        NodeModuleId::AccessRules1 => "substates_access_rules_1",
+        NodeModuleId::ComponentRoyalty => "substates_component_royalty",
+    }
 }
 
-impl RadixEngineDB {
-    pub fn new(root: PathBuf) -> Self {
-        let db = DB::open_default(root.as_path()).unwrap();
-        Self { db }
+/// Returns the name of the column family holding the given event index.
+fn event_index_cf(index: EventIndex) -> &'static str {
+    match index {
+        EventIndex::ByEmitter => EVENTS_BY_EMITTER_CF,
+        EventIndex::ByType => EVENTS_BY_TYPE_CF,
+    }
+}
+
+/// Encodes the key of a substate within its module's column family.
+fn encode_substate_key(node_id: &RENodeId, offset: &SubstateOffset) -> Vec<u8> {
+    scrypto_encode(&(node_id.clone(), offset.clone())).expect("Could not encode substate key")
+}
+
+/// Encodes the common prefix of the keys of all the given RENode's substates (within their
+/// modules' column families).
+fn encode_substate_key_prefix(node_id: &RENodeId) -> Vec<u8> {
+    // The key is an encoded tuple, ending with the (self-delimiting) encoding of the offset.
+    let any_offset = SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo);
+    let mut key = encode_substate_key(node_id, &any_offset);
+    key.truncate(key.len() - encoded_value_len(&any_offset));
+    key
+}
+
+/// Encodes the common prefix of the keys of all the given RENode's substate history entries (see
+/// `encode_substate_history_key()`).
+fn encode_substate_history_key_prefix(node_id: &RENodeId) -> Vec<u8> {
+    // The encoded substate ID ends with the (self-delimiting) encodings of the module and offset.
+    let any_module_id = NodeModuleId::SELF;
+    let any_offset = SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo);
+    let mut key = scrypto_encode(&SubstateId(
+        node_id.clone(),
+        any_module_id,
+        any_offset.clone(),
+    ))
+    .expect("Could not encode substate id");
+    key.truncate(key.len() - encoded_value_len(&any_module_id) - encoded_value_len(&any_offset));
+    key
+}
+
+/// Encodes the common prefix of the keys of all the given key-value store's entries within the
+/// `KV_STORE_KEYS_CF` index.
+fn encode_kv_store_index_key_prefix(kv_store_id: &KeyValueStoreId) -> Vec<u8> {
+    scrypto_encode(kv_store_id).expect("Could not encode key-value store id")
+}
+
+/// Encodes the key of a substate within the `KV_STORE_KEYS_CF` index, or returns `None` if it is
+/// not a key-value store entry.
+/// Since the prefix has a fixed length and the raw entry key follows it, the entries of a single
+/// key-value store are adjacent and ordered by their raw keys.
+fn encode_kv_store_index_key(substate_id: &SubstateId) -> Option<Vec<u8>> {
+    match substate_id {
+        SubstateId(
+            RENodeId::KeyValueStore(kv_store_id),
+            NodeModuleId::SELF,
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(entry_key)),
+        ) => {
+            let mut key = encode_kv_store_index_key_prefix(kv_store_id);
+            key.extend_from_slice(entry_key);
+            Some(key)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the length of the value's encoding when nested in another value (i.e. without the
+/// payload prefix).
+fn encoded_value_len<T: ScryptoEncode>(value: &T) -> usize {
+    scrypto_encode(value).expect("Could not encode value").len() - 1
+}
+
+/// Encodes the key of a substate's value put at the given state version.
+/// Since the encoding of a substate ID is self-delimiting, the keys of a single substate are
+/// adjacent and ordered by version.
+fn encode_substate_history_key(substate_id: &SubstateId, state_version: Version) -> Vec<u8> {
+    let mut key = scrypto_encode(substate_id).expect("Could not encode substate id");
+    key.extend_from_slice(&state_version.to_be_bytes());
+    key
+}
+
+/// The value of a substate history entry which records the substate's removal.
+const REMOVED_SUBSTATE_HISTORY_VALUE: &[u8] = b"";
+
+/// Decodes the value of a substate history entry, which is `None` if it records a removal.
+fn decode_substate_history_value(value: &[u8]) -> Option<OutputValue> {
+    if value == REMOVED_SUBSTATE_HISTORY_VALUE {
+        None
+    } else {
+        Some(scrypto_decode(value).expect("Could not decode persisted substate"))
+    }
+}
+
+/// Decodes a key encoded by `encode_substate_history_key()`.
+fn decode_substate_history_key(key: &[u8]) -> (SubstateId, Version) {
+    let (substate_id_bytes, version_bytes) = key.split_at(key.len() - 8);
+    (
+        scrypto_decode(substate_id_bytes).expect("Could not decode substate id"),
+        Version::from_be_bytes(version_bytes.try_into().unwrap()),
+    )
+}
+
+/// A marker of the last transaction committed to the `RadixEngineDB`.
+/// It is written in the same atomic batch as the transaction's state changes, so that after a
+/// crash it tells exactly which transaction made it to the ledger.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct LastCommittedTransaction {
+    /// The state version reached by committing the transaction.
+    pub state_version: Version,
+    /// The hash of the committed transaction, if it was known to the committer.
+    pub transaction_hash: Option<Hash>,
+}
+
+/// A thread mode of the underlying RocksDB instance.
+/// It abstracts over the type of column family handles, which differs between the modes.
+pub trait ColumnFamilyAccess: ThreadMode + Sized {
+    type Handle<'a>: AsColumnFamilyRef
+    where
+        Self: 'a;
+
+    fn cf_handle<'a>(db: &'a DBWithThreadMode<Self>, name: &str) -> Option<Self::Handle<'a>>;
+}
+
+impl ColumnFamilyAccess for SingleThreaded {
+    type Handle<'a> = &'a ColumnFamily;
+
+    fn cf_handle<'a>(db: &'a DBWithThreadMode<Self>, name: &str) -> Option<Self::Handle<'a>> {
+        db.cf_handle(name)
+    }
+}
+
+impl ColumnFamilyAccess for MultiThreaded {
+    type Handle<'a> = Arc<BoundColumnFamily<'a>>;
+
+    fn cf_handle<'a>(db: &'a DBWithThreadMode<Self>, name: &str) -> Option<Self::Handle<'a>> {
+        db.cf_handle(name)
+    }
+}
+
+/// A RocksDB-backed substate store, used exclusively by a single thread.
+pub type RadixEngineDB = RadixEngineDBWithThreadMode<SingleThreaded>;
+
+/// A RocksDB-backed substate store, which can additionally serve consistent reads to many threads
+/// (see `snapshot()`) while its owner keeps committing.
+pub type MultiThreadedRadixEngineDB = RadixEngineDBWithThreadMode<MultiThreaded>;
+
+/// A RocksDB-backed substate store.
+/// The substates are partitioned into column families by their `NodeModuleId`, and all their past
+/// values are retained (see `VersionedSubstateStore`). Apart from them, the store maintains a
+/// state hash tree (see `hash_tree`) and a `LastCommittedTransaction` marker, both updated
+/// atomically with the substates on every commit. The transactions committed via
+/// `commit_and_log()` are additionally logged, along with their indexed events (see
+/// `EventStore`).
+pub struct RadixEngineDBWithThreadMode<M: ColumnFamilyAccess> {
+    db: Arc<DBWithThreadMode<M>>,
+}
+
+/// An error of opening a `RadixEngineDB`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RadixEngineDBError {
+    /// The database at the given location was created with the legacy layout (i.e. with all
+    /// substates in the default column family) - such ledger has to be reset.
+    LegacyLayout(PathBuf),
+}
+
+impl<M: ColumnFamilyAccess> RadixEngineDBWithThreadMode<M> {
+    /// Opens (or creates) the database at the given location.
+    pub fn new(root: PathBuf) -> Result<Self, RadixEngineDBError> {
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let column_families = SUBSTATE_MODULES
+            .iter()
+            .map(|module_id| substates_cf(*module_id))
+            .chain([
+                SUBSTATE_HISTORY_CF,
+                COMMIT_LOG_CF,
+                EVENTS_CF,
+                EVENTS_BY_EMITTER_CF,
+                EVENTS_BY_TYPE_CF,
+                KV_STORE_KEYS_CF,
+                TREE_NODES_CF,
+                STALE_TREE_NODES_CF,
+                METADATA_CF,
+            ]);
+        let db = DBWithThreadMode::<M>::open_cf(&options, root.as_path(), column_families).unwrap();
+        if db.iterator(IteratorMode::Start).next().is_some() {
+            return Err(RadixEngineDBError::LegacyLayout(root));
+        }
+        let substate_store = Self { db: Arc::new(db) };
+        substate_store.index_kv_store_keys();
+        Ok(substate_store)
+    }
+
+    /// Fills the `KV_STORE_KEYS_CF` index from the stored key-value store entries, unless it was
+    /// already done (i.e. for a database created before the index existed).
+    fn index_kv_store_keys(&self) {
+        let metadata_cf = self.cf(METADATA_CF);
+        if self
+            .db
+            .get_cf(&metadata_cf, KV_STORE_KEYS_INDEXED_KEY)
+            .unwrap()
+            .is_some()
+        {
+            return;
+        }
+        let mut batch = WriteBatch::default();
+        for kv in self.db.iterator_cf(
+            &self.cf(substates_cf(NodeModuleId::SELF)),
+            IteratorMode::Start,
+        ) {
+            let (key, value) = kv.unwrap();
+            let (node_id, offset): (RENodeId, SubstateOffset) = scrypto_decode(&key).unwrap();
+            let output_value: OutputValue =
+                scrypto_decode(&value).expect("Could not decode persisted substate");
+            self.stage_kv_store_index_update(
+                &mut batch,
+                &SubstateId(node_id, NodeModuleId::SELF, offset),
+                Some(&output_value),
+            );
+        }
+        batch.put_cf(&metadata_cf, KV_STORE_KEYS_INDEXED_KEY, b"");
+        self.db.write(batch).unwrap();
+    }
+
+    /// Stages the update of the `KV_STORE_KEYS_CF` index caused by putting the given value (or
+    /// removing the substate, if `None`), if the substate is a key-value store entry.
+    fn stage_kv_store_index_update(
+        &self,
+        batch: &mut WriteBatch,
+        substate_id: &SubstateId,
+        output_value: Option<&OutputValue>,
+    ) {
+        if let Some(key) = encode_kv_store_index_key(substate_id) {
+            let is_present = matches!(
+                output_value,
+                Some(OutputValue {
+                    substate: PersistedSubstate::KeyValueStoreEntry(Some(..)),
+                    ..
+                })
+            );
+            if is_present {
+                batch.put_cf(&self.cf(KV_STORE_KEYS_CF), key, b"");
+            } else {
+                batch.delete_cf(&self.cf(KV_STORE_KEYS_CF), key);
+            }
+        }
     }
 
     pub fn with_bootstrap<W: WasmEngine>(
         root: PathBuf,
         scrypto_interpreter: &ScryptoInterpreter<W>,
-    ) -> Self {
-        let mut substate_store = Self::new(root);
+    ) -> Result<Self, RadixEngineDBError> {
+        let mut substate_store = Self::new(root)?;
         bootstrap(&mut substate_store, scrypto_interpreter);
-        substate_store
+        Ok(substate_store)
     }
 
     pub fn list_packages(&self) -> Vec<PackageAddress> {
-        let start = &scrypto_encode(&SubstateId(
-            RENodeId::GlobalObject(PackageAddress::Normal([0; 26]).into()),
-            NodeModuleId::TypeInfo,
-            SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-        ))
-        .unwrap();
-        let end = &scrypto_encode(&SubstateId(
-            RENodeId::GlobalObject(PackageAddress::Normal([255; 26]).into()),
-            NodeModuleId::TypeInfo,
-            SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-        ))
-        .unwrap();
-        let substate_ids: Vec<SubstateId> = self.list_items(start, end);
+        let start = &encode_substate_key(
+            &RENodeId::GlobalObject(PackageAddress::Normal([0; 26]).into()),
+            &SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+        );
+        let end = &encode_substate_key(
+            &RENodeId::GlobalObject(PackageAddress::Normal([255; 26]).into()),
+            &SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+        );
+        let substate_keys: Vec<(RENodeId, SubstateOffset)> =
+            self.list_items(NodeModuleId::TypeInfo, start, end);
 
         let mut addresses = Vec::new();
-        for substate_id in substate_ids {
-            if let SubstateId(
+        for substate_key in substate_keys {
+            if let (
                 RENodeId::GlobalObject(Address::Package(package_address)),
-                NodeModuleId::TypeInfo,
                 SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-            ) = substate_id
+            ) = substate_key
             {
                 addresses.push(package_address);
             }
@@ -63,26 +365,22 @@ impl RadixEngineDB {
         start: ComponentAddress,
         end: ComponentAddress,
     ) -> Vec<ComponentAddress> {
-        let start = &scrypto_encode(&SubstateId(
-            RENodeId::GlobalObject(Address::Component(start)),
-            NodeModuleId::TypeInfo,
-            SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-        ))
-        .unwrap();
-        let end = &scrypto_encode(&SubstateId(
-            RENodeId::GlobalObject(Address::Component(end)),
-            NodeModuleId::TypeInfo,
-            SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-        ))
-        .unwrap();
-        let substate_ids: Vec<SubstateId> = self.list_items(start, end);
+        let start = &encode_substate_key(
+            &RENodeId::GlobalObject(Address::Component(start)),
+            &SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+        );
+        let end = &encode_substate_key(
+            &RENodeId::GlobalObject(Address::Component(end)),
+            &SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+        );
+        let substate_keys: Vec<(RENodeId, SubstateOffset)> =
+            self.list_items(NodeModuleId::TypeInfo, start, end);
         let mut addresses = Vec::new();
-        for substate_id in substate_ids {
-            if let SubstateId(
+        for substate_key in substate_keys {
+            if let (
                 RENodeId::GlobalObject(Address::Component(component_address)),
-                NodeModuleId::TypeInfo,
                 SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-            ) = substate_id
+            ) = substate_key
             {
                 addresses.push(component_address);
             }
@@ -105,26 +403,22 @@ impl RadixEngineDB {
     }
 
     pub fn list_resource_managers(&self) -> Vec<ResourceAddress> {
-        let start = &scrypto_encode(&SubstateId(
-            RENodeId::GlobalObject(ResourceAddress::Fungible([0; 26]).into()),
-            NodeModuleId::TypeInfo,
-            SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-        ))
-        .unwrap();
-        let end = &scrypto_encode(&SubstateId(
-            RENodeId::GlobalObject(ResourceAddress::NonFungible([255; 26]).into()),
-            NodeModuleId::TypeInfo,
-            SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-        ))
-        .unwrap();
-        let substate_ids: Vec<SubstateId> = self.list_items(start, end);
+        let start = &encode_substate_key(
+            &RENodeId::GlobalObject(ResourceAddress::Fungible([0; 26]).into()),
+            &SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+        );
+        let end = &encode_substate_key(
+            &RENodeId::GlobalObject(ResourceAddress::NonFungible([255; 26]).into()),
+            &SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+        );
+        let substate_keys: Vec<(RENodeId, SubstateOffset)> =
+            self.list_items(NodeModuleId::TypeInfo, start, end);
         let mut addresses = Vec::new();
-        for substate_id in substate_ids {
-            if let SubstateId(
+        for substate_key in substate_keys {
+            if let (
                 RENodeId::GlobalObject(Address::Resource(resource_address)),
-                NodeModuleId::TypeInfo,
                 SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-            ) = substate_id
+            ) = substate_key
             {
                 addresses.push(resource_address);
             }
@@ -133,12 +427,18 @@ impl RadixEngineDB {
         addresses
     }
 
-    fn list_items<T: ScryptoDecode>(&self, start: &[u8], inclusive_end: &[u8]) -> Vec<T> {
-        let mut iter = self
-            .db
-            .iterator(IteratorMode::From(start, Direction::Forward));
+    fn list_items<T: ScryptoDecode>(
+        &self,
+        module_id: NodeModuleId,
+        start: &[u8],
+        inclusive_end: &[u8],
+    ) -> Vec<T> {
+        let iter = self.db.iterator_cf(
+            &self.cf(substates_cf(module_id)),
+            IteratorMode::From(start, Direction::Forward),
+        );
         let mut items = Vec::new();
-        while let Some(kv) = iter.next() {
+        for kv in iter {
             let (key, _value) = kv.unwrap();
             if key.as_ref() > inclusive_end {
                 break;
@@ -150,61 +450,717 @@ impl RadixEngineDB {
         items
     }
 
-    fn read(&self, substate_id: &SubstateId) -> Option<Vec<u8>> {
-        // TODO: Use get_pinned
+    /// Returns the version and the root hash of the current state, or `None` if no transaction
+    /// was committed yet.
+    /// Note: substates written via `put_substate()` (i.e. not via `commit()`) are not hashed.
+    pub fn get_state_hash(&self) -> Option<(Version, Hash)> {
         self.db
-            .get(scrypto_encode(substate_id).expect("Could not encode substate id"))
+            .get_cf(&self.cf(METADATA_CF), STATE_HASH_KEY)
             .unwrap()
+            .map(|bytes| scrypto_decode(&bytes).expect("Could not decode state hash"))
+    }
+
+    fn cf(&self, name: &str) -> M::Handle<'_> {
+        cf(&self.db, name)
     }
 
-    fn write(&self, substate_id: SubstateId, value: Vec<u8>) {
+    /// Returns the marker of the last committed transaction, or `None` if no transaction was
+    /// committed yet.
+    pub fn get_last_committed_transaction(&self) -> Option<LastCommittedTransaction> {
         self.db
-            .put(
-                scrypto_encode(&substate_id).expect("Could not encode substate id"),
+            .get_cf(&self.cf(METADATA_CF), LAST_COMMITTED_TRANSACTION_KEY)
+            .unwrap()
+            .map(|bytes| {
+                scrypto_decode(&bytes).expect("Could not decode last committed transaction")
+            })
+    }
+
+    /// Atomically commits the given state diff of the transaction with the given hash: the
+    /// substates, the state hash tree and the `LastCommittedTransaction` marker are all written
+    /// in a single `WriteBatch`.
+    pub fn commit_batch(
+        &mut self,
+        transaction_hash: Hash,
+        state_diff: &StateDiff,
+    ) -> CommitReceipt {
+        let receipt = state_diff.to_commit_receipt();
+        self.write_batch(Some(transaction_hash), state_diff, &receipt, None);
+        receipt
+    }
+
+    fn write_batch(
+        &mut self,
+        transaction_hash: Option<Hash>,
+        state_diff: &StateDiff,
+        receipt: &CommitReceipt,
+        committed_transaction: Option<&CommittedTransaction>,
+    ) {
+        let current_version = self.get_state_hash().map(|(version, _)| version);
+        let next_version = current_version.unwrap_or(0) + 1;
+
+        let mut batch = WriteBatch::default();
+        for (substate_id, output_value) in &state_diff.up_substates {
+            let SubstateId(node_id, module_id, offset) = substate_id;
+            let value =
+                scrypto_encode(output_value).expect("Could not encode substate for persistence");
+            batch.put_cf(
+                &self.cf(SUBSTATE_HISTORY_CF),
+                encode_substate_history_key(substate_id, next_version),
+                &value,
+            );
+            batch.put_cf(
+                &self.cf(substates_cf(*module_id)),
+                encode_substate_key(node_id, offset),
                 value,
+            );
+            self.stage_kv_store_index_update(&mut batch, substate_id, Some(output_value));
+        }
+        for substate_id in state_diff.removed_substate_ids() {
+            self.stage_kv_store_index_update(&mut batch, substate_id, None);
+            let SubstateId(node_id, module_id, offset) = substate_id;
+            batch.put_cf(
+                &self.cf(SUBSTATE_HISTORY_CF),
+                encode_substate_history_key(substate_id, next_version),
+                REMOVED_SUBSTATE_HISTORY_VALUE,
+            );
+            batch.delete_cf(
+                &self.cf(substates_cf(*module_id)),
+                encode_substate_key(node_id, offset),
+            );
+        }
+
+        let mut tree_store = WriteBatchTreeStore::new(self, &mut batch);
+        let root_hash = put_at_next_version(
+            &mut tree_store,
+            current_version,
+            to_substate_hash_changes(&receipt.inputs, &receipt.outputs),
+        );
+        let metadata_cf = self.cf(METADATA_CF);
+        batch.put_cf(
+            &metadata_cf,
+            STATE_HASH_KEY,
+            scrypto_encode(&(next_version, root_hash)).unwrap(),
+        );
+        batch.put_cf(
+            &metadata_cf,
+            LAST_COMMITTED_TRANSACTION_KEY,
+            scrypto_encode(&LastCommittedTransaction {
+                state_version: next_version,
+                transaction_hash,
+            })
+            .unwrap(),
+        );
+        if let Some(committed_transaction) = committed_transaction {
+            batch.put_cf(
+                &self.cf(COMMIT_LOG_CF),
+                committed_transaction.state_version.to_be_bytes(),
+                scrypto_encode(committed_transaction).expect("Could not encode commit log entry"),
+            );
+            for event in StoredEvent::from_committed_transaction(committed_transaction) {
+                for (index, key) in encode_event_index_keys(&event) {
+                    batch.put_cf(&self.cf(event_index_cf(index)), key, b"");
+                }
+                batch.put_cf(
+                    &self.cf(EVENTS_CF),
+                    event.cursor.to_bytes(),
+                    scrypto_encode(&event).expect("Could not encode event"),
+                );
+            }
+        }
+
+        self.db.write(batch).unwrap();
+    }
+
+    fn read(&self, substate_id: &SubstateId) -> Option<Vec<u8>> {
+        let SubstateId(node_id, module_id, offset) = substate_id;
+        // TODO: Use get_pinned
+        self.db
+            .get_cf(
+                &self.cf(substates_cf(*module_id)),
+                encode_substate_key(node_id, offset),
             )
-            .unwrap();
+            .unwrap()
+    }
+
+    /// Writes the substate outside of any commit, i.e. without advancing the state version and
+    /// without recording it in the substate history (nor in the state hash tree). This is only
+    /// meant for setting up a store, as a historical read would not see the substate at all.
+    fn write(&self, substate_id: SubstateId, output_value: &OutputValue) {
+        let value =
+            scrypto_encode(output_value).expect("Could not encode substate for persistence");
+        let mut batch = WriteBatch::default();
+        self.stage_kv_store_index_update(&mut batch, &substate_id, Some(output_value));
+        let SubstateId(node_id, module_id, offset) = substate_id;
+        batch.put_cf(
+            &self.cf(substates_cf(module_id)),
+            encode_substate_key(&node_id, &offset),
+            value,
+        );
+        self.db.write(batch).unwrap();
+    }
+
+    /// Deletes the substate outside of any commit (see `write()`).
+    fn delete(&self, substate_id: &SubstateId) {
+        let mut batch = WriteBatch::default();
+        self.stage_kv_store_index_update(&mut batch, substate_id, None);
+        let SubstateId(node_id, module_id, offset) = substate_id;
+        batch.delete_cf(
+            &self.cf(substates_cf(*module_id)),
+            encode_substate_key(node_id, offset),
+        );
+        self.db.write(batch).unwrap();
     }
 }
 
-impl QueryableSubstateStore for RadixEngineDB {
+impl<M: ColumnFamilyAccess> QueryableSubstateStore for RadixEngineDBWithThreadMode<M> {
     fn get_kv_store_entries(
         &self,
         kv_store_id: &KeyValueStoreId,
     ) -> HashMap<Vec<u8>, PersistedSubstate> {
-        let mut iter = self.db.iterator(IteratorMode::Start);
-        let mut items = HashMap::new();
-        while let Some(kv) = iter.next() {
+        let iter = self.db.iterator_cf(
+            &self.cf(substates_cf(NodeModuleId::SELF)),
+            IteratorMode::Start,
+        );
+        collect_kv_store_entries(iter, kv_store_id)
+    }
+
+    fn list_substates(
+        &self,
+        node_id: &RENodeId,
+        module_id: Option<NodeModuleId>,
+        from: Option<&(NodeModuleId, SubstateOffset)>,
+        limit: usize,
+    ) -> Vec<(SubstateId, OutputValue)> {
+        list_node_substates(node_id, module_id, from, limit, |module_id, start| {
+            self.db.iterator_cf(
+                &self.cf(substates_cf(module_id)),
+                IteratorMode::From(start, Direction::Forward),
+            )
+        })
+    }
+}
+
+impl<M: ColumnFamilyAccess> ReadableSubstateStore for RadixEngineDBWithThreadMode<M> {
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        self.read(substate_id)
+            .map(|b| scrypto_decode(&b).expect("Could not decode persisted substate"))
+    }
+
+    fn list_kv_store_keys(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<Vec<u8>> {
+        list_indexed_kv_store_keys(kv_store_id, from, limit, |start| {
+            self.db.iterator_cf(
+                &self.cf(KV_STORE_KEYS_CF),
+                IteratorMode::From(start, Direction::Forward),
+            )
+        })
+    }
+}
+
+impl<M: ColumnFamilyAccess> WriteableSubstateStore for RadixEngineDBWithThreadMode<M> {
+    fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+        self.write(substate_id, &substate);
+    }
+
+    fn remove_substate(&mut self, substate_id: &SubstateId) {
+        self.delete(substate_id);
+    }
+
+    fn commit(&mut self, state_diff: &StateDiff) -> CommitReceipt {
+        let receipt = state_diff.to_commit_receipt();
+        self.write_batch(None, state_diff, &receipt, None);
+        receipt
+    }
+}
+
+impl<M: ColumnFamilyAccess> CommitLogStore for RadixEngineDBWithThreadMode<M> {
+    fn commit_and_log(
+        &mut self,
+        transaction_hash: Hash,
+        commit_result: &CommitResult,
+    ) -> CommittedTransaction {
+        let state_diff = &commit_result.state_updates;
+        let committed_transaction = CommittedTransaction::new(
+            self.get_state_version() + 1,
+            transaction_hash,
+            commit_result,
+            state_diff.to_commit_receipt(),
+        );
+        let receipt = CommitReceipt {
+            inputs: committed_transaction.inputs.clone(),
+            outputs: committed_transaction.outputs.clone(),
+        };
+        self.write_batch(
+            Some(transaction_hash),
+            state_diff,
+            &receipt,
+            Some(&committed_transaction),
+        );
+        committed_transaction
+    }
+
+    fn get_commit_log(&self, from_state_version: u64, limit: usize) -> Vec<CommittedTransaction> {
+        self.db
+            .iterator_cf(
+                &self.cf(COMMIT_LOG_CF),
+                IteratorMode::From(&from_state_version.to_be_bytes(), Direction::Forward),
+            )
+            .take(limit)
+            .map(|kv| {
+                let (_, value) = kv.unwrap();
+                scrypto_decode(&value).expect("Could not decode commit log entry")
+            })
+            .collect()
+    }
+}
+
+impl<M: ColumnFamilyAccess> EventStore for RadixEngineDBWithThreadMode<M> {
+    fn query_events(&self, query: &EventQuery) -> EventPage {
+        select_events(
+            query,
+            |index, start| {
+                self.db
+                    .iterator_cf(
+                        &self.cf(event_index_cf(index)),
+                        IteratorMode::From(&start, Direction::Forward),
+                    )
+                    .map(|kv| kv.unwrap().0.to_vec())
+            },
+            |from| {
+                self.db
+                    .iterator_cf(
+                        &self.cf(EVENTS_CF),
+                        IteratorMode::From(&from.to_bytes(), Direction::Forward),
+                    )
+                    .map(|kv| scrypto_decode(&kv.unwrap().1).expect("Could not decode event"))
+            },
+            |cursor: EventCursor| {
+                self.db
+                    .get_cf(&self.cf(EVENTS_CF), cursor.to_bytes())
+                    .unwrap()
+                    .map(|bytes| scrypto_decode(&bytes).expect("Could not decode event"))
+                    .expect("Indexed event not found")
+            },
+        )
+    }
+}
+
+impl<M: ColumnFamilyAccess> VersionedSubstateStore for RadixEngineDBWithThreadMode<M> {
+    fn get_state_version(&self) -> Version {
+        self.get_state_hash()
+            .map(|(version, _)| version)
+            .unwrap_or(0)
+    }
+
+    fn get_substate_at(
+        &self,
+        substate_id: &SubstateId,
+        state_version: Version,
+    ) -> Option<OutputValue> {
+        let key = encode_substate_history_key(substate_id, state_version);
+        // The closest preceding key is either the requested substate's latest value put at or
+        // before the given version, or some other substate's (if there is no such value).
+        let (found_key, value) = self
+            .db
+            .iterator_cf(
+                &self.cf(SUBSTATE_HISTORY_CF),
+                IteratorMode::From(&key, Direction::Reverse),
+            )
+            .next()?
+            .unwrap();
+        let substate_id_len = key.len() - 8;
+        if found_key.len() == key.len() && found_key[..substate_id_len] == key[..substate_id_len] {
+            decode_substate_history_value(&value)
+        } else {
+            None
+        }
+    }
+
+    fn list_substates_at(
+        &self,
+        node_id: &RENodeId,
+        module_id: Option<NodeModuleId>,
+        from: Option<&(NodeModuleId, SubstateOffset)>,
+        limit: usize,
+        state_version: Version,
+    ) -> Vec<(SubstateId, OutputValue)> {
+        let prefix = encode_substate_history_key_prefix(node_id);
+        let from_key = from.map(|(module_id, offset)| substate_listing_key(*module_id, offset));
+        let iter = self.db.iterator_cf(
+            &self.cf(SUBSTATE_HISTORY_CF),
+            IteratorMode::From(&prefix, Direction::Forward),
+        );
+        // The history entries are ordered by the encoded substate ID (i.e. by the module and the
+        // encoded offset, within a RENode) and then by version.
+        let mut substates: Vec<(SubstateId, OutputValue)> = Vec::new();
+        for kv in iter {
             let (key, value) = kv.unwrap();
-            let substate_id: SubstateId = scrypto_decode(&key).unwrap();
+            if !key.starts_with(&prefix) {
+                break;
+            }
+            let (substate_id, version) = decode_substate_history_key(&key);
+            if version > state_version
+                || module_id.map_or(false, |module_id| module_id != substate_id.1)
+                || from_key.as_ref().map_or(false, |from_key| {
+                    substate_listing_key(substate_id.1, &substate_id.2) < *from_key
+                })
+            {
+                continue;
+            }
+            let is_latest_listed = substates.last().map_or(false, |(last_substate_id, _)| {
+                *last_substate_id == substate_id
+            });
+            match decode_substate_history_value(&value) {
+                Some(output_value) if is_latest_listed => {
+                    substates.last_mut().unwrap().1 = output_value;
+                }
+                Some(output_value) => {
+                    if substates.len() == limit {
+                        break;
+                    }
+                    substates.push((substate_id, output_value));
+                }
+                None if is_latest_listed => {
+                    substates.pop();
+                }
+                None => {}
+            }
+        }
+        substates
+    }
+
+    fn get_kv_store_entries_at(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        state_version: Version,
+    ) -> HashMap<Vec<u8>, PersistedSubstate> {
+        let prefix = encode_substate_history_key_prefix(&RENodeId::KeyValueStore(*kv_store_id));
+        let mut iter = self.db.raw_iterator_cf(&self.cf(SUBSTATE_HISTORY_CF));
+        iter.seek(&prefix);
+        let mut items = HashMap::new();
+        // Visits each entry once: reads its value as of the given version, then seeks past all
+        // its other values.
+        while let Some(key) = iter.key().filter(|key| key.starts_with(&prefix)) {
+            let (substate_id, _) = decode_substate_history_key(key);
             if let SubstateId(
-                RENodeId::KeyValueStore(id),
+                _,
                 NodeModuleId::SELF,
                 SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(entry_id)),
-            ) = substate_id
+            ) = &substate_id
             {
-                let substate: OutputValue = scrypto_decode(&value.to_vec()).unwrap();
-                if id == *kv_store_id {
-                    items.insert(entry_id, substate.substate);
+                if let Some(output_value) = self.get_substate_at(&substate_id, state_version) {
+                    items.insert(entry_id.clone(), output_value.substate);
                 }
             }
+            iter.seek(encode_substate_history_key(&substate_id, Version::MAX));
+            iter.next();
         }
+        iter.status().unwrap();
         items
     }
 }
 
-impl ReadableSubstateStore for RadixEngineDB {
+impl<M: ColumnFamilyAccess, P: Payload> ReadableTreeStore<P> for RadixEngineDBWithThreadMode<M> {
+    fn get_node(&self, key: &NodeKey) -> Option<TreeNode<P>> {
+        self.db
+            .get_cf(&self.cf(TREE_NODES_CF), encode_key(key))
+            .unwrap()
+            .map(|bytes| scrypto_decode(&bytes).expect("Could not decode tree node"))
+    }
+}
+
+impl<M: ColumnFamilyAccess> PrunableTreeStore for RadixEngineDBWithThreadMode<M> {
+    /// Deletes the stale nodes and their stale node index entries in a single `WriteBatch`.
+    fn delete_stale_nodes(&mut self, up_to_version: Version) -> usize {
+        let stale_tree_nodes_cf = self.cf(STALE_TREE_NODES_CF);
+        let tree_nodes_cf = self.cf(TREE_NODES_CF);
+        let mut batch = WriteBatch::default();
+        let mut count = 0;
+        for kv in self
+            .db
+            .iterator_cf(&stale_tree_nodes_cf, IteratorMode::Start)
+        {
+            let (key, _value) = kv.unwrap();
+            let stale_node = decode_stale_node(key.as_ref());
+            if stale_node.stale_since_version > up_to_version {
+                break;
+            }
+            batch.delete_cf(&stale_tree_nodes_cf, key);
+            batch.delete_cf(&tree_nodes_cf, encode_key(&stale_node.key));
+            count += 1;
+        }
+        self.db.write(batch).unwrap();
+        count
+    }
+}
+
+impl RadixEngineDBWithThreadMode<MultiThreaded> {
+    /// Takes a snapshot of the last committed state.
+    /// Since every commit is written atomically, the snapshot is always pinned at a committed
+    /// version (see `RadixEngineDBSnapshot::get_state_hash()`), regardless of any concurrent
+    /// commits.
+    pub fn snapshot(&self) -> RadixEngineDBSnapshot {
+        RadixEngineDBSnapshot {
+            inner: Arc::new(PinnedSnapshot::new(self.db.clone(), |db| db.snapshot())),
+        }
+    }
+}
+
+/// A read-only view of a `MultiThreadedRadixEngineDB`, pinned at the version it was taken at.
+/// It is cheap to clone and can be shared with other threads; the underlying RocksDB snapshot is
+/// released when the last clone is dropped (even if the `MultiThreadedRadixEngineDB` it was taken
+/// from is already gone).
+#[derive(Clone)]
+pub struct RadixEngineDBSnapshot {
+    inner: Arc<PinnedSnapshot>,
+}
+
+type DBSnapshot<'a> = SnapshotWithThreadMode<'a, DBWithThreadMode<MultiThreaded>>;
+
+self_cell!(
+    /// A RocksDB snapshot, along with the DB it borrows (which is kept open for as long as the
+    /// snapshot is alive).
+    struct PinnedSnapshot {
+        owner: Arc<DBWithThreadMode<MultiThreaded>>,
+
+        #[covariant]
+        dependent: DBSnapshot,
+    }
+);
+
+impl RadixEngineDBSnapshot {
+    /// Returns the version and the root hash of the state this snapshot is pinned at, or `None`
+    /// if no transaction was committed before taking it.
+    pub fn get_state_hash(&self) -> Option<(Version, Hash)> {
+        self.read(METADATA_CF, STATE_HASH_KEY)
+            .map(|bytes| scrypto_decode(&bytes).expect("Could not decode state hash"))
+    }
+
+    /// Returns the marker of the last transaction committed before taking this snapshot.
+    pub fn get_last_committed_transaction(&self) -> Option<LastCommittedTransaction> {
+        self.read(METADATA_CF, LAST_COMMITTED_TRANSACTION_KEY)
+            .map(|bytes| {
+                scrypto_decode(&bytes).expect("Could not decode last committed transaction")
+            })
+    }
+
+    fn read<K: AsRef<[u8]>>(&self, cf_name: &str, key: K) -> Option<Vec<u8>> {
+        self.inner
+            .borrow_dependent()
+            .get_cf(&cf(self.inner.borrow_owner(), cf_name), key)
+            .unwrap()
+    }
+}
+
+impl QueryableSubstateStore for RadixEngineDBSnapshot {
+    fn get_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+    ) -> HashMap<Vec<u8>, PersistedSubstate> {
+        let iter = self.inner.borrow_dependent().iterator_cf(
+            &cf(self.inner.borrow_owner(), substates_cf(NodeModuleId::SELF)),
+            IteratorMode::Start,
+        );
+        collect_kv_store_entries(iter, kv_store_id)
+    }
+
+    fn list_substates(
+        &self,
+        node_id: &RENodeId,
+        module_id: Option<NodeModuleId>,
+        from: Option<&(NodeModuleId, SubstateOffset)>,
+        limit: usize,
+    ) -> Vec<(SubstateId, OutputValue)> {
+        list_node_substates(node_id, module_id, from, limit, |module_id, start| {
+            self.inner.borrow_dependent().iterator_cf(
+                &cf(self.inner.borrow_owner(), substates_cf(module_id)),
+                IteratorMode::From(start, Direction::Forward),
+            )
+        })
+    }
+}
+
+impl ReadableSubstateStore for RadixEngineDBSnapshot {
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
-        self.read(substate_id)
-            .map(|b| scrypto_decode(&b).expect("Could not decode persisted substate"))
+        let SubstateId(node_id, module_id, offset) = substate_id;
+        self.read(
+            substates_cf(*module_id),
+            encode_substate_key(node_id, offset),
+        )
+        .map(|b| scrypto_decode(&b).expect("Could not decode persisted substate"))
+    }
+
+    fn list_kv_store_keys(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<Vec<u8>> {
+        list_indexed_kv_store_keys(kv_store_id, from, limit, |start| {
+            self.inner.borrow_dependent().iterator_cf(
+                &cf(self.inner.borrow_owner(), KV_STORE_KEYS_CF),
+                IteratorMode::From(start, Direction::Forward),
+            )
+        })
     }
 }
 
-impl WriteableSubstateStore for RadixEngineDB {
-    fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
-        self.write(
-            substate_id,
-            scrypto_encode(&substate).expect("Could not encode substate for persistence"),
+impl<P: Payload> ReadableTreeStore<P> for RadixEngineDBSnapshot {
+    fn get_node(&self, key: &NodeKey) -> Option<TreeNode<P>> {
+        self.read(TREE_NODES_CF, encode_key(key))
+            .map(|bytes| scrypto_decode(&bytes).expect("Could not decode tree node"))
+    }
+}
+
+/// A `TreeStore` which reads the tree nodes already committed to the `RadixEngineDB` and stages
+/// all the writes into a `WriteBatch` (so that they are committed atomically, together with the
+/// substates they represent).
+struct WriteBatchTreeStore<'a, M: ColumnFamilyAccess> {
+    db: &'a RadixEngineDBWithThreadMode<M>,
+    batch: &'a mut WriteBatch,
+    staged_nodes: HashMap<Vec<u8>, Vec<u8>>,
+}
+
+impl<'a, M: ColumnFamilyAccess> WriteBatchTreeStore<'a, M> {
+    fn new(db: &'a RadixEngineDBWithThreadMode<M>, batch: &'a mut WriteBatch) -> Self {
+        Self {
+            db,
+            batch,
+            staged_nodes: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, M: ColumnFamilyAccess, P: Payload> ReadableTreeStore<P> for WriteBatchTreeStore<'a, M> {
+    fn get_node(&self, key: &NodeKey) -> Option<TreeNode<P>> {
+        match self.staged_nodes.get(&encode_key(key)) {
+            Some(bytes) => Some(scrypto_decode(bytes).expect("Could not decode tree node")),
+            None => ReadableTreeStore::<P>::get_node(self.db, key),
+        }
+    }
+}
+
+impl<'a, M: ColumnFamilyAccess, P: Payload> WriteableTreeStore<P> for WriteBatchTreeStore<'a, M> {
+    fn insert_node(&mut self, key: NodeKey, node: TreeNode<P>) {
+        let key_bytes = encode_key(&key);
+        let node_bytes = scrypto_encode(&node).expect("Could not encode tree node");
+        self.batch
+            .put_cf(&self.db.cf(TREE_NODES_CF), &key_bytes, &node_bytes);
+        self.staged_nodes.insert(key_bytes, node_bytes);
+    }
+
+    fn record_stale_node(&mut self, key: NodeKey, stale_since_version: Version) {
+        let stale_node = StaleNode {
+            stale_since_version,
+            key,
+        };
+        self.batch.put_cf(
+            &self.db.cf(STALE_TREE_NODES_CF),
+            encode_stale_node(&stale_node),
+            [0u8; 0],
         );
     }
 }
+
+/// Returns the handle of the given column family.
+///
+/// # Panics
+/// Panics if the column family was not opened.
+fn cf<'a, M: ColumnFamilyAccess>(db: &'a DBWithThreadMode<M>, name: &str) -> M::Handle<'a> {
+    M::cf_handle(db, name).unwrap_or_else(|| panic!("Column family {} not opened", name))
+}
+
+/// Lists the substates of a RENode from its modules' column families, using the given function to
+/// iterate a module's column family from a key (see `QueryableSubstateStore::list_substates()`).
+/// Since the modules' column families are visited in the order of `NodeModuleId`s, and the keys
+/// within them end with the encoded offset, the listing order matches `substate_listing_key()`.
+fn list_node_substates<I: Iterator<Item = Result<(Box<[u8]>, Box<[u8]>), rocksdb::Error>>>(
+    node_id: &RENodeId,
+    module_id: Option<NodeModuleId>,
+    from: Option<&(NodeModuleId, SubstateOffset)>,
+    limit: usize,
+    iterate_from: impl Fn(NodeModuleId, &[u8]) -> I,
+) -> Vec<(SubstateId, OutputValue)> {
+    let prefix = encode_substate_key_prefix(node_id);
+    let mut substates = Vec::new();
+    for listed_module_id in SUBSTATE_MODULES {
+        if module_id.map_or(false, |module_id| module_id != listed_module_id) {
+            continue;
+        }
+        let start = match from {
+            Some((from_module_id, _)) if *from_module_id > listed_module_id => continue,
+            Some((from_module_id, from_offset)) if *from_module_id == listed_module_id => {
+                encode_substate_key(node_id, from_offset)
+            }
+            _ => prefix.clone(),
+        };
+        for kv in iterate_from(listed_module_id, &start) {
+            if substates.len() == limit {
+                return substates;
+            }
+            let (key, value) = kv.unwrap();
+            if !key.starts_with(&prefix) {
+                break;
+            }
+            let (_, offset): (RENodeId, SubstateOffset) = scrypto_decode(&key).unwrap();
+            substates.push((
+                SubstateId(node_id.clone(), listed_module_id, offset),
+                scrypto_decode(&value).expect("Could not decode persisted substate"),
+            ));
+        }
+    }
+    substates
+}
+
+/// Lists the keys of the given key-value store's entries from the `KV_STORE_KEYS_CF` index, using
+/// the given function to iterate the index from a key (see
+/// `ReadableSubstateStore::list_kv_store_keys()`).
+/// Only the listed keys are visited, since the index is ordered by the raw entry keys.
+fn list_indexed_kv_store_keys<
+    I: Iterator<Item = Result<(Box<[u8]>, Box<[u8]>), rocksdb::Error>>,
+>(
+    kv_store_id: &KeyValueStoreId,
+    from: Option<&[u8]>,
+    limit: usize,
+    iterate_from: impl FnOnce(&[u8]) -> I,
+) -> Vec<Vec<u8>> {
+    let prefix = encode_kv_store_index_key_prefix(kv_store_id);
+    let mut start = prefix.clone();
+    if let Some(from) = from {
+        start.extend_from_slice(from);
+    }
+    iterate_from(&start)
+        .map(|kv| kv.unwrap().0)
+        .take_while(|key| key.starts_with(&prefix))
+        .take(limit)
+        .map(|key| key[prefix.len()..].to_vec())
+        .collect()
+}
+
+/// Collects the entries of the given key-value store from an iterator over the `SELF` module's
+/// column family.
+fn collect_kv_store_entries(
+    iter: impl Iterator<Item = Result<(Box<[u8]>, Box<[u8]>), rocksdb::Error>>,
+    kv_store_id: &KeyValueStoreId,
+) -> HashMap<Vec<u8>, PersistedSubstate> {
+    let mut items = HashMap::new();
+    for kv in iter {
+        let (key, value) = kv.unwrap();
+        let substate_key: (RENodeId, SubstateOffset) = scrypto_decode(&key).unwrap();
+        if let (
+            RENodeId::KeyValueStore(id),
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(entry_id)),
+        ) = substate_key
+        {
+            let substate: OutputValue = scrypto_decode(&value.to_vec()).unwrap();
+            if id == *kv_store_id {
+                items.insert(entry_id, substate.substate);
+            }
+        }
+    }
+    items
+}
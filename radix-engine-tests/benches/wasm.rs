@@ -21,7 +21,7 @@ fn bench_wasm_instantiation(c: &mut Criterion) {
     let package_address = PackageAddress::Normal([0u8; 26]);
     let code = include_bytes!("../../assets/faucet.wasm").to_vec();
     let pretend_instrumented_code = InstrumentedCode {
-        metered_code_key: (package_address, WasmMeteringConfig::V0),
+        metered_code_key: ((package_address, 0), WasmMeteringConfig::V0),
         code: Arc::new(code),
     };
     c.bench_function("WASM::instantiate_wasm", |b| {
@@ -36,7 +36,7 @@ fn bench_wasm_instantiation_pre_loaded(c: &mut Criterion) {
     let package_address = PackageAddress::Normal([0u8; 26]);
     let code = include_bytes!("../../assets/faucet.wasm").to_vec();
     let pretend_instrumented_code = InstrumentedCode {
-        metered_code_key: (package_address, WasmMeteringConfig::V0),
+        metered_code_key: ((package_address, 0), WasmMeteringConfig::V0),
         code: Arc::new(code),
     };
     let engine = DefaultWasmEngine::default();
@@ -3,6 +3,7 @@ use radix_engine_interface::api::node_modules::auth::AuthAddresses;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::rule;
 use scrypto_unit::*;
+use transaction::bls12381::Bls12381PrivateKey;
 use transaction::builder::ManifestBuilder;
 
 fn test_auth_rule(
@@ -316,3 +317,28 @@ fn cannot_withdraw_from_my_any_xrd_auth_account_with_less_than_amount_of_proof()
     // Assert
     receipt.expect_specific_failure(is_auth_error)
 }
+
+#[test]
+fn can_withdraw_from_account_protected_by_bls12381_key_with_its_signature() {
+    let mut test_runner = TestRunner::builder().build();
+    let public_key = Bls12381PrivateKey::from_u64(1).unwrap().public_key();
+    let (other_public_key, _, _) = test_runner.new_key_pair_with_auth_address();
+    let auth = rule!(require(NonFungibleGlobalId::from_public_key(&public_key)));
+
+    test_auth_rule(&mut test_runner, &auth, &[public_key.into()], true);
+    test_auth_rule(&mut test_runner, &auth, &[other_public_key.into()], false);
+}
+
+#[test]
+fn bls12381_key_has_no_virtual_account_or_identity() {
+    // Arrange
+    let public_key: PublicKey = Bls12381PrivateKey::from_u64(1).unwrap().public_key().into();
+
+    // Act
+    let account_address = ComponentAddress::try_virtual_account_from_public_key(&public_key);
+    let identity_address = ComponentAddress::try_virtual_identity_from_public_key(&public_key);
+
+    // Assert
+    assert_eq!(account_address, None);
+    assert_eq!(identity_address, None);
+}
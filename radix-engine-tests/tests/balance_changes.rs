@@ -62,7 +62,7 @@ fn test_balance_changes_when_success() {
         result.balance_changes(),
         &indexmap!(
             FAUCET_COMPONENT.into() => indexmap!(
-                RADIX_TOKEN => BalanceChange::Fungible(-(result.fee_summary.total_execution_cost_xrd + result.fee_summary.total_royalty_cost_xrd))
+                RADIX_TOKEN => BalanceChange::Fungible(-(result.fee_summary.total_execution_cost_xrd + result.fee_summary.total_royalty_cost_xrd + result.fee_summary.total_storage_cost_xrd))
             ),
             package_address.into() => indexmap!(
                 RADIX_TOKEN => BalanceChange::Fungible(dec!("0.0000002"))
@@ -139,7 +139,7 @@ fn test_balance_changes_when_failure() {
         result.direct_vault_updates(),
         &indexmap!(
             vault_id => indexmap!(
-                RADIX_TOKEN => BalanceChange::Fungible(-(result.fee_summary.total_execution_cost_xrd + result.fee_summary.total_royalty_cost_xrd))
+                RADIX_TOKEN => BalanceChange::Fungible(-(result.fee_summary.total_execution_cost_xrd + result.fee_summary.total_royalty_cost_xrd + result.fee_summary.total_storage_cost_xrd))
             )
         )
     )
@@ -174,7 +174,7 @@ fn test_balance_changes_when_recall() {
         result.balance_changes(),
         &indexmap!(
             FAUCET_COMPONENT.into() => indexmap!(
-                RADIX_TOKEN => BalanceChange::Fungible(-(result.fee_summary.total_execution_cost_xrd + result.fee_summary.total_royalty_cost_xrd))
+                RADIX_TOKEN => BalanceChange::Fungible(-(result.fee_summary.total_execution_cost_xrd + result.fee_summary.total_royalty_cost_xrd + result.fee_summary.total_storage_cost_xrd))
             ),
             other_account.into() => indexmap!(
                 recallable_token => BalanceChange::Fungible(dec!("1"))
@@ -219,7 +219,7 @@ fn test_balance_changes_when_transferring_non_fungibles() {
         result.balance_changes(),
         &indexmap!(
             FAUCET_COMPONENT.into() => indexmap!(
-                RADIX_TOKEN => BalanceChange::Fungible(-(result.fee_summary.total_execution_cost_xrd + result.fee_summary.total_royalty_cost_xrd))
+                RADIX_TOKEN => BalanceChange::Fungible(-(result.fee_summary.total_execution_cost_xrd + result.fee_summary.total_royalty_cost_xrd + result.fee_summary.total_storage_cost_xrd))
             ),
             account.into() => indexmap!(
                 resource_address => BalanceChange::NonFungible {
@@ -0,0 +1,52 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod crypto_utils_test {
+    struct CryptoUtilsTest {}
+
+    impl CryptoUtilsTest {
+        pub fn verify_ecdsa_secp256k1(
+            message: Vec<u8>,
+            public_key: EcdsaSecp256k1PublicKey,
+            signature: Vec<u8>,
+        ) -> bool {
+            CryptoUtils::verify_ecdsa_secp256k1(&message, &public_key, &signature)
+        }
+
+        pub fn verify_eddsa_ed25519(
+            message: Vec<u8>,
+            public_key: EddsaEd25519PublicKey,
+            signature: Vec<u8>,
+        ) -> bool {
+            CryptoUtils::verify_eddsa_ed25519(&message, &public_key, &signature)
+        }
+
+        pub fn verify_bls12381(
+            message_hash: Hash,
+            public_key: Bls12381G1PublicKey,
+            signature: Vec<u8>,
+        ) -> bool {
+            CryptoUtils::verify_bls12381(&message_hash, &public_key, &signature)
+        }
+
+        pub fn aggregate_verify_bls12381(
+            message_hash: Hash,
+            public_keys: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+            signature: Vec<u8>,
+        ) -> bool {
+            CryptoUtils::aggregate_verify_bls12381(&message_hash, &public_keys, &signature)
+        }
+
+        pub fn keccak256_hash(data: Vec<u8>) -> Hash {
+            CryptoUtils::keccak256_hash(data)
+        }
+
+        pub fn sha256_hash(data: Vec<u8>) -> Hash {
+            CryptoUtils::sha256_hash(data)
+        }
+
+        pub fn blake2b_256_hash(data: Vec<u8>) -> Hash {
+            CryptoUtils::blake2b_256_hash(data)
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use scrypto::prelude::*;
+
+// The schema exported with the Radiswap package in /assets
+import_blueprint!("../../../../assets/radiswap.schema", "Radiswap");
+
+#[blueprint]
+mod import_blueprint_caller {
+    struct ImportBlueprintCaller {}
+
+    impl ImportBlueprintCaller {
+        pub fn swap_with_new_pool(
+            radiswap_package: PackageAddress,
+            a_tokens: Bucket,
+            b_tokens: Bucket,
+            input_tokens: Bucket,
+        ) -> (Bucket, Bucket) {
+            let (component_address, lp_tokens) = RadiswapBlueprint::at(radiswap_package)
+                .instantiate_pool(
+                    a_tokens,
+                    b_tokens,
+                    dec!("1000"),
+                    "LP".to_owned(),
+                    "LP token".to_owned(),
+                    "https://www.radiswap.com".to_owned(),
+                    dec!("0.01"),
+                );
+
+            let mut radiswap = RadiswapGlobalComponentRef::at(component_address);
+            let output_tokens = radiswap.swap(input_tokens);
+
+            (lp_tokens, output_tokens)
+        }
+    }
+}
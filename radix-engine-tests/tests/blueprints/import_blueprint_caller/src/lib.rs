@@ -0,0 +1 @@
+pub mod import_blueprint_caller;
@@ -0,0 +1,64 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod iteration {
+    struct Iteration {
+        map: KeyValueStore<u8, String>,
+    }
+
+    impl Iteration {
+        pub fn new(count: u8) -> ComponentAddress {
+            let map = KeyValueStore::new();
+            // Inserted out of order, to check that iteration is ordered
+            for key in (0..count).rev() {
+                map.insert(key, key.to_string());
+            }
+            Iteration { map }.instantiate().globalize()
+        }
+
+        pub fn iterate_in_memory(count: u8) {
+            let map = KeyValueStore::new();
+            for key in (0..count).rev() {
+                map.insert(key, key.to_string());
+            }
+            assert_eq!(map.remove(&0u8), Some("0".to_owned()));
+            assert_eq!(map.remove(&0u8), None);
+
+            let entries: Vec<(u8, String)> = map.iter().collect();
+            let expected: Vec<(u8, String)> =
+                (1..count).map(|key| (key, key.to_string())).collect();
+            assert_eq!(entries, expected);
+            assert_eq!(map.len(), count as usize - 1);
+            Iteration { map }.instantiate().globalize();
+        }
+
+        pub fn insert(&mut self, key: u8) {
+            self.map.insert(key, key.to_string());
+        }
+
+        pub fn remove(&mut self, key: u8) -> Option<String> {
+            self.map.remove(&key)
+        }
+
+        pub fn len(&self) -> usize {
+            self.map.len()
+        }
+
+        pub fn keys(&self) -> Vec<u8> {
+            self.map.iter().map(|(key, _)| key).collect()
+        }
+
+        pub fn page(&self, from: Option<u8>, limit: u32) -> Vec<u8> {
+            self.map.keys(from.as_ref(), limit)
+        }
+
+        pub fn remove_while_iterating(&mut self) -> Vec<u8> {
+            let mut visited = Vec::new();
+            for (key, _) in self.map.iter() {
+                visited.push(key);
+                self.map.remove(&(key + 1));
+            }
+            visited
+        }
+    }
+}
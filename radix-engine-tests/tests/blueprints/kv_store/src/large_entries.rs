@@ -0,0 +1,32 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod large_entries {
+    struct LargeEntries {
+        map: KeyValueStore<u32, Vec<u8>>,
+    }
+
+    impl LargeEntries {
+        pub fn new() -> ComponentAddress {
+            LargeEntries {
+                map: KeyValueStore::new(),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn insert(&mut self, key: u32, size: u32) {
+            self.map.insert(key, vec![0u8; size as usize]);
+        }
+
+        pub fn spin_and_remove(&mut self, key: u32, rounds: u32) {
+            let mut n: u64 = 0;
+            for _ in 0..rounds {
+                n += 1;
+                // Avoid loop being optimised away!
+                std::hint::black_box(n);
+            }
+            self.map.remove(&key);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod cyclic_map;
+pub mod iteration;
 pub mod kv_store;
+pub mod large_entries;
 pub mod multiple_reads;
 pub mod precommitted;
 pub mod ref_check;
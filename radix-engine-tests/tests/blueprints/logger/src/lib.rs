@@ -15,5 +15,19 @@ mod logger {
             info!("{}", message);
             panic!("I'm panicking!")
         }
+
+        pub fn structured_log(message: String) {
+            log!(Level::Warn, message, answer = 42, unit = "units");
+        }
+
+        pub fn nested_log(message: String) {
+            debug!("Calling nested");
+            Runtime::call_function::<_, _, ()>(
+                Runtime::package_address(),
+                "Logger",
+                "no_panic_log",
+                scrypto_args!(message),
+            );
+        }
     }
 }
@@ -0,0 +1,29 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod guarded_counter {
+    struct GuardedCounter {
+        count: u32,
+        admin: NonFungibleGlobalId,
+    }
+
+    impl GuardedCounter {
+        pub fn new(admin: NonFungibleGlobalId) -> ComponentAddress {
+            Self { count: 0, admin }
+                .instantiate()
+                .globalize_with_access_rules(
+                    AccessRulesConfig::new()
+                        .method("increment", rule!(require("admin")), rule!(deny_all))
+                        .default(rule!(allow_all), AccessRule::DenyAll),
+                )
+        }
+
+        pub fn increment(&mut self) {
+            self.count += 1;
+        }
+
+        pub fn get(&self) -> u32 {
+            self.count
+        }
+    }
+}
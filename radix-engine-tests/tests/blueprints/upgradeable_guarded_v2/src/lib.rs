@@ -0,0 +1,43 @@
+use scrypto::prelude::*;
+
+/// The state of `GuardedCounter` as published by `upgradeable_guarded_v1`.
+#[derive(ScryptoSbor)]
+pub struct GuardedCounterV1 {
+    count: u32,
+    admin: NonFungibleGlobalId,
+}
+
+#[blueprint]
+mod guarded_counter {
+    struct GuardedCounter {
+        count: u64,
+        admin: NonFungibleGlobalId,
+    }
+
+    impl GuardedCounter {
+        pub fn migrate(old: GuardedCounterV1) -> GuardedCounter {
+            Self {
+                count: old.count.into(),
+                admin: old.admin,
+            }
+        }
+
+        pub fn new(admin: NonFungibleGlobalId) -> ComponentAddress {
+            Self { count: 0, admin }
+                .instantiate()
+                .globalize_with_access_rules(
+                    AccessRulesConfig::new()
+                        .method("increment", rule!(require("admin")), rule!(deny_all))
+                        .default(rule!(allow_all), AccessRule::DenyAll),
+                )
+        }
+
+        pub fn increment(&mut self) {
+            self.count += 1;
+        }
+
+        pub fn get(&self) -> u64 {
+            self.count
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod counter {
+    struct Counter {
+        count: u32,
+    }
+
+    impl Counter {
+        pub fn new() -> ComponentAddress {
+            Self { count: 0 }.instantiate().globalize()
+        }
+
+        pub fn increment(&mut self) {
+            self.count += 1;
+        }
+
+        pub fn get(&self) -> u32 {
+            self.count
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use scrypto::prelude::*;
+
+/// The state of `Counter` as published by `upgradeable_v1`.
+#[derive(ScryptoSbor)]
+pub struct CounterV1 {
+    count: u32,
+}
+
+#[blueprint]
+mod counter {
+    struct Counter {
+        count: u64,
+        label: String,
+    }
+
+    impl Counter {
+        pub fn migrate(old: CounterV1) -> Counter {
+            Self {
+                count: old.count.into(),
+                label: "migrated".to_owned(),
+            }
+        }
+
+        pub fn new() -> ComponentAddress {
+            Self {
+                count: 0,
+                label: "new".to_owned(),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn increment(&mut self) {
+            self.count += 1;
+        }
+
+        pub fn get(&self) -> u64 {
+            self.count
+        }
+
+        pub fn label(&self) -> String {
+            self.label.clone()
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use scrypto::prelude::*;
+
+/// The state of `Counter` as published by `upgradeable_v2`.
+#[derive(ScryptoSbor)]
+pub struct CounterV2 {
+    count: u64,
+    label: String,
+}
+
+#[blueprint]
+mod counter {
+    struct Counter {
+        count: u64,
+        label: String,
+    }
+
+    impl Counter {
+        /// Only migrates from the state of `upgradeable_v2`, which breaks the components still
+        /// holding the state of `upgradeable_v1`.
+        pub fn migrate(old: CounterV2) -> Counter {
+            Self {
+                count: old.count,
+                label: old.label,
+            }
+        }
+
+        pub fn new() -> ComponentAddress {
+            Self {
+                count: 0,
+                label: "new".to_owned(),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn increment(&mut self) {
+            self.count += 1;
+        }
+
+        pub fn get(&self) -> u64 {
+            self.count
+        }
+
+        pub fn label(&self) -> String {
+            self.label.clone()
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use radix_engine::system::kernel_modules::costing::{CostingReason, FeeTable};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn transfer(test_runner: &mut TestRunner, fail: bool) -> TransactionReceipt {
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let mut builder = ManifestBuilder::new();
+    builder
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(account, RADIX_TOKEN, 1.into());
+    if fail {
+        builder.assert_worktop_contains_by_amount(2.into(), RADIX_TOKEN);
+    }
+    let manifest = builder
+        .call_method(
+            other_account,
+            ACCOUNT_DEPOSIT_BATCH_IDENT,
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn dropping_nodes_created_by_the_transaction_is_partially_credited() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = transfer(&mut test_runner, false);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    let create_node_cost = fee_summary.execution_cost_breakdown[&CostingReason::CreateNode];
+    let create_node_credit = fee_summary.execution_credit_breakdown[&CostingReason::CreateNode];
+    assert!(create_node_credit > 0);
+    assert!(create_node_credit <= FeeTable::new().cleanup_credit(create_node_cost));
+    assert!(fee_summary.execution_credit_sum < fee_summary.execution_cost_sum);
+    assert_eq!(fee_summary.tip_percentage, 0);
+    assert_eq!(
+        fee_summary.total_execution_cost_xrd,
+        fee_summary.cost_unit_price
+            * Decimal::from(fee_summary.execution_cost_sum - fee_summary.execution_credit_sum)
+    );
+}
+
+#[test]
+fn failed_transactions_are_not_credited() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = transfer(&mut test_runner, true);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_failure().fee_summary;
+    assert_eq!(fee_summary.execution_credit_sum, 0);
+    assert!(fee_summary.execution_credit_breakdown.is_empty());
+}
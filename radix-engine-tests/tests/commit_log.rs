@@ -0,0 +1,111 @@
+use radix_engine::ledger::*;
+use radix_engine::state_manager::{
+    CommitFeed, CommitLogStore, CommitSubscriber, CommittedTransaction,
+};
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::resource::FromPublicKey;
+use scrypto_unit::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use transaction::builder::ManifestBuilder;
+
+#[derive(Clone, Default)]
+struct RecordingSubscriber {
+    committed_transactions: Rc<RefCell<Vec<CommittedTransaction>>>,
+}
+
+impl CommitSubscriber for RecordingSubscriber {
+    fn on_commit(&mut self, committed_transaction: &CommittedTransaction) {
+        self.committed_transactions
+            .borrow_mut()
+            .push(committed_transaction.clone());
+    }
+}
+
+fn transfer(
+    test_runner: &mut TestRunner,
+    public_key: EcdsaSecp256k1PublicKey,
+    from: ComponentAddress,
+    to: ComponentAddress,
+) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(from, 10.into())
+        .withdraw_from_account(from, RADIX_TOKEN, 1.into())
+        .call_method(
+            to,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_subscriber_is_notified_about_each_commit_in_order() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account1) = test_runner.new_allocated_account();
+    let (_, _, account2) = test_runner.new_allocated_account();
+    let subscriber = RecordingSubscriber::default();
+    test_runner.subscribe_to_commits(Box::new(subscriber.clone()));
+    let state_version_before = test_runner.get_state_version();
+
+    // Act
+    transfer(&mut test_runner, public_key, account1, account2);
+    transfer(&mut test_runner, public_key, account2, account1);
+
+    // Assert
+    let committed_transactions = subscriber.committed_transactions.borrow();
+    assert_eq!(
+        committed_transactions
+            .iter()
+            .map(|committed_transaction| committed_transaction.state_version)
+            .collect::<Vec<_>>(),
+        vec![state_version_before + 1, state_version_before + 2]
+    );
+    for committed_transaction in committed_transactions.iter() {
+        assert!(!committed_transaction.outputs.is_empty());
+        assert_eq!(
+            committed_transaction.outputs.len(),
+            committed_transaction.state_updates.up_substates.len()
+        );
+    }
+}
+
+#[test]
+fn test_commit_log_replays_the_notified_commits() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account1) = test_runner.new_allocated_account();
+    let (_, _, account2) = test_runner.new_allocated_account();
+    let subscriber = RecordingSubscriber::default();
+    test_runner.subscribe_to_commits(Box::new(subscriber.clone()));
+    let state_version_before = test_runner.get_state_version();
+    transfer(&mut test_runner, public_key, account1, account2);
+    transfer(&mut test_runner, public_key, account2, account1);
+
+    // Act
+    let mut replaying_subscriber = RecordingSubscriber::default();
+    let next_state_version = CommitFeed::replay(
+        test_runner.substate_store(),
+        state_version_before + 1,
+        &mut replaying_subscriber,
+    );
+
+    // Assert
+    assert_eq!(next_state_version, test_runner.get_state_version() + 1);
+    assert_eq!(
+        *replaying_subscriber.committed_transactions.borrow(),
+        *subscriber.committed_transactions.borrow()
+    );
+    assert_eq!(
+        test_runner
+            .substate_store()
+            .get_commit_log(state_version_before + 2, 10),
+        subscriber.committed_transactions.borrow()[1..].to_vec()
+    );
+}
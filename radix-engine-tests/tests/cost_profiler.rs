@@ -0,0 +1,116 @@
+use radix_engine::system::kernel_modules::costing::CostingReason;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn cost_profile_is_not_collected_by_default() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(receipt.execution_trace.cost_profile.is_none());
+}
+
+#[test]
+fn cost_profile_attributes_execution_cost_to_call_stacks() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/recursion");
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "Caller", "recursive", manifest_args!(3u32))
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest_with_cost_profiling(manifest, vec![]);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    let cost_profile = receipt.execution_trace.cost_profile.as_ref().unwrap();
+    let transaction_cost: u32 = fee_summary
+        .execution_cost_breakdown
+        .iter()
+        .filter(|(reason, _)| {
+            matches!(
+                reason,
+                CostingReason::TxBaseCost
+                    | CostingReason::TxPayloadCost
+                    | CostingReason::TxSignatureVerification
+            )
+        })
+        .map(|(_, cost_units)| cost_units)
+        .sum();
+    assert_eq!(
+        cost_profile.execution_cost_sum(),
+        fee_summary.execution_cost_sum - transaction_cost
+    );
+
+    let caller_stacks: Vec<_> = cost_profile
+        .call_stacks
+        .iter()
+        .filter(|(stack, _)| {
+            stack
+                .last()
+                .map(|frame| frame.package_address == package_address && frame.ident == "recursive")
+                == Some(true)
+        })
+        .collect();
+    assert_eq!(caller_stacks.len(), 3);
+    for (stack, cost) in &caller_stacks {
+        assert_eq!(cost.calls, 1);
+        assert!(cost.wasm_cost_units() > 0);
+        assert_eq!(
+            stack.last().unwrap().depth,
+            stack.first().unwrap().depth + stack.len() - 1
+        );
+    }
+    assert!(
+        cost_profile.inclusive_execution_cost(package_address, "Caller", "recursive")
+            > caller_stacks
+                .iter()
+                .map(|(_, cost)| cost.execution_cost_sum())
+                .max()
+                .unwrap()
+    );
+}
+
+#[test]
+fn cost_profile_can_be_formatted_as_collapsed_stacks() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/recursion");
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "Caller", "recursive", manifest_args!(2u32))
+        .build();
+    let receipt = test_runner.execute_manifest_with_cost_profiling(manifest, vec![]);
+    let cost_profile = receipt.execution_trace.cost_profile.as_ref().unwrap();
+    let bech32_encoder = Bech32Encoder::for_simulator();
+
+    // Act
+    let collapsed_stacks = cost_profile.to_collapsed_stacks(&bech32_encoder);
+
+    // Assert
+    let caller = format!(
+        "{}::Caller::recursive",
+        bech32_encoder.encode_package_address_to_string(&package_address)
+    );
+    let mut cost_units_sum = 0u32;
+    for line in collapsed_stacks.lines() {
+        let (stack, cost_units) = line.rsplit_once(' ').unwrap();
+        assert!(!stack.contains(' '));
+        cost_units_sum += cost_units.parse::<u32>().unwrap();
+    }
+    assert_eq!(cost_units_sum, cost_profile.execution_cost_sum());
+    assert!(collapsed_stacks
+        .lines()
+        .any(|line| line.contains(&format!("{};{} ", caller, caller))));
+}
@@ -0,0 +1,246 @@
+use radix_engine::system::kernel_modules::costing::{CostingReason, FeeTable};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::bls12381::{Bls12381G2Signature, Bls12381PrivateKey};
+use transaction::builder::ManifestBuilder;
+use transaction::ecdsa_secp256k1::EcdsaSecp256k1PrivateKey;
+use transaction::eddsa_ed25519::EddsaEd25519PrivateKey;
+
+fn call_crypto_utils(function_name: &str, args: Vec<u8>) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/crypto_utils");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "CryptoUtilsTest", function_name, args)
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn can_verify_ecdsa_secp256k1_signature() {
+    // Arrange
+    let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+    let message = b"Price: 42".to_vec();
+    let signature = private_key.sign(&hash(&message)).to_vec();
+
+    // Act
+    let valid = call_crypto_utils(
+        "verify_ecdsa_secp256k1",
+        manifest_args!(message, private_key.public_key(), signature.clone()),
+    );
+    let invalid = call_crypto_utils(
+        "verify_ecdsa_secp256k1",
+        manifest_args!(b"Price: 43".to_vec(), private_key.public_key(), signature),
+    );
+
+    // Assert
+    assert!(valid.expect_commit_success().output::<bool>(1));
+    assert!(!invalid.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn can_verify_eddsa_ed25519_signature() {
+    // Arrange
+    let private_key = EddsaEd25519PrivateKey::from_u64(1).unwrap();
+    let message = b"Price: 42".to_vec();
+    let signature = private_key.sign(&hash(&message)).to_vec();
+
+    // Act
+    let valid = call_crypto_utils(
+        "verify_eddsa_ed25519",
+        manifest_args!(message, private_key.public_key(), signature.clone()),
+    );
+    let invalid = call_crypto_utils(
+        "verify_eddsa_ed25519",
+        manifest_args!(b"Price: 43".to_vec(), private_key.public_key(), signature),
+    );
+
+    // Assert
+    assert!(valid.expect_commit_success().output::<bool>(1));
+    assert!(!invalid.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn can_verify_bls12381_signature() {
+    // Arrange
+    let private_key = Bls12381PrivateKey::from_u64(1).unwrap();
+    let message_hash = hash("Price: 42");
+    let signature = private_key.sign(&message_hash).to_vec();
+
+    // Act
+    let valid = call_crypto_utils(
+        "verify_bls12381",
+        manifest_args!(message_hash, private_key.public_key(), signature.clone()),
+    );
+    let invalid = call_crypto_utils(
+        "verify_bls12381",
+        manifest_args!(hash("Price: 43"), private_key.public_key(), signature),
+    );
+
+    // Assert
+    assert!(valid.expect_commit_success().output::<bool>(1));
+    assert!(!invalid.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn can_verify_aggregate_bls12381_signature() {
+    // Arrange
+    let private_keys: Vec<Bls12381PrivateKey> = (1..4)
+        .map(|n| Bls12381PrivateKey::from_u64(n).unwrap())
+        .collect();
+    let public_keys: Vec<(Bls12381G1PublicKey, Vec<u8>)> = private_keys
+        .iter()
+        .map(|sk| (sk.public_key(), sk.prove_possession().to_vec()))
+        .collect();
+    let message_hash = hash("Price: 42");
+    let signatures: Vec<Bls12381G2Signature> = private_keys
+        .iter()
+        .map(|sk| sk.sign(&message_hash))
+        .collect();
+    let signature = Bls12381G2Signature::aggregate(&signatures)
+        .unwrap()
+        .to_vec();
+
+    // Act
+    let valid = call_crypto_utils(
+        "aggregate_verify_bls12381",
+        manifest_args!(message_hash, public_keys.clone(), signature.clone()),
+    );
+    let missing_signer = call_crypto_utils(
+        "aggregate_verify_bls12381",
+        manifest_args!(message_hash, public_keys[..2].to_vec(), signature),
+    );
+
+    // Assert
+    assert!(valid.expect_commit_success().output::<bool>(1));
+    assert!(!missing_signer.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn aggregate_bls12381_verification_rejects_key_without_proof_of_possession() {
+    // Arrange
+    let private_keys: Vec<Bls12381PrivateKey> = (1..3)
+        .map(|n| Bls12381PrivateKey::from_u64(n).unwrap())
+        .collect();
+    let message_hash = hash("Price: 42");
+    let signatures: Vec<Bls12381G2Signature> = private_keys
+        .iter()
+        .map(|sk| sk.sign(&message_hash))
+        .collect();
+    let signature = Bls12381G2Signature::aggregate(&signatures)
+        .unwrap()
+        .to_vec();
+    // The second key comes with the first one's proof of possession
+    let public_keys: Vec<(Bls12381G1PublicKey, Vec<u8>)> = private_keys
+        .iter()
+        .map(|sk| (sk.public_key(), private_keys[0].prove_possession().to_vec()))
+        .collect();
+
+    // Act
+    let receipt = call_crypto_utils(
+        "aggregate_verify_bls12381",
+        manifest_args!(message_hash, public_keys, signature),
+    );
+
+    // Assert
+    assert!(!receipt.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn aggregate_bls12381_verification_is_charged_per_key() {
+    // Arrange
+    let private_key = Bls12381PrivateKey::from_u64(1).unwrap();
+    let message_hash = hash("Price: 42");
+    let signature = private_key.sign(&message_hash).to_vec();
+
+    // Act
+    let receipt = call_crypto_utils(
+        "aggregate_verify_bls12381",
+        manifest_args!(
+            message_hash,
+            vec![(
+                private_key.public_key(),
+                private_key.prove_possession().to_vec()
+            )],
+            signature
+        ),
+    );
+
+    // Assert
+    let fee_table = FeeTable::new();
+    assert_eq!(
+        receipt
+            .expect_commit_success()
+            .fee_summary
+            .execution_cost_breakdown[&CostingReason::RunCryptoUtils],
+        fee_table.verify_bls12381_cost + fee_table.aggregate_verify_bls12381_cost_per_key
+    );
+}
+
+#[test]
+fn malformed_signature_is_reported_as_invalid() {
+    // Arrange
+    let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+    let message = b"Price: 42".to_vec();
+
+    // Act
+    let receipt = call_crypto_utils(
+        "verify_ecdsa_secp256k1",
+        manifest_args!(message, private_key.public_key(), vec![1u8, 2, 3]),
+    );
+
+    // Assert
+    assert!(!receipt.expect_commit_success().output::<bool>(1));
+}
+
+#[test]
+fn can_hash_with_native_hash_functions() {
+    // Arrange
+    let data = b"Hello Radix".to_vec();
+
+    // Act
+    let keccak256 = call_crypto_utils("keccak256_hash", manifest_args!(data.clone()));
+    let sha256 = call_crypto_utils("sha256_hash", manifest_args!(data.clone()));
+    let blake2b_256 = call_crypto_utils("blake2b_256_hash", manifest_args!(data.clone()));
+
+    // Assert
+    assert_eq!(
+        keccak256.expect_commit_success().output::<Hash>(1),
+        keccak256_hash(&data)
+    );
+    assert_eq!(
+        sha256.expect_commit_success().output::<Hash>(1),
+        sha256_hash(&data)
+    );
+    assert_eq!(
+        blake2b_256.expect_commit_success().output::<Hash>(1),
+        blake2b_256_hash(&data)
+    );
+}
+
+#[test]
+fn crypto_utils_are_charged_according_to_fee_table() {
+    // Arrange
+    let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+    let message = vec![42u8; 1024];
+    let signature = private_key.sign(&hash(&message)).to_vec();
+
+    // Act
+    let receipt = call_crypto_utils(
+        "verify_ecdsa_secp256k1",
+        manifest_args!(message, private_key.public_key(), signature),
+    );
+
+    // Assert
+    let fee_table = FeeTable::new();
+    assert!(receipt.expect_commit_success().output::<bool>(1));
+    assert_eq!(
+        receipt
+            .expect_commit_success()
+            .fee_summary
+            .execution_cost_breakdown[&CostingReason::RunCryptoUtils],
+        fee_table.verify_ecdsa_secp256k1_cost + fee_table.hash_cost_per_byte * 1024
+    );
+}
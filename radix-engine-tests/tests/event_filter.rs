@@ -0,0 +1,127 @@
+use radix_engine::blueprints::account::{DepositEvent, WithdrawEvent};
+use radix_engine::blueprints::resource::WithdrawResourceEvent;
+use radix_engine::system::node_modules::metadata::SetMetadataEvent;
+use radix_engine::transaction::{CommitResult, EventFilter};
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::resource::VAULT_BLUEPRINT;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn transfer(
+    test_runner: &mut TestRunner,
+    from: ComponentAddress,
+    public_key: EcdsaSecp256k1PublicKey,
+    to: ComponentAddress,
+    amount: Decimal,
+) -> CommitResult {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(from, RADIX_TOKEN, amount)
+        .call_method(
+            to,
+            ACCOUNT_DEPOSIT_BATCH_IDENT,
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success().clone()
+}
+
+#[test]
+fn typed_events_can_be_filtered_by_emitter() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let result = transfer(
+        &mut test_runner,
+        account,
+        public_key,
+        other_account,
+        5.into(),
+    );
+
+    // Act
+    let deposits = result
+        .typed_events::<DepositEvent, _>(
+            test_runner.substate_store(),
+            &EventFilter::new().emitter(RENodeId::GlobalObject(other_account.into())),
+        )
+        .unwrap();
+    let withdrawals = result
+        .typed_events::<WithdrawEvent, _>(
+            test_runner.substate_store(),
+            &EventFilter::new().emitter(RENodeId::GlobalObject(other_account.into())),
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(deposits.len(), 1);
+    assert_eq!(deposits[0].1, DepositEvent::Fungible(RADIX_TOKEN, 5.into()));
+    assert!(withdrawals.is_empty());
+}
+
+#[test]
+fn events_can_be_filtered_by_blueprint_and_name() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let result = transfer(
+        &mut test_runner,
+        account,
+        public_key,
+        other_account,
+        5.into(),
+    );
+
+    // Act
+    let account_events = result
+        .filter_events(
+            test_runner.substate_store(),
+            &EventFilter::new().blueprint(ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT),
+        )
+        .unwrap();
+    let vault_withdrawals = result
+        .typed_events::<WithdrawResourceEvent, _>(
+            test_runner.substate_store(),
+            &EventFilter::new().blueprint(RESOURCE_MANAGER_PACKAGE, VAULT_BLUEPRINT),
+        )
+        .unwrap();
+
+    // Assert
+    let account_event_names: Vec<&str> = account_events
+        .iter()
+        .map(|(_, resolved, _)| resolved.event_name.as_str())
+        .collect();
+    assert_eq!(account_event_names, vec!["WithdrawEvent", "DepositEvent"]);
+    assert_eq!(vault_withdrawals.len(), 1);
+    assert!(vault_withdrawals[0].1 == WithdrawResourceEvent::Amount(5.into()));
+}
+
+#[test]
+fn typed_events_of_a_type_not_emitted_are_empty() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let result = transfer(
+        &mut test_runner,
+        account,
+        public_key,
+        other_account,
+        5.into(),
+    );
+
+    // Act
+    let events = result
+        .typed_events::<SetMetadataEvent, _>(test_runner.substate_store(), &EventFilter::new())
+        .unwrap();
+
+    // Assert
+    assert!(events.is_empty());
+}
@@ -0,0 +1,198 @@
+use radix_engine::transaction::resolve_event_type;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_stores::event_store::{EventQuery, EventStore, StoredEvent};
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn transfer(
+    test_runner: &mut TestRunner,
+    public_key: EcdsaSecp256k1PublicKey,
+    from: ComponentAddress,
+    to: ComponentAddress,
+) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(from, RADIX_TOKEN, 1.into())
+        .call_method(
+            to,
+            ACCOUNT_DEPOSIT_BATCH_IDENT,
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+}
+
+fn event_name(test_runner: &TestRunner, event: &StoredEvent) -> String {
+    resolve_event_type(test_runner.substate_store(), &event.event_type_identifier)
+        .unwrap()
+        .0
+        .event_name
+}
+
+#[test]
+fn events_can_be_queried_by_emitter_since_a_state_version() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_event_store().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    transfer(&mut test_runner, public_key, account, other_account);
+    let second_transfer_version = test_runner.get_state_version() + 1;
+    transfer(&mut test_runner, public_key, account, other_account);
+
+    // Act
+    let page = test_runner.event_store().query_events(
+        &EventQuery::new(100)
+            .emitter(RENodeId::GlobalObject(other_account.into()))
+            .since(second_transfer_version),
+    );
+
+    // Assert
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(page.events.len(), 1);
+    assert_eq!(page.events[0].cursor.state_version, second_transfer_version);
+    assert_eq!(event_name(&test_runner, &page.events[0]), "DepositEvent");
+}
+
+#[test]
+fn events_can_be_queried_by_type_and_version_range() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_event_store().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let first_transfer_version = test_runner.get_state_version() + 1;
+    transfer(&mut test_runner, public_key, account, other_account);
+    transfer(&mut test_runner, public_key, account, other_account);
+    transfer(&mut test_runner, public_key, account, other_account);
+    let deposit_event_type = test_runner
+        .event_store()
+        .query_events(
+            &EventQuery::new(1)
+                .emitter(RENodeId::GlobalObject(other_account.into()))
+                .since(first_transfer_version),
+        )
+        .events[0]
+        .event_type_identifier
+        .clone();
+
+    // Act
+    let page = test_runner.event_store().query_events(
+        &EventQuery::new(100)
+            .event_type(deposit_event_type.clone())
+            .since(first_transfer_version)
+            .until(first_transfer_version + 1),
+    );
+
+    // Assert
+    assert_eq!(
+        page.events
+            .iter()
+            .map(|event| event.cursor.state_version)
+            .collect::<Vec<_>>(),
+        vec![first_transfer_version, first_transfer_version + 1]
+    );
+    for event in &page.events {
+        assert_eq!(event.event_type_identifier, deposit_event_type);
+    }
+}
+
+#[test]
+fn paginated_query_returns_all_the_events_in_order() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_event_store().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let first_transfer_version = test_runner.get_state_version() + 1;
+    transfer(&mut test_runner, public_key, account, other_account);
+    transfer(&mut test_runner, public_key, other_account, account);
+    let all_events = test_runner
+        .event_store()
+        .query_events(&EventQuery::new(usize::MAX).since(first_transfer_version))
+        .events;
+
+    // Act
+    let mut paginated_events = Vec::new();
+    let mut query = EventQuery::new(3).since(first_transfer_version);
+    loop {
+        let page = test_runner.event_store().query_events(&query);
+        assert!(page.events.len() <= 3);
+        paginated_events.extend(page.events);
+        match page.next_cursor {
+            Some(cursor) => query = query.from_cursor(cursor),
+            None => break,
+        }
+    }
+
+    // Assert
+    assert!(all_events.len() > 3);
+    assert_eq!(paginated_events, all_events);
+    assert!(all_events
+        .windows(2)
+        .all(|pair| pair[0].cursor < pair[1].cursor));
+}
+
+#[test]
+fn paginated_query_with_zero_limit_returns_one_event_per_page() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_event_store().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let first_transfer_version = test_runner.get_state_version() + 1;
+    transfer(&mut test_runner, public_key, account, other_account);
+    let all_events = test_runner
+        .event_store()
+        .query_events(&EventQuery::new(usize::MAX).since(first_transfer_version))
+        .events;
+
+    // Act
+    let mut paginated_events = Vec::new();
+    let mut query = EventQuery::new(0).since(first_transfer_version);
+    loop {
+        let page = test_runner.event_store().query_events(&query);
+        assert_eq!(page.events.len(), 1);
+        paginated_events.extend(page.events);
+        match page.next_cursor {
+            Some(cursor) => query = query.from_cursor(cursor),
+            None => break,
+        }
+    }
+
+    // Assert
+    assert!(all_events.len() > 1);
+    assert_eq!(paginated_events, all_events);
+}
+
+#[test]
+fn query_by_type_of_another_emitter_returns_nothing() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_event_store().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let first_transfer_version = test_runner.get_state_version() + 1;
+    transfer(&mut test_runner, public_key, account, other_account);
+    let deposit_event_type = test_runner
+        .event_store()
+        .query_events(
+            &EventQuery::new(1)
+                .emitter(RENodeId::GlobalObject(other_account.into()))
+                .since(first_transfer_version),
+        )
+        .events[0]
+        .event_type_identifier
+        .clone();
+
+    // Act
+    let page = test_runner.event_store().query_events(
+        &EventQuery::new(100)
+            .emitter(RENodeId::GlobalObject(account.into()))
+            .event_type(deposit_event_type),
+    );
+
+    // Assert
+    assert!(page.events.is_empty());
+    assert_eq!(page.next_cursor, None);
+}
@@ -1,3 +1,4 @@
+use radix_engine::blueprints::account::{DepositEvent, WithdrawEvent};
 use radix_engine::blueprints::epoch_manager::{
     ClaimXrdEvent, EpochChangeEvent, RegisterValidatorEvent, RoundChangeEvent, StakeEvent,
     UnregisterValidatorEvent, UnstakeEvent, UpdateAcceptingStakeDelegationStateEvent,
@@ -28,10 +29,6 @@ use transaction::model::{Instruction, SystemTransaction};
 // TODO: In the future, the ClientAPI should only be able to add events to the event store. It
 // should not be able to have full control over it.
 
-// TODO: Creation of proofs triggers withdraw and deposit events when the amount is still liquid.
-// This is not the intended behavior. Should figure out a solution to that so that it doesn't emit
-// that and clean up this test to have one event.
-
 //=========
 // Scrypto
 //=========
@@ -241,7 +238,7 @@ fn vault_fungible_recall_emits_correct_events() {
     // Assert
     {
         let events = receipt.expect_commit(true).clone().application_events;
-        assert_eq!(events.len(), 4); // Four events: vault lock fee, vault fungible withdraw, vault fungible recall, vault fungible deposit
+        assert_eq!(events.len(), 4); // Four events: vault lock fee, vault fungible recall, vault fungible deposit, account deposit
         assert!(match events.get(0) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
@@ -251,14 +248,12 @@ fn vault_fungible_recall_emits_correct_events() {
                 true,
             _ => false,
         });
-        // TODO: Currently recall first emits a withdraw event and then a recall event. Should the
-        // redundant withdraw event go away or does it make sense from a user perspective?
         assert!(match events.get(1) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ref event_data,
-            )) if test_runner.is_event_name_equal::<WithdrawResourceEvent>(event_identifier)
-                && is_decoded_equal(&WithdrawResourceEvent::Amount(1.into()), event_data) =>
+            )) if test_runner.is_event_name_equal::<RecallResourceEvent>(event_identifier)
+                && is_decoded_equal(&RecallResourceEvent::Amount(1.into()), event_data) =>
                 true,
             _ => false,
         });
@@ -266,17 +261,24 @@ fn vault_fungible_recall_emits_correct_events() {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ref event_data,
-            )) if test_runner.is_event_name_equal::<RecallResourceEvent>(event_identifier)
-                && is_decoded_equal(&RecallResourceEvent::Amount(1.into()), event_data) =>
+            )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier)
+                && is_decoded_equal(&DepositResourceEvent::Amount(1.into()), event_data) =>
                 true,
             _ => false,
         });
         assert!(match events.get(3) {
             Some((
-                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                event_identifier @ EventTypeIdentifier(
+                    Emitter::Method(node_id, NodeModuleId::SELF),
+                    ..,
+                ),
                 ref event_data,
-            )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier)
-                && is_decoded_equal(&DepositResourceEvent::Amount(1.into()), event_data) =>
+            )) if test_runner.is_event_name_equal::<DepositEvent>(event_identifier)
+                && is_decoded_equal(
+                    &DepositEvent::Fungible(recallable_resource_address, 1.into()),
+                    event_data
+                )
+                && *node_id == RENodeId::GlobalObject(account.into()) =>
                 true,
             _ => false,
         });
@@ -333,7 +335,7 @@ fn vault_non_fungible_recall_emits_correct_events() {
     // Assert
     {
         let events = receipt.expect_commit(true).clone().application_events;
-        assert_eq!(events.len(), 4); // Four events: vault lock fee, vault non-fungible withdraw, vault non-fungible recall, vault non-fungible deposit
+        assert_eq!(events.len(), 4); // Four events: vault lock fee, vault non-fungible recall, vault non-fungible deposit, account deposit
         assert!(match events.get(0) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
@@ -343,14 +345,12 @@ fn vault_non_fungible_recall_emits_correct_events() {
                 true,
             _ => false,
         });
-        // TODO: Currently recall first emits a withdraw event and then a recall event. Should the
-        // redundant withdraw event go away or does it make sense from a user perspective?
         assert!(match events.get(1) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ref event_data,
-            )) if test_runner.is_event_name_equal::<WithdrawResourceEvent>(event_identifier)
-                && is_decoded_equal(&WithdrawResourceEvent::Amount(1.into()), event_data) =>
+            )) if test_runner.is_event_name_equal::<RecallResourceEvent>(event_identifier)
+                && is_decoded_equal(&RecallResourceEvent::Amount(1.into()), event_data) =>
                 true,
             _ => false,
         });
@@ -358,8 +358,11 @@ fn vault_non_fungible_recall_emits_correct_events() {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ref event_data,
-            )) if test_runner.is_event_name_equal::<RecallResourceEvent>(event_identifier)
-                && is_decoded_equal(&RecallResourceEvent::Amount(1.into()), event_data) =>
+            )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier)
+                && is_decoded_equal(
+                    &DepositResourceEvent::Ids([non_fungible_local_id.clone()].into()),
+                    event_data
+                ) =>
                 true,
             _ => false,
         });
@@ -367,9 +370,12 @@ fn vault_non_fungible_recall_emits_correct_events() {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ref event_data,
-            )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier)
+            )) if test_runner.is_event_name_equal::<DepositEvent>(event_identifier)
                 && is_decoded_equal(
-                    &DepositResourceEvent::Ids([non_fungible_local_id.clone()].into()),
+                    &DepositEvent::NonFungible(
+                        recallable_resource_address,
+                        [non_fungible_local_id.clone()].into()
+                    ),
                     event_data
                 ) =>
                 true,
@@ -378,6 +384,118 @@ fn vault_non_fungible_recall_emits_correct_events() {
     }
 }
 
+#[test]
+fn creating_a_proof_from_a_vault_emits_no_resource_events() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account_by_amount(account, RADIX_TOKEN, 1.into())
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    {
+        let events = receipt.expect_commit(true).clone().application_events;
+        assert_eq!(events.len(), 1); // One event: vault lock fee
+        assert!(match events.get(0) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ..,
+            )) if test_runner.is_event_name_equal::<LockFeeEvent>(event_identifier) => true,
+            _ => false,
+        });
+    }
+}
+
+//=========
+// Account
+//=========
+
+#[test]
+fn account_withdraw_and_deposit_emit_correct_events() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(account, RADIX_TOKEN, 5.into())
+        .call_method(
+            other_account,
+            ACCOUNT_DEPOSIT_BATCH_IDENT,
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    {
+        let events = receipt.expect_commit(true).clone().application_events;
+        assert_eq!(events.len(), 5); // Five events: vault lock fee, vault withdraw, account withdraw, vault deposit, account deposit
+        assert!(match events.get(1) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<WithdrawResourceEvent>(event_identifier)
+                && is_decoded_equal(&WithdrawResourceEvent::Amount(5.into()), event_data) =>
+                true,
+            _ => false,
+        });
+        assert!(match events.get(2) {
+            Some((
+                event_identifier @ EventTypeIdentifier(
+                    Emitter::Method(node_id, NodeModuleId::SELF),
+                    ..,
+                ),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<WithdrawEvent>(event_identifier)
+                && is_decoded_equal(
+                    &WithdrawEvent::Fungible(RADIX_TOKEN, 5.into()),
+                    event_data
+                )
+                && *node_id == RENodeId::GlobalObject(account.into()) =>
+                true,
+            _ => false,
+        });
+        assert!(match events.get(3) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier)
+                && is_decoded_equal(&DepositResourceEvent::Amount(5.into()), event_data) =>
+                true,
+            _ => false,
+        });
+        assert!(match events.get(4) {
+            Some((
+                event_identifier @ EventTypeIdentifier(
+                    Emitter::Method(node_id, NodeModuleId::SELF),
+                    ..,
+                ),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<DepositEvent>(event_identifier)
+                && is_decoded_equal(&DepositEvent::Fungible(RADIX_TOKEN, 5.into()), event_data)
+                && *node_id == RENodeId::GlobalObject(other_account.into()) =>
+                true,
+            _ => false,
+        });
+    }
+}
+
 //==================
 // Resource Manager
 //==================
@@ -409,7 +527,7 @@ fn resource_manager_new_vault_emits_correct_events() {
     // Assert
     {
         let events = receipt.expect_commit(true).clone().application_events;
-        assert_eq!(events.len(), 3); // Three events: vault lock fee, resource manager create vault, vault fungible deposit
+        assert_eq!(events.len(), 4); // Four events: vault lock fee, resource manager create vault, vault fungible deposit, account deposit
         assert!(match events.get(0) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
@@ -441,6 +559,13 @@ fn resource_manager_new_vault_emits_correct_events() {
                 true,
             _ => false,
         });
+        assert!(match events.get(3) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ..,
+            )) if test_runner.is_event_name_equal::<DepositEvent>(event_identifier) => true,
+            _ => false,
+        });
     }
 }
 
@@ -861,7 +986,7 @@ fn validator_staking_emits_correct_event() {
     // Assert
     {
         let events = receipt.expect_commit(true).clone().application_events;
-        assert_eq!(events.len(), 7); // Seven events: vault lock fee, vault withdraw fungible, resource manager mint (lp tokens), vault deposit event, validator stake event, resource manager vault create (for the LP tokens), vault deposit
+        assert_eq!(events.len(), 9); // Nine events: vault lock fee, vault withdraw fungible, account withdraw, resource manager mint (lp tokens), vault deposit event, validator stake event, resource manager vault create (for the LP tokens), vault deposit, account deposit
         assert!(match events.get(0) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
@@ -881,6 +1006,18 @@ fn validator_staking_emits_correct_event() {
             _ => false,
         });
         assert!(match events.get(2) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<WithdrawEvent>(event_identifier)
+                && is_decoded_equal(
+                    &WithdrawEvent::Fungible(RADIX_TOKEN, 100.into()),
+                    event_data
+                ) =>
+                true,
+            _ => false,
+        });
+        assert!(match events.get(3) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
@@ -889,7 +1026,7 @@ fn validator_staking_emits_correct_event() {
                 true,
             _ => false,
         });
-        assert!(match events.get(3) {
+        assert!(match events.get(4) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ref event_data,
@@ -898,7 +1035,7 @@ fn validator_staking_emits_correct_event() {
                 true,
             _ => false,
         });
-        assert!(match events.get(4) {
+        assert!(match events.get(5) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ref event_data,
@@ -912,7 +1049,7 @@ fn validator_staking_emits_correct_event() {
                 true,
             _ => false,
         });
-        assert!(match events.get(5) {
+        assert!(match events.get(6) {
             Some((
                 event_identifier @ EventTypeIdentifier(
                     Emitter::Method(
@@ -925,13 +1062,20 @@ fn validator_staking_emits_correct_event() {
             )) if test_runner.is_event_name_equal::<VaultCreationEvent>(event_identifier) => true,
             _ => false,
         });
-        assert!(match events.get(6) {
+        assert!(match events.get(7) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
             )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier) => true,
             _ => false,
         });
+        assert!(match events.get(8) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ..,
+            )) if test_runner.is_event_name_equal::<DepositEvent>(event_identifier) => true,
+            _ => false,
+        });
     }
 }
 
@@ -989,18 +1133,20 @@ fn validator_unstake_emits_correct_events() {
     {
         let events = receipt.expect_commit(true).clone().application_events;
         /*
-        Nine Events:
+        Eleven Events:
         1. Lock Fee event
         2. Vault withdraw event (LP Tokens)
-        3. Resource Manager burn event (LP Tokens)
-        4. Vault withdraw event (withdraw from stake vault)
-        5. Vault deposit event (deposit into stake vault)
-        6. Resource Manager Mint (minting unstake redeem tokens)
-        7. Validator Unstake event
-        8. Resource Manager Vault creation event (unstake redeem tokens)
-        9. Vault Deposit Event (unstake redeem tokens)
+        3. Account withdraw event (LP Tokens)
+        4. Resource Manager burn event (LP Tokens)
+        5. Vault withdraw event (withdraw from stake vault)
+        6. Vault deposit event (deposit into stake vault)
+        7. Resource Manager Mint (minting unstake redeem tokens)
+        8. Validator Unstake event
+        9. Resource Manager Vault creation event (unstake redeem tokens)
+        10. Vault Deposit Event (unstake redeem tokens)
+        11. Account Deposit Event (unstake redeem tokens)
          */
-        assert_eq!(events.len(), 9);
+        assert_eq!(events.len(), 11);
         assert!(match events.get(0) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
@@ -1020,6 +1166,18 @@ fn validator_unstake_emits_correct_events() {
             _ => false,
         });
         assert!(match events.get(2) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<WithdrawEvent>(event_identifier)
+                && is_decoded_equal(
+                    &WithdrawEvent::Fungible(validator_substate.liquidity_token, 1.into()),
+                    event_data
+                ) =>
+                true,
+            _ => false,
+        });
+        assert!(match events.get(3) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ref event_data,
@@ -1032,7 +1190,7 @@ fn validator_unstake_emits_correct_events() {
                 true,
             _ => false,
         });
-        assert!(match events.get(3) {
+        assert!(match events.get(4) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
@@ -1040,14 +1198,14 @@ fn validator_unstake_emits_correct_events() {
                 true,
             _ => false,
         });
-        assert!(match events.get(4) {
+        assert!(match events.get(5) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
             )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier) => true,
             _ => false,
         });
-        assert!(match events.get(5) {
+        assert!(match events.get(6) {
             Some((
                 event_identifier @ EventTypeIdentifier(
                     Emitter::Method(
@@ -1063,14 +1221,14 @@ fn validator_unstake_emits_correct_events() {
                 true,
             _ => false,
         });
-        assert!(match events.get(6) {
+        assert!(match events.get(7) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
             )) if test_runner.is_event_name_equal::<UnstakeEvent>(event_identifier) => true,
             _ => false,
         });
-        assert!(match events.get(7) {
+        assert!(match events.get(8) {
             Some((
                 event_identifier @ EventTypeIdentifier(
                     Emitter::Method(
@@ -1083,13 +1241,20 @@ fn validator_unstake_emits_correct_events() {
             )) if test_runner.is_event_name_equal::<VaultCreationEvent>(event_identifier) => true,
             _ => false,
         });
-        assert!(match events.get(8) {
+        assert!(match events.get(9) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
             )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier) => true,
             _ => false,
         });
+        assert!(match events.get(10) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ..,
+            )) if test_runner.is_event_name_equal::<DepositEvent>(event_identifier) => true,
+            _ => false,
+        });
     }
 }
 
@@ -1163,16 +1328,18 @@ fn validator_claim_xrd_emits_correct_events() {
     {
         let events = receipt.expect_commit(true).clone().application_events;
         /*
-        Seven Events:
+        Nine Events:
         1. Vault lock fee event
         2. Vault withdraw event (unstake nft)
-        3. Resource Manager burn event (unstake nft)
-        4. Vault withdraw event (unstaked xrd)
-        5. Claim XRD
-        5. Resource Manager vault creation event (XRD)
-        6. Vault deposit event (XRD)
+        3. Account withdraw event (unstake nft)
+        4. Resource Manager burn event (unstake nft)
+        5. Vault withdraw event (unstaked xrd)
+        6. Claim XRD
+        7. Resource Manager vault creation event (XRD)
+        8. Vault deposit event (XRD)
+        9. Account deposit event (XRD)
          */
-        assert_eq!(events.len(), 7);
+        assert_eq!(events.len(), 9);
         assert!(match events.get(0) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
@@ -1192,6 +1359,13 @@ fn validator_claim_xrd_emits_correct_events() {
             _ => false,
         });
         assert!(match events.get(2) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ..,
+            )) if test_runner.is_event_name_equal::<WithdrawEvent>(event_identifier) => true,
+            _ => false,
+        });
+        assert!(match events.get(3) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
@@ -1200,7 +1374,7 @@ fn validator_claim_xrd_emits_correct_events() {
                 true,
             _ => false,
         });
-        assert!(match events.get(3) {
+        assert!(match events.get(4) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
@@ -1208,14 +1382,14 @@ fn validator_claim_xrd_emits_correct_events() {
                 true,
             _ => false,
         });
-        assert!(match events.get(4) {
+        assert!(match events.get(5) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
             )) if test_runner.is_event_name_equal::<ClaimXrdEvent>(event_identifier) => true,
             _ => false,
         });
-        assert!(match events.get(5) {
+        assert!(match events.get(6) {
             Some((
                 event_identifier @ EventTypeIdentifier(
                     Emitter::Method(
@@ -1228,13 +1402,20 @@ fn validator_claim_xrd_emits_correct_events() {
             )) if test_runner.is_event_name_equal::<VaultCreationEvent>(event_identifier) => true,
             _ => false,
         });
-        assert!(match events.get(6) {
+        assert!(match events.get(7) {
             Some((
                 event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
                 ..,
             )) if test_runner.is_event_name_equal::<DepositResourceEvent>(event_identifier) => true,
             _ => false,
         });
+        assert!(match events.get(8) {
+            Some((
+                event_identifier @ EventTypeIdentifier(Emitter::Method(_, NodeModuleId::SELF), ..),
+                ..,
+            )) if test_runner.is_event_name_equal::<DepositEvent>(event_identifier) => true,
+            _ => false,
+        });
     }
 }
 
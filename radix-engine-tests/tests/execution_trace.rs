@@ -61,7 +61,9 @@ fn test_trace_resource_transfers() {
     ); // One resource change in the first instruction (lock fee)
 
     let fee_summary = receipt.expect_commit(true).fee_summary.clone();
-    let total_fee_paid = fee_summary.total_execution_cost_xrd + fee_summary.total_royalty_cost_xrd
+    let total_fee_paid = fee_summary.total_execution_cost_xrd
+        + fee_summary.total_royalty_cost_xrd
+        + fee_summary.total_storage_cost_xrd
         - fee_summary.total_bad_debt_xrd;
 
     // Source vault withdrawal
@@ -140,7 +142,9 @@ fn test_trace_fee_payments() {
     // Assert
     let resource_changes = &receipt.execution_trace.resource_changes;
     let fee_summary = receipt.expect_commit(true).fee_summary.clone();
-    let total_fee_paid = fee_summary.total_execution_cost_xrd + fee_summary.total_royalty_cost_xrd
+    let total_fee_paid = fee_summary.total_execution_cost_xrd
+        + fee_summary.total_royalty_cost_xrd
+        + fee_summary.total_storage_cost_xrd
         - fee_summary.total_bad_debt_xrd;
 
     assert_eq!(1, resource_changes.len());
@@ -0,0 +1,154 @@
+use radix_engine::transaction::{FeeEstimationConfig, FeeEstimationError};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::builder::{ManifestBuilder, TransactionBuilder};
+use transaction::ecdsa_secp256k1::EcdsaSecp256k1PrivateKey;
+use transaction::model::*;
+use transaction::validation::{NotarizedTransactionValidator, TestIntentHashManager};
+use transaction::validation::{TransactionValidator, ValidationConfig};
+
+fn transfer_manifest(
+    from: ComponentAddress,
+    to: ComponentAddress,
+    lock_fee_amount: Decimal,
+) -> TransactionManifest {
+    ManifestBuilder::new()
+        .lock_fee(from, lock_fee_amount)
+        .withdraw_from_account(from, RADIX_TOKEN, 1.into())
+        .call_method(
+            to,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build()
+}
+
+fn notarize(
+    test_runner: &mut TestRunner,
+    network: &NetworkDefinition,
+    manifest: TransactionManifest,
+    cost_unit_limit: u32,
+    private_key: &EcdsaSecp256k1PrivateKey,
+) -> NotarizedTransaction {
+    TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: 1,
+            network_id: network.id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 99,
+            nonce: test_runner.next_transaction_nonce(),
+            notary_public_key: private_key.public_key().into(),
+            notary_as_signatory: true,
+            cost_unit_limit,
+            tip_percentage: 0,
+        })
+        .manifest(manifest)
+        .notarize(private_key)
+        .build()
+}
+
+#[test]
+fn transaction_succeeds_with_the_recommended_limit_and_lock_fee() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let (public_key, private_key, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let estimate = test_runner
+        .estimate_fee(
+            transfer_manifest(account, other_account, 10.into()),
+            vec![public_key.into()],
+            &network,
+            &FeeEstimationConfig::default(),
+        )
+        .unwrap();
+    let transaction = notarize(
+        &mut test_runner,
+        &network,
+        transfer_manifest(account, other_account, estimate.recommended_lock_fee_xrd),
+        estimate.recommended_cost_unit_limit,
+        &private_key,
+    );
+    let executable = NotarizedTransactionValidator::new(ValidationConfig::default(network.id))
+        .validate(&transaction, 0, &TestIntentHashManager::new())
+        .unwrap();
+    let receipt = test_runner.execute_transaction(executable);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert!(estimate.payload_cost > 0);
+    assert!(estimate.signature_cost > 0);
+    assert!(estimate.royalty_cost_breakdown.is_empty());
+    assert!(fee_summary.execution_cost_sum <= estimate.recommended_cost_unit_limit);
+    assert!(
+        fee_summary.total_execution_cost_xrd
+            + fee_summary.total_royalty_cost_xrd
+            + fee_summary.total_storage_cost_xrd
+            <= estimate.recommended_lock_fee_xrd
+    );
+}
+
+#[test]
+fn estimate_grows_with_the_safety_margin() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let manifest = transfer_manifest(account, other_account, 10.into());
+
+    // Act
+    let no_margin = test_runner
+        .estimate_fee(
+            manifest.clone(),
+            vec![public_key.into()],
+            &network,
+            &FeeEstimationConfig {
+                safety_margin_percentage: 0,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let margin = test_runner
+        .estimate_fee(
+            manifest,
+            vec![public_key.into()],
+            &network,
+            &FeeEstimationConfig {
+                safety_margin_percentage: 50,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        no_margin.recommended_cost_unit_limit,
+        no_margin.execution_cost_sum + no_margin.payload_cost
+    );
+    assert_eq!(
+        margin.recommended_cost_unit_limit,
+        no_margin.recommended_cost_unit_limit * 3 / 2
+    );
+    assert!(margin.recommended_lock_fee_xrd > no_margin.recommended_lock_fee_xrd);
+}
+
+#[test]
+fn estimation_requires_a_signer() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+
+    // Act
+    let result = test_runner.estimate_fee(
+        ManifestBuilder::new().clear_auth_zone().build(),
+        vec![],
+        &network,
+        &FeeEstimationConfig::default(),
+    );
+
+    // Assert
+    assert!(matches!(result, Err(FeeEstimationError::NoSigners)));
+}
@@ -0,0 +1,206 @@
+use radix_engine::errors::{ModuleError, RuntimeError};
+use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
+use scrypto_unit::*;
+use transaction::builder::{ManifestBuilder, TransactionBuilder};
+use transaction::ecdsa_secp256k1::EcdsaSecp256k1PrivateKey;
+use transaction::errors::{SignatureValidationError, TransactionValidationError};
+use transaction::model::*;
+use transaction::validation::{NotarizedTransactionValidator, TestIntentHashManager};
+use transaction::validation::{TransactionValidator, ValidationConfig};
+
+fn build_transaction(
+    test_runner: &mut TestRunner,
+    network: &NetworkDefinition,
+    manifest: TransactionManifest,
+    notary_key: &EcdsaSecp256k1PrivateKey,
+    fee_sponsor: FeeSponsor,
+    sponsor_key: Option<&EcdsaSecp256k1PrivateKey>,
+) -> NotarizedTransaction {
+    let mut builder = TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: 1,
+            network_id: network.id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 99,
+            nonce: test_runner.next_transaction_nonce(),
+            notary_public_key: notary_key.public_key().into(),
+            notary_as_signatory: true,
+            cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
+            tip_percentage: 0,
+        })
+        .manifest(manifest)
+        .fee_sponsor(fee_sponsor);
+    if let Some(sponsor_key) = sponsor_key {
+        builder = builder.sponsor_sign(sponsor_key);
+    }
+    builder.notarize(notary_key).build()
+}
+
+fn validate(
+    network: &NetworkDefinition,
+    transaction: &NotarizedTransaction,
+) -> Result<Executable, TransactionValidationError> {
+    NotarizedTransactionValidator::new(ValidationConfig::default(network.id)).validate(
+        transaction,
+        0,
+        &TestIntentHashManager::new(),
+    )
+}
+
+#[test]
+fn sponsor_pays_the_fee_of_a_manifest_without_lock_fee() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let (_, user_key, user_account) = test_runner.new_allocated_account();
+    let (sponsor_public_key, sponsor_key, sponsor_account) = test_runner.new_allocated_account();
+    let user_balance_before = test_runner
+        .account_balance(user_account, RADIX_TOKEN)
+        .unwrap();
+    let sponsor_balance_before = test_runner
+        .account_balance(sponsor_account, RADIX_TOKEN)
+        .unwrap();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(user_account, RADIX_TOKEN, 1.into())
+        .call_method(
+            user_account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let transaction = build_transaction(
+        &mut test_runner,
+        &network,
+        manifest,
+        &user_key,
+        FeeSponsor {
+            account: sponsor_account,
+            public_key: sponsor_public_key.into(),
+            max_fee: 10.into(),
+        },
+        Some(&sponsor_key),
+    );
+    let executable = validate(&network, &transaction).unwrap();
+    let receipt = test_runner.execute_transaction(executable);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(
+        test_runner
+            .account_balance(user_account, RADIX_TOKEN)
+            .unwrap(),
+        user_balance_before
+    );
+    assert_eq!(
+        sponsor_balance_before
+            - test_runner
+                .account_balance(sponsor_account, RADIX_TOKEN)
+                .unwrap(),
+        fee_summary.total_execution_cost_xrd + fee_summary.total_storage_cost_xrd
+    );
+}
+
+#[test]
+fn manifest_cannot_withdraw_from_the_sponsor_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let (_, user_key, user_account) = test_runner.new_allocated_account();
+    let (sponsor_public_key, sponsor_key, sponsor_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(sponsor_account, RADIX_TOKEN, 1.into())
+        .call_method(
+            user_account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let transaction = build_transaction(
+        &mut test_runner,
+        &network,
+        manifest,
+        &user_key,
+        FeeSponsor {
+            account: sponsor_account,
+            public_key: sponsor_public_key.into(),
+            max_fee: 10.into(),
+        },
+        Some(&sponsor_key),
+    );
+    let executable = validate(&network, &transaction).unwrap();
+    let receipt = test_runner.execute_transaction(executable);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::ModuleError(ModuleError::AuthError { .. }))
+    });
+}
+
+#[test]
+fn sponsored_transaction_without_sponsor_signature_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let (_, user_key, _) = test_runner.new_allocated_account();
+    let (sponsor_public_key, _, sponsor_account) = test_runner.new_allocated_account();
+
+    // Act
+    let transaction = build_transaction(
+        &mut test_runner,
+        &network,
+        ManifestBuilder::new().clear_auth_zone().build(),
+        &user_key,
+        FeeSponsor {
+            account: sponsor_account,
+            public_key: sponsor_public_key.into(),
+            max_fee: 10.into(),
+        },
+        None,
+    );
+    let result = validate(&network, &transaction);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(TransactionValidationError::SignatureValidationError(
+            SignatureValidationError::MissingSponsorSignature
+        ))
+    ));
+}
+
+#[test]
+fn sponsored_transaction_signed_by_another_key_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let (_, user_key, _) = test_runner.new_allocated_account();
+    let (sponsor_public_key, _, sponsor_account) = test_runner.new_allocated_account();
+
+    // Act
+    let transaction = build_transaction(
+        &mut test_runner,
+        &network,
+        ManifestBuilder::new().clear_auth_zone().build(),
+        &user_key,
+        FeeSponsor {
+            account: sponsor_account,
+            public_key: sponsor_public_key.into(),
+            max_fee: 10.into(),
+        },
+        Some(&user_key),
+    );
+    let result = validate(&network, &transaction);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(TransactionValidationError::SignatureValidationError(
+            SignatureValidationError::InvalidSponsorSignature
+        ))
+    ));
+}
@@ -0,0 +1,156 @@
+use radix_engine::blueprints::epoch_manager::{EpochManagerError, EpochManagerSetFeeTableInput};
+use radix_engine::errors::{ApplicationError, ModuleError, RuntimeError};
+use radix_engine::system::kernel_modules::costing::{CostingReason, FeeTable};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
+use radix_engine_interface::blueprints::epoch_manager::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::model::{Instruction, SystemTransaction};
+
+fn set_fee_table(
+    test_runner: &mut TestRunner,
+    fee_table: FeeTable,
+    initial_proofs: Vec<NonFungibleGlobalId>,
+) -> TransactionReceipt {
+    let instructions = vec![Instruction::CallMethod {
+        component_address: EPOCH_MANAGER,
+        method_name: EPOCH_MANAGER_SET_FEE_TABLE_IDENT.to_string(),
+        args: to_manifest_value(&EpochManagerSetFeeTableInput { fee_table }),
+    }];
+    test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(initial_proofs),
+    )
+}
+
+fn execute_faucet_transaction(test_runner: &mut TestRunner) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn transactions_are_priced_with_the_initial_fee_table_by_default() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_faucet_transaction(&mut test_runner);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(fee_summary.fee_table_version, FeeTable::INITIAL_VERSION);
+    assert_eq!(
+        fee_summary.execution_cost_breakdown[&CostingReason::TxBaseCost],
+        FeeTable::new().tx_base_fee()
+    );
+}
+
+#[test]
+fn system_can_update_the_fee_table_used_by_later_transactions() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let fee_table = FeeTable {
+        version: FeeTable::INITIAL_VERSION + 1,
+        tx_base_fee: 70_000,
+        ..FeeTable::new()
+    };
+
+    // Act
+    set_fee_table(
+        &mut test_runner,
+        fee_table,
+        vec![AuthAddresses::system_role()],
+    )
+    .expect_commit_success();
+    let receipt = execute_faucet_transaction(&mut test_runner);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(fee_summary.fee_table_version, FeeTable::INITIAL_VERSION + 1);
+    assert_eq!(
+        fee_summary.execution_cost_breakdown[&CostingReason::TxBaseCost],
+        70_000
+    );
+}
+
+#[test]
+fn fee_table_cannot_be_updated_without_system_auth() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let fee_table = FeeTable {
+        version: FeeTable::INITIAL_VERSION + 1,
+        ..FeeTable::new()
+    };
+
+    // Act
+    let receipt = set_fee_table(&mut test_runner, fee_table, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::ModuleError(ModuleError::AuthError { .. }))
+    });
+}
+
+#[test]
+fn fee_table_cannot_be_updated_without_bumping_its_version() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let fee_table = FeeTable {
+        version: FeeTable::INITIAL_VERSION,
+        tx_base_fee: 70_000,
+        ..FeeTable::new()
+    };
+
+    // Act
+    let receipt = set_fee_table(
+        &mut test_runner,
+        fee_table,
+        vec![AuthAddresses::system_role()],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::InvalidFeeTableVersionUpdate { from, to }
+            )) if *from == FeeTable::INITIAL_VERSION && *to == FeeTable::INITIAL_VERSION
+        )
+    });
+    let receipt = execute_faucet_transaction(&mut test_runner);
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(fee_summary.fee_table_version, FeeTable::INITIAL_VERSION);
+}
+
+#[test]
+fn cleanup_credits_follow_the_fee_table() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let fee_table = FeeTable {
+        version: FeeTable::INITIAL_VERSION + 1,
+        cleanup_credit_percentage: 0,
+        ..FeeTable::new()
+    };
+    set_fee_table(
+        &mut test_runner,
+        fee_table,
+        vec![AuthAddresses::system_role()],
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = execute_faucet_transaction(&mut test_runner);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(fee_summary.execution_credit_sum, 0);
+}
@@ -0,0 +1,97 @@
+use radix_engine::ledger::*;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::resource::FromPublicKey;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn test_component_resources_at_past_state_version() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account1) = test_runner.new_allocated_account();
+    let (_, _, account2) = test_runner.new_allocated_account();
+    let state_version_before = test_runner.get_state_version();
+    let account1_balance_before = test_runner.get_component_resources(account1)[&RADIX_TOKEN];
+    let account2_balance_before = test_runner.get_component_resources(account2)[&RADIX_TOKEN];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10.into())
+        .withdraw_from_account(account1, RADIX_TOKEN, 66.into())
+        .call_method(
+            account2,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+
+    // Assert
+    assert_eq!(test_runner.get_state_version(), state_version_before + 1);
+    assert_eq!(
+        test_runner.get_component_resources_at(account1, state_version_before)[&RADIX_TOKEN],
+        account1_balance_before
+    );
+    assert_eq!(
+        test_runner.get_component_resources_at(account2, state_version_before)[&RADIX_TOKEN],
+        account2_balance_before
+    );
+    assert_eq!(
+        test_runner.get_component_resources_at(account2, state_version_before + 1)[&RADIX_TOKEN],
+        account2_balance_before + 66
+    );
+    assert_eq!(
+        test_runner.get_component_resources_at(account1, state_version_before + 1),
+        test_runner.get_component_resources(account1)
+    );
+}
+
+#[test]
+fn test_substate_did_not_exist_before_its_creation() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let state_version_before = test_runner.get_state_version();
+
+    // Act
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Assert
+    let substate_id = SubstateId(
+        RENodeId::GlobalObject(account.into()),
+        NodeModuleId::TypeInfo,
+        SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+    );
+    let substate_store = test_runner.substate_store();
+    assert!(substate_store
+        .get_substate_at(&substate_id, state_version_before)
+        .is_none());
+    assert_eq!(
+        substate_store.get_substate_at(&substate_id, substate_store.get_state_version()),
+        substate_store.get_substate(&substate_id)
+    );
+}
+
+#[test]
+fn test_state_version_not_reached_yet_cannot_be_viewed() {
+    // Arrange
+    let test_runner = TestRunner::builder().build();
+    let substate_store = test_runner.substate_store();
+    let current_version = substate_store.get_state_version();
+
+    // Act
+    let result = substate_store.at_version(current_version + 1);
+
+    // Assert
+    assert_eq!(
+        result.err(),
+        Some(HistoricalSubstateStoreError::StateVersionNotReached {
+            state_version: current_version + 1,
+            current_version,
+        })
+    );
+    assert!(substate_store.at_version(current_version).is_ok());
+}
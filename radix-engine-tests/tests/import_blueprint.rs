@@ -0,0 +1,55 @@
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+/// This tests the import_blueprint! macro, with the schema exported by the Radiswap package
+#[test]
+fn blueprint_imported_from_schema_can_be_called() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let radiswap_package = test_runner.publish_package(
+        include_bytes!("../../assets/radiswap.wasm").to_vec(),
+        scrypto_decode(include_bytes!("../../assets/radiswap.schema")).unwrap(),
+        BTreeMap::new(),
+        BTreeMap::new(),
+        AccessRulesConfig::new(),
+    );
+    let caller_package =
+        test_runner.compile_and_publish("./tests/blueprints/import_blueprint_caller");
+    let btc = test_runner.create_fungible_resource(1_000.into(), 18, account);
+    let eth = test_runner.create_fungible_resource(1_000.into(), 18, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10u32.into())
+        .withdraw_from_account(account, btc, 600.into())
+        .withdraw_from_account(account, eth, 500.into())
+        .take_from_worktop_by_amount(500.into(), btc, |builder, a_bucket| {
+            builder.take_from_worktop(eth, |builder, b_bucket| {
+                builder.take_from_worktop(btc, |builder, input_bucket| {
+                    builder.call_function(
+                        caller_package,
+                        "ImportBlueprintCaller",
+                        "swap_with_new_pool",
+                        manifest_args!(radiswap_package, a_bucket, b_bucket, input_bucket),
+                    )
+                })
+            })
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_runner.account_balance(account, btc), Some(400.into()));
+    assert!(test_runner.account_balance(account, eth).unwrap() > 500.into());
+}
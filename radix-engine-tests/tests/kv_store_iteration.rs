@@ -0,0 +1,260 @@
+use radix_engine::ledger::{OutputValue, ReadableSubstateStore};
+use radix_engine::system::kernel_modules::costing::CostingReason;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+const ENTRY_COUNT: u8 = 40;
+
+fn instantiate(test_runner: &mut TestRunner) -> ComponentAddress {
+    instantiate_with_entries(test_runner, ENTRY_COUNT)
+}
+
+fn instantiate_with_entries(test_runner: &mut TestRunner, entry_count: u8) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 100.into())
+        .call_function(
+            package_address,
+            "Iteration",
+            "new",
+            manifest_args!(entry_count),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+fn call<T: ScryptoDecode>(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    method_name: &str,
+    args: Vec<u8>,
+) -> T {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, method_name, args)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().output(1)
+}
+
+#[test]
+fn can_iterate_over_kv_store_in_key_order() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate(&mut test_runner);
+
+    // Act
+    let keys: Vec<u8> = call(
+        &mut test_runner,
+        component_address,
+        "keys",
+        manifest_args!(),
+    );
+
+    // Assert
+    assert_eq!(keys, (0..ENTRY_COUNT).collect::<Vec<u8>>());
+}
+
+#[test]
+fn can_list_kv_store_keys_page_by_page() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate(&mut test_runner);
+
+    // Act
+    let first_page: Vec<u8> = call(
+        &mut test_runner,
+        component_address,
+        "page",
+        manifest_args!(Option::<u8>::None, 10u32),
+    );
+    let second_page: Vec<u8> = call(
+        &mut test_runner,
+        component_address,
+        "page",
+        manifest_args!(Some(10u8), 10u32),
+    );
+    let last_page: Vec<u8> = call(
+        &mut test_runner,
+        component_address,
+        "page",
+        manifest_args!(Some(35u8), 10u32),
+    );
+
+    // Assert
+    assert_eq!(first_page, (0..10).collect::<Vec<u8>>());
+    assert_eq!(second_page, (10..20).collect::<Vec<u8>>());
+    assert_eq!(last_page, (35..ENTRY_COUNT).collect::<Vec<u8>>());
+}
+
+#[test]
+fn removing_kv_store_entry_returns_value_and_deletes_substate() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "remove", manifest_args!(7u8))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let removed: Option<String> = result.output(1);
+    assert_eq!(removed, Some("7".to_owned()));
+    let removed_key = scrypto_encode(&7u8).unwrap();
+    let removed_substate_id = result
+        .state_updates
+        .down_substates
+        .iter()
+        .map(|output_id| &output_id.substate_id)
+        .find(|substate_id| {
+            substate_id.2
+                == SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(removed_key.clone()))
+        })
+        .expect("Removed entry must be a down substate")
+        .clone();
+    assert!(!result
+        .state_updates
+        .up_substates
+        .contains_key(&removed_substate_id));
+    let entry: Option<OutputValue> = test_runner
+        .substate_store()
+        .get_substate(&removed_substate_id);
+    assert!(entry.is_none());
+
+    let removed_again: Option<String> = call(
+        &mut test_runner,
+        component_address,
+        "remove",
+        manifest_args!(7u8),
+    );
+    assert_eq!(removed_again, None);
+    let keys: Vec<u8> = call(
+        &mut test_runner,
+        component_address,
+        "keys",
+        manifest_args!(),
+    );
+    assert!(!keys.contains(&7));
+    assert_eq!(keys.len(), ENTRY_COUNT as usize - 1);
+}
+
+#[test]
+fn kv_store_len_follows_inserts_and_removals() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate(&mut test_runner);
+
+    // Act
+    let initial_len: usize = call(&mut test_runner, component_address, "len", manifest_args!());
+    let _: () = call(
+        &mut test_runner,
+        component_address,
+        "insert",
+        manifest_args!(100u8),
+    );
+    let _: Option<String> = call(
+        &mut test_runner,
+        component_address,
+        "remove",
+        manifest_args!(0u8),
+    );
+    let _: Option<String> = call(
+        &mut test_runner,
+        component_address,
+        "remove",
+        manifest_args!(1u8),
+    );
+    let final_len: usize = call(&mut test_runner, component_address, "len", manifest_args!());
+
+    // Assert
+    assert_eq!(initial_len, ENTRY_COUNT as usize);
+    assert_eq!(final_len, ENTRY_COUNT as usize - 1);
+}
+
+#[test]
+fn kv_store_len_counts_entries_over_many_pages() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate_with_entries(&mut test_runner, u8::MAX);
+
+    // Act
+    let len: usize = call(&mut test_runner, component_address, "len", manifest_args!());
+
+    // Assert
+    assert_eq!(len, u8::MAX as usize);
+}
+
+#[test]
+fn entries_removed_while_iterating_are_skipped() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate(&mut test_runner);
+
+    // Act
+    let visited: Vec<u8> = call(
+        &mut test_runner,
+        component_address,
+        "remove_while_iterating",
+        manifest_args!(),
+    );
+
+    // Assert
+    assert_eq!(visited, (0..ENTRY_COUNT).step_by(2).collect::<Vec<u8>>());
+}
+
+#[test]
+fn can_remove_and_iterate_kv_store_owned_by_frame() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "Iteration",
+            "iterate_in_memory",
+            manifest_args!(ENTRY_COUNT),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn listing_more_kv_store_keys_costs_more() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate(&mut test_runner);
+    let mut execute = |limit: u32| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_method(
+                component_address,
+                "page",
+                manifest_args!(Option::<u8>::None, limit),
+            )
+            .build();
+        test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .fee_summary
+            .execution_cost_breakdown[&CostingReason::ListSubstates]
+    };
+
+    // Act
+    let small_page_cost = execute(1);
+    let large_page_cost = execute(ENTRY_COUNT as u32);
+
+    // Assert
+    assert!(small_page_cost < large_page_cost);
+}
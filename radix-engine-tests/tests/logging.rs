@@ -1,22 +1,35 @@
-use radix_engine::{transaction::TransactionReceipt, types::*};
+use radix_engine::kernel::actor::ActorIdentifier;
+use radix_engine::system::kernel_modules::costing::{CostingReason, FeeTable};
+use radix_engine::transaction::{
+    ExecutionConfig, FeeReserveConfig, JsonLinesLogSink, TransactionReceipt,
+};
+use radix_engine::types::*;
 use radix_engine_interface::api::types::Level;
 use scrypto_unit::*;
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
 use transaction::builder::ManifestBuilder;
+use transaction::model::TestTransaction;
 
 fn log_message<S: AsRef<str>>(message: S, panic_log: bool) -> TransactionReceipt {
-    let mut test_runner = TestRunner::builder().build();
-    let package_address = test_runner.compile_and_publish("./tests/blueprints/logger");
-
     let method_name = match panic_log {
         true => "panic_log",
         false => "no_panic_log",
     };
 
+    call_logger(method_name, message)
+}
+
+fn call_logger<S: AsRef<str>>(function_name: &str, message: S) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/logger");
+
     let manifest = ManifestBuilder::new()
         .call_function(
             package_address,
             "Logger",
-            method_name,
+            function_name,
             manifest_args!(message.as_ref().to_owned()),
         )
         .build();
@@ -25,6 +38,29 @@ fn log_message<S: AsRef<str>>(message: S, panic_log: bool) -> TransactionReceipt
     receipt
 }
 
+fn levels_and_messages(receipt: &TransactionReceipt, is_success: bool) -> Vec<(Level, String)> {
+    receipt
+        .expect_commit(is_success)
+        .application_logs
+        .iter()
+        .map(|record| (record.level, record.message.clone()))
+        .collect()
+}
+
+/// A writer whose output can be read after it has been handed over to a sink.
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 #[test]
 fn log_messages_from_transaction_with_no_panic_shows_up_in_receipts() {
     // Arrange
@@ -38,7 +74,7 @@ fn log_messages_from_transaction_with_no_panic_shows_up_in_receipts() {
     {
         receipt.expect_commit_success();
 
-        let logs = receipt.expect_commit(true).application_logs.clone();
+        let logs = levels_and_messages(&receipt, true);
         let expected_logs = vec![(Level::Info, message.to_owned())];
 
         assert_eq!(expected_logs, logs)
@@ -56,7 +92,7 @@ fn log_messages_from_transaction_with_panic_shows_up_in_receipts() {
 
     // Assert
     {
-        let logs = receipt.expect_commit(false).application_logs.clone();
+        let logs = levels_and_messages(&receipt, false);
         let expected_logs = vec![
             (Level::Info, message.to_owned()),
             (
@@ -68,3 +104,184 @@ fn log_messages_from_transaction_with_panic_shows_up_in_receipts() {
         assert_eq!(expected_logs, logs)
     }
 }
+
+#[test]
+fn structured_log_messages_carry_their_fields_and_actor() {
+    // Arrange
+    let message = "Hello Fields";
+
+    // Act
+    let receipt = call_logger("structured_log", message);
+
+    // Assert
+    let logs = receipt.expect_commit_success().application_logs.clone();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].level, Level::Warn);
+    assert_eq!(logs[0].message, message);
+    assert_eq!(
+        logs[0].fields,
+        vec![
+            ("answer".to_owned(), "42".to_owned()),
+            ("unit".to_owned(), "units".to_owned()),
+        ]
+    );
+    assert_eq!(
+        logs[0].display_message(),
+        "Hello Fields answer=42 unit=units"
+    );
+    let actor = logs[0].actor.as_ref().expect("Log must have an actor");
+    assert_eq!(actor.fn_identifier.blueprint_name, "Logger");
+    assert_eq!(actor.fn_identifier.ident, "structured_log");
+    assert!(matches!(actor.identifier, ActorIdentifier::Function(..)));
+}
+
+#[test]
+fn log_messages_are_tagged_with_call_depth() {
+    // Arrange
+    let message = "Hello Depth";
+
+    // Act
+    let receipt = call_logger("nested_log", message);
+
+    // Assert
+    let logs = receipt.expect_commit_success().application_logs.clone();
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[0].message, "Calling nested");
+    assert_eq!(logs[1].message, message);
+    assert_eq!(
+        logs[0].actor.as_ref().unwrap().fn_identifier.ident,
+        "nested_log"
+    );
+    assert_eq!(
+        logs[1].actor.as_ref().unwrap().fn_identifier.ident,
+        "no_panic_log"
+    );
+    assert_eq!(logs[1].depth, logs[0].depth + 1);
+}
+
+#[test]
+fn log_messages_below_min_log_level_are_dropped_and_not_charged() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/logger");
+    let mut execute = |min_log_level: Level| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(
+                package_address,
+                "Logger",
+                "nested_log",
+                manifest_args!("Hello Level".to_owned()),
+            )
+            .build();
+        let nonce = test_runner.next_transaction_nonce();
+        let transaction = TestTransaction::new(manifest, nonce, DEFAULT_COST_UNIT_LIMIT);
+        test_runner.execute_transaction_with_config(
+            transaction.get_executable(vec![]),
+            &FeeReserveConfig::default(),
+            &ExecutionConfig::default().with_min_log_level(min_log_level),
+        )
+    };
+
+    // Act
+    let verbose_receipt = execute(Level::Trace);
+    let quiet_receipt = execute(Level::Info);
+
+    // Assert
+    let verbose_logs = levels_and_messages(&verbose_receipt, true);
+    let quiet_logs = levels_and_messages(&quiet_receipt, true);
+    assert_eq!(
+        verbose_logs,
+        vec![
+            (Level::Debug, "Calling nested".to_owned()),
+            (Level::Info, "Hello Level".to_owned()),
+        ]
+    );
+    assert_eq!(quiet_logs, vec![(Level::Info, "Hello Level".to_owned())]);
+    assert!(
+        quiet_receipt
+            .expect_commit_success()
+            .fee_summary
+            .execution_cost_sum
+            < verbose_receipt
+                .expect_commit_success()
+                .fee_summary
+                .execution_cost_sum
+    );
+}
+
+#[test]
+fn log_messages_are_charged_by_length() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/logger");
+    let mut execute = |message: String| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(
+                package_address,
+                "Logger",
+                "no_panic_log",
+                manifest_args!(message),
+            )
+            .build();
+        test_runner.execute_manifest(manifest, vec![])
+    };
+
+    // Act
+    let tiny_receipt = execute("a".repeat(1));
+    let short_receipt = execute("a".repeat(50));
+    let medium_receipt = execute("a".repeat(100));
+    let long_receipt = execute("a".repeat(1100));
+
+    // Assert
+    let system_cost = |receipt: &TransactionReceipt| {
+        receipt
+            .expect_commit_success()
+            .fee_summary
+            .execution_cost_breakdown[&CostingReason::RunSystem]
+    };
+    // Messages of up to 50 bytes cost the fixed low fee
+    assert_eq!(system_cost(&tiny_receipt), system_cost(&short_receipt));
+    assert_eq!(
+        system_cost(&long_receipt) - system_cost(&medium_receipt),
+        FeeTable::new().invoke_cost_per_byte * 1000
+    );
+}
+
+#[test]
+fn log_messages_of_committed_transactions_are_written_to_log_sink() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/logger");
+    let buffer = SharedBuffer::default();
+    test_runner.set_log_sink(JsonLinesLogSink::new(Box::new(buffer.clone())));
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "Logger",
+            "structured_log",
+            manifest_args!("Hello Sink".to_owned()),
+        )
+        .build();
+
+    // Act
+    test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .expect_commit_success();
+
+    // Assert
+    let output = String::from_utf8(buffer.0.borrow().clone()).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 1);
+    let record: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(record["level"], "WARN");
+    assert_eq!(record["message"], "Hello Sink");
+    assert_eq!(record["fields"]["answer"], "42");
+    assert_eq!(record["fields"]["unit"], "units");
+    assert_eq!(record["actor"]["blueprint_name"], "Logger");
+    assert_eq!(record["actor"]["ident"], "structured_log");
+    assert!(record["actor"]["receiver"].is_null());
+    assert!(record["depth"].is_u64());
+    assert_eq!(record["transaction_hash"].as_str().unwrap().len(), 64);
+}
@@ -305,6 +305,7 @@ fn test_flash_loan() {
         old_balance - new_balance,
         commit_result.fee_summary.total_execution_cost_xrd
             + commit_result.fee_summary.total_royalty_cost_xrd
+            + commit_result.fee_summary.total_storage_cost_xrd
             + (repay_amount - loan_amount)
     );
 
@@ -0,0 +1,332 @@
+use radix_engine::blueprints::package::PackageError;
+use radix_engine::errors::{ApplicationError, ModuleError, RuntimeError};
+use radix_engine::system::kernel_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::resource::FromPublicKey;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn publish_upgradeable_counter(
+    test_runner: &mut TestRunner,
+    package_dir: &str,
+    owner_badge: NonFungibleGlobalId,
+) -> PackageAddress {
+    let (code, schema) = Compile::compile(package_dir);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 100u32.into())
+        .publish_upgradeable_package_with_owner(code, schema, owner_badge)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_package_addresses()[0]
+}
+
+fn instantiate_counter(
+    test_runner: &mut TestRunner,
+    package_address: PackageAddress,
+) -> ComponentAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_function(package_address, "Counter", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+fn call_counter(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    method_name: &str,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_method(component_address, method_name, manifest_args!())
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn upgrade_counter(
+    test_runner: &mut TestRunner,
+    package_address: PackageAddress,
+    package_dir: &str,
+    migrations: BTreeMap<String, String>,
+    initial_proofs: Vec<NonFungibleGlobalId>,
+) -> TransactionReceipt {
+    let (code, schema) = Compile::compile(package_dir);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 100u32.into())
+        .upgrade_package(package_address, code, schema, migrations)
+        .build();
+    test_runner.execute_manifest(manifest, initial_proofs)
+}
+
+#[test]
+fn upgrading_package_migrates_component_state_on_next_call() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address = publish_upgradeable_counter(
+        &mut test_runner,
+        "./tests/blueprints/upgradeable_v1",
+        owner_badge.clone(),
+    );
+    let component_address = instantiate_counter(&mut test_runner, package_address);
+    call_counter(&mut test_runner, component_address, "increment").expect_commit_success();
+    call_counter(&mut test_runner, component_address, "increment").expect_commit_success();
+
+    // Act
+    let receipt = upgrade_counter(
+        &mut test_runner,
+        package_address,
+        "./tests/blueprints/upgradeable_v2",
+        BTreeMap::from([("Counter".to_owned(), "migrate".to_owned())]),
+        vec![owner_badge],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let receipt = call_counter(&mut test_runner, component_address, "get");
+    assert_eq!(receipt.expect_commit_success().output::<u64>(1), 2u64);
+    let receipt = call_counter(&mut test_runner, component_address, "label");
+    assert_eq!(
+        receipt.expect_commit_success().output::<String>(1),
+        "migrated".to_owned()
+    );
+    call_counter(&mut test_runner, component_address, "increment").expect_commit_success();
+    let receipt = call_counter(&mut test_runner, component_address, "get");
+    assert_eq!(receipt.expect_commit_success().output::<u64>(1), 3u64);
+}
+
+#[test]
+fn upgrading_package_with_incompatible_state_and_no_migration_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address = publish_upgradeable_counter(
+        &mut test_runner,
+        "./tests/blueprints/upgradeable_v1",
+        owner_badge.clone(),
+    );
+
+    // Act
+    let receipt = upgrade_counter(
+        &mut test_runner,
+        package_address,
+        "./tests/blueprints/upgradeable_v2",
+        BTreeMap::new(),
+        vec![owner_badge],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::IncompatibleState(blueprint)
+            )) if blueprint == "Counter"
+        )
+    });
+}
+
+#[test]
+fn upgrading_package_not_published_as_upgradeable_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address = test_runner
+        .compile_and_publish_with_owner("./tests/blueprints/upgradeable_v1", owner_badge.clone());
+
+    // Act
+    let receipt = upgrade_counter(
+        &mut test_runner,
+        package_address,
+        "./tests/blueprints/upgradeable_v2",
+        BTreeMap::from([("Counter".to_owned(), "migrate".to_owned())]),
+        vec![owner_badge],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::PackageNotUpgradeable
+            ))
+        )
+    });
+}
+
+#[test]
+fn upgrading_package_without_owner_badge_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address = publish_upgradeable_counter(
+        &mut test_runner,
+        "./tests/blueprints/upgradeable_v1",
+        owner_badge,
+    );
+
+    // Act
+    let receipt = upgrade_counter(
+        &mut test_runner,
+        package_address,
+        "./tests/blueprints/upgradeable_v2",
+        BTreeMap::from([("Counter".to_owned(), "migrate".to_owned())]),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::ModuleError(ModuleError::AuthError(..)))
+    });
+}
+
+#[test]
+fn upgrading_package_twice_keeps_running_earlier_migrations() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address = publish_upgradeable_counter(
+        &mut test_runner,
+        "./tests/blueprints/upgradeable_v1",
+        owner_badge.clone(),
+    );
+    let component_address = instantiate_counter(&mut test_runner, package_address);
+    call_counter(&mut test_runner, component_address, "increment").expect_commit_success();
+    upgrade_counter(
+        &mut test_runner,
+        package_address,
+        "./tests/blueprints/upgradeable_v2",
+        BTreeMap::from([("Counter".to_owned(), "migrate".to_owned())]),
+        vec![owner_badge.clone()],
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = upgrade_counter(
+        &mut test_runner,
+        package_address,
+        "./tests/blueprints/upgradeable_v2",
+        BTreeMap::new(),
+        vec![owner_badge],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let receipt = call_counter(&mut test_runner, component_address, "get");
+    assert_eq!(receipt.expect_commit_success().output::<u64>(1), 1u64);
+    let receipt = call_counter(&mut test_runner, component_address, "label");
+    assert_eq!(
+        receipt.expect_commit_success().output::<String>(1),
+        "migrated".to_owned()
+    );
+}
+
+#[test]
+fn upgrading_package_with_code_breaking_an_earlier_migration_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address = publish_upgradeable_counter(
+        &mut test_runner,
+        "./tests/blueprints/upgradeable_v1",
+        owner_badge.clone(),
+    );
+    upgrade_counter(
+        &mut test_runner,
+        package_address,
+        "./tests/blueprints/upgradeable_v2",
+        BTreeMap::from([("Counter".to_owned(), "migrate".to_owned())]),
+        vec![owner_badge.clone()],
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = upgrade_counter(
+        &mut test_runner,
+        package_address,
+        "./tests/blueprints/upgradeable_v3",
+        BTreeMap::new(),
+        vec![owner_badge],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::InvalidMigration { blueprint, function }
+            )) if blueprint == "Counter" && function == "migrate"
+        )
+    });
+}
+
+#[test]
+fn state_dependent_access_rule_fails_until_component_state_is_migrated() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address = publish_upgradeable_counter(
+        &mut test_runner,
+        "./tests/blueprints/upgradeable_guarded_v1",
+        badge.clone(),
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_function(
+            package_address,
+            "GuardedCounter",
+            "new",
+            manifest_args!(badge.clone()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+    upgrade_counter(
+        &mut test_runner,
+        package_address,
+        "./tests/blueprints/upgradeable_guarded_v2",
+        BTreeMap::from([("GuardedCounter".to_owned(), "migrate".to_owned())]),
+        vec![badge.clone()],
+    )
+    .expect_commit_success();
+    let increment = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_method(component_address, "increment", manifest_args!())
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(increment.clone(), vec![badge.clone()]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::UnmigratedComponentState(
+                ..
+            )))
+        )
+    });
+    call_counter(&mut test_runner, component_address, "get").expect_commit_success();
+    test_runner
+        .execute_manifest(increment.clone(), vec![])
+        .expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized(..)))
+            )
+        });
+    test_runner
+        .execute_manifest(increment, vec![badge])
+        .expect_commit_success();
+    let receipt = call_counter(&mut test_runner, component_address, "get");
+    assert_eq!(receipt.expect_commit_success().output::<u64>(1), 1u64);
+}
@@ -1,3 +1,4 @@
+use radix_engine::kernel::track::SubstateAccess;
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::rule;
@@ -90,6 +91,58 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
     result.unwrap().receipt.expect_commit_success();
 }
 
+#[test]
+fn test_preview_reports_substate_footprint() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .withdraw_from_account(account, RADIX_TOKEN, 1.into())
+        .call_method(
+            other_account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let preview_flags = PreviewFlags {
+        unlimited_loan: true,
+        assume_all_signature_proofs: true,
+        permit_invalid_header_epoch: false,
+        permit_duplicate_intent_hash: false,
+    };
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &preview_flags,
+    );
+    let state_version_before = test_runner.get_state_version();
+
+    // Act
+    let result = test_runner.preview(preview_intent, &network).unwrap();
+
+    // Assert
+    result.receipt.expect_commit_success();
+    assert_eq!(test_runner.get_state_version(), state_version_before);
+    for component in [account, other_account] {
+        let node_footprint = &result.substate_footprint[&RENodeId::GlobalObject(component.into())];
+        assert_eq!(
+            node_footprint[&NodeModuleId::TypeInfo]
+                [&SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo)],
+            SubstateAccess::ReadOnly
+        );
+        let vault_id = test_runner.get_component_vaults(component, RADIX_TOKEN)[0];
+        assert_eq!(
+            result.substate_footprint[&RENodeId::Object(vault_id)][&NodeModuleId::SELF]
+                [&SubstateOffset::Vault(VaultOffset::LiquidFungible)],
+            SubstateAccess::Mutable
+        );
+    }
+}
+
 fn prepare_matching_test_tx_and_preview_intent(
     test_runner: &mut TestRunner,
     network: &NetworkDefinition,
@@ -0,0 +1,436 @@
+use radix_engine::ledger::*;
+use radix_engine::state_manager::{
+    CommitFeed, CommitLogStore, CommitSubscriber, CommittedTransaction,
+};
+use radix_engine::types::*;
+use radix_engine_stores::event_store::{EventQuery, EventStore};
+use radix_engine_stores::hash_tree::prune_stale_nodes;
+use radix_engine_stores::rocks_db::{
+    ColumnFamilyAccess, LastCommittedTransaction, MultiThreadedRadixEngineDB, RadixEngineDB,
+    RadixEngineDBError, RadixEngineDBWithThreadMode,
+};
+use scrypto_unit::*;
+use tempfile::TempDir;
+use transaction::builder::ManifestBuilder;
+
+/// Commits every transaction it is notified about to a `RadixEngineDB`.
+struct RocksDbReplica<'a, M: ColumnFamilyAccess> {
+    substate_store: &'a mut RadixEngineDBWithThreadMode<M>,
+}
+
+impl<'a, M: ColumnFamilyAccess> CommitSubscriber for RocksDbReplica<'a, M> {
+    fn on_commit(&mut self, committed_transaction: &CommittedTransaction) {
+        self.substate_store.commit_batch(
+            committed_transaction.transaction_hash,
+            &committed_transaction.state_updates,
+        );
+    }
+}
+
+/// Commits all the transactions logged by the test runner which were not committed to the given
+/// `RadixEngineDB` yet.
+fn catch_up<M: ColumnFamilyAccess>(
+    test_runner: &TestRunner,
+    substate_store: &mut RadixEngineDBWithThreadMode<M>,
+) {
+    CommitFeed::replay(
+        test_runner.substate_store(),
+        substate_store.get_state_version() + 1,
+        &mut RocksDbReplica { substate_store },
+    );
+}
+
+/// Opens a `RadixEngineDB` in the given directory and commits all the transactions logged by the
+/// test runner to it.
+fn replicate<M: ColumnFamilyAccess>(
+    test_runner: &TestRunner,
+    dir: &TempDir,
+) -> RadixEngineDBWithThreadMode<M> {
+    let mut substate_store = RadixEngineDBWithThreadMode::new(dir.path().to_path_buf()).unwrap();
+    catch_up(test_runner, &mut substate_store);
+    substate_store
+}
+
+/// Transfers from the faucet to the given account with the test runner, and commits (and logs)
+/// the transaction to the given `RadixEngineDB`, too.
+fn transfer_from_faucet_and_log(
+    test_runner: &mut TestRunner,
+    substate_store: &mut RadixEngineDB,
+    account: ComponentAddress,
+) -> CommittedTransaction {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", manifest_args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let transaction_hash = test_runner
+        .substate_store()
+        .get_commit_log(test_runner.get_state_version(), 1)[0]
+        .transaction_hash;
+    substate_store.commit_and_log(transaction_hash, receipt.expect_commit_success())
+}
+
+#[test]
+fn state_hash_after_kv_store_entry_removal_matches_rocks_db() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_state_hashing().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "Iteration", "new", manifest_args!(10u8))
+        .build();
+    let component_address = test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "remove", manifest_args!(7u8))
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Assert
+    let dir = TempDir::new().unwrap();
+    let substate_store: RadixEngineDB = replicate(&test_runner, &dir);
+    let (_, rocks_db_state_hash) = substate_store.get_state_hash().unwrap();
+    assert_eq!(test_runner.get_state_hash(), rocks_db_state_hash);
+}
+
+#[test]
+fn snapshot_outlives_the_db_it_was_taken_from() {
+    // Arrange
+    let test_runner = TestRunner::builder().build();
+    let dir = TempDir::new().unwrap();
+    let substate_store: MultiThreadedRadixEngineDB = replicate(&test_runner, &dir);
+    let state_hash = substate_store.get_state_hash();
+
+    // Act
+    let snapshot = substate_store.snapshot();
+    drop(substate_store);
+
+    // Assert
+    let snapshot_state_hash = std::thread::spawn(move || snapshot.get_state_hash())
+        .join()
+        .unwrap();
+    assert!(state_hash.is_some());
+    assert_eq!(snapshot_state_hash, state_hash);
+}
+
+#[test]
+fn substates_of_all_modules_are_listed_from_their_column_families() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let dir = TempDir::new().unwrap();
+    let substate_store: RadixEngineDB = replicate(&test_runner, &dir);
+    let node_id = RENodeId::GlobalObject(account.into());
+
+    // Act
+    let substates = substate_store.list_substates(&node_id, None, None, usize::MAX);
+    let type_info_substates =
+        substate_store.list_substates(&node_id, Some(NodeModuleId::TypeInfo), None, usize::MAX);
+
+    // Assert
+    assert_eq!(
+        substates,
+        test_runner
+            .substate_store()
+            .list_substates(&node_id, None, None, usize::MAX)
+    );
+    assert!(substates
+        .iter()
+        .any(|(SubstateId(_, module_id, _), _)| *module_id == NodeModuleId::SELF));
+    assert!(!type_info_substates.is_empty());
+    for (substate_id, output_value) in type_info_substates {
+        assert_eq!(substate_id.1, NodeModuleId::TypeInfo);
+        assert_eq!(
+            substate_store.get_substate(&substate_id),
+            Some(output_value)
+        );
+    }
+}
+
+#[test]
+fn last_committed_transaction_is_recorded_with_each_commit() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    test_runner.new_allocated_account();
+    let dir = TempDir::new().unwrap();
+
+    // Act
+    let substate_store: RadixEngineDB = replicate(&test_runner, &dir);
+
+    // Assert
+    let state_version = test_runner.get_state_version();
+    let last_logged_transaction = &test_runner
+        .substate_store()
+        .get_commit_log(state_version, 1)[0];
+    assert_eq!(
+        substate_store.get_last_committed_transaction(),
+        Some(LastCommittedTransaction {
+            state_version,
+            transaction_hash: Some(last_logged_transaction.transaction_hash),
+        })
+    );
+}
+
+#[test]
+fn opening_db_with_legacy_layout_returns_error() {
+    // Arrange
+    let dir = TempDir::new().unwrap();
+    let legacy_db = rocksdb::DB::open_default(dir.path()).unwrap();
+    legacy_db.put(b"substate", b"value").unwrap();
+    drop(legacy_db);
+
+    // Act
+    let result = RadixEngineDB::new(dir.path().to_path_buf());
+
+    // Assert
+    assert_eq!(
+        result.err(),
+        Some(RadixEngineDBError::LegacyLayout(dir.path().to_path_buf()))
+    );
+}
+
+#[test]
+fn state_hash_tree_is_persisted_across_reopening() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_state_hashing().build();
+    test_runner.new_allocated_account();
+    let dir = TempDir::new().unwrap();
+    let substate_store: RadixEngineDB = replicate(&test_runner, &dir);
+    drop(substate_store);
+
+    // Act
+    let substate_store = RadixEngineDB::new(dir.path().to_path_buf()).unwrap();
+
+    // Assert
+    assert_eq!(
+        substate_store.get_state_hash(),
+        Some((
+            test_runner.get_state_version(),
+            test_runner.get_state_hash()
+        ))
+    );
+}
+
+#[test]
+fn state_hash_tree_keeps_up_after_pruning_stale_nodes() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_state_hashing().build();
+    test_runner.new_allocated_account();
+    let dir = TempDir::new().unwrap();
+    let mut substate_store: RadixEngineDB = replicate(&test_runner, &dir);
+    let pruned_node_count = prune_stale_nodes(&mut substate_store, test_runner.get_state_version());
+
+    // Act
+    test_runner.new_allocated_account();
+    catch_up(&test_runner, &mut substate_store);
+
+    // Assert
+    assert!(pruned_node_count > 0);
+    assert_eq!(
+        substate_store.get_state_hash(),
+        Some((
+            test_runner.get_state_version(),
+            test_runner.get_state_hash()
+        ))
+    );
+}
+
+#[test]
+fn snapshot_is_not_affected_by_later_commits() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let dir = TempDir::new().unwrap();
+    let mut substate_store: MultiThreadedRadixEngineDB = replicate(&test_runner, &dir);
+    let state_hash = substate_store.get_state_hash();
+    let snapshot = substate_store.snapshot();
+
+    // Act
+    let (_, _, account) = test_runner.new_allocated_account();
+    catch_up(&test_runner, &mut substate_store);
+
+    // Assert
+    let account_type_info_id = SubstateId(
+        RENodeId::GlobalObject(account.into()),
+        NodeModuleId::TypeInfo,
+        SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+    );
+    assert!(substate_store.get_substate(&account_type_info_id).is_some());
+    assert_ne!(substate_store.get_state_hash(), state_hash);
+    let workers = (0..4)
+        .map(|_| {
+            let snapshot = snapshot.clone();
+            let account_type_info_id = account_type_info_id.clone();
+            std::thread::spawn(move || {
+                (
+                    snapshot.get_state_hash(),
+                    snapshot.get_substate(&account_type_info_id),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    for worker in workers {
+        assert_eq!(worker.join().unwrap(), (state_hash, None));
+    }
+}
+
+#[test]
+fn historical_reads_match_the_in_memory_store() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "Iteration", "new", manifest_args!(10u8))
+        .build();
+    let component_address = test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+    let created_version = test_runner.get_state_version();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "remove", manifest_args!(7u8))
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+    let kv_store_id = test_runner
+        .substate_store()
+        .get_commit_log(created_version, 1)[0]
+        .state_updates
+        .up_substates
+        .keys()
+        .find_map(|SubstateId(node_id, ..)| match node_id {
+            RENodeId::KeyValueStore(kv_store_id) => Some(*kv_store_id),
+            _ => None,
+        })
+        .unwrap();
+    let node_id = RENodeId::GlobalObject(component_address.into());
+
+    // Act
+    let dir = TempDir::new().unwrap();
+    let substate_store: RadixEngineDB = replicate(&test_runner, &dir);
+
+    // Assert
+    let in_memory_store = test_runner.substate_store();
+    for state_version in [created_version - 1, created_version, created_version + 1] {
+        assert_eq!(
+            substate_store.get_kv_store_entries_at(&kv_store_id, state_version),
+            in_memory_store.get_kv_store_entries_at(&kv_store_id, state_version)
+        );
+        assert_eq!(
+            substate_store.list_substates_at(&node_id, None, None, usize::MAX, state_version),
+            in_memory_store.list_substates_at(&node_id, None, None, usize::MAX, state_version)
+        );
+    }
+    assert!(substate_store
+        .get_kv_store_entries_at(&kv_store_id, created_version - 1)
+        .is_empty());
+    assert_eq!(
+        substate_store
+            .get_kv_store_entries_at(&kv_store_id, created_version)
+            .len(),
+        10
+    );
+}
+
+#[test]
+fn logged_transactions_are_read_back_from_the_commit_log() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let dir = TempDir::new().unwrap();
+    let mut substate_store: RadixEngineDB = replicate(&test_runner, &dir);
+
+    // Act
+    let committed_transactions = vec![
+        transfer_from_faucet_and_log(&mut test_runner, &mut substate_store, account),
+        transfer_from_faucet_and_log(&mut test_runner, &mut substate_store, account),
+    ];
+    drop(substate_store);
+
+    // Assert
+    let substate_store = RadixEngineDB::new(dir.path().to_path_buf()).unwrap();
+    let first_logged_version = test_runner.get_state_version() - 1;
+    assert_eq!(
+        committed_transactions
+            .iter()
+            .map(|committed_transaction| committed_transaction.state_version)
+            .collect::<Vec<_>>(),
+        vec![first_logged_version, first_logged_version + 1]
+    );
+    // the transactions replicated via `commit_batch()` are not logged
+    assert_eq!(
+        substate_store.get_commit_log(0, usize::MAX),
+        committed_transactions
+    );
+    assert_eq!(
+        substate_store.get_commit_log(first_logged_version + 1, usize::MAX),
+        committed_transactions[1..]
+    );
+    assert_eq!(
+        substate_store.get_commit_log(first_logged_version, 1),
+        committed_transactions[..1]
+    );
+}
+
+#[test]
+fn events_of_logged_transactions_are_queried_like_in_memory() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_event_store().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let dir = TempDir::new().unwrap();
+    let mut substate_store: RadixEngineDB = replicate(&test_runner, &dir);
+    let first_logged_version = test_runner.get_state_version() + 1;
+    transfer_from_faucet_and_log(&mut test_runner, &mut substate_store, account);
+    transfer_from_faucet_and_log(&mut test_runner, &mut substate_store, account);
+    let deposit_event_type = test_runner
+        .event_store()
+        .query_events(
+            &EventQuery::new(1)
+                .emitter(RENodeId::GlobalObject(account.into()))
+                .since(first_logged_version),
+        )
+        .events[0]
+        .event_type_identifier
+        .clone();
+
+    // Act
+    let queries = [
+        EventQuery::new(usize::MAX).since(first_logged_version),
+        EventQuery::new(2).since(first_logged_version),
+        EventQuery::new(usize::MAX)
+            .emitter(RENodeId::GlobalObject(account.into()))
+            .since(first_logged_version),
+        EventQuery::new(usize::MAX)
+            .event_type(deposit_event_type.clone())
+            .since(first_logged_version)
+            .until(first_logged_version),
+    ];
+    let pages = queries
+        .iter()
+        .map(|query| substate_store.query_events(query))
+        .collect::<Vec<_>>();
+
+    // Assert
+    for (query, page) in queries.iter().zip(&pages) {
+        assert_eq!(*page, test_runner.event_store().query_events(query));
+    }
+    assert!(pages[0].events.len() > 2);
+    assert!(pages[1].next_cursor.is_some());
+    assert_eq!(pages[2].events.len(), 2);
+    assert_eq!(pages[3].events.len(), 1);
+    assert_eq!(pages[3].events[0].event_type_identifier, deposit_event_type);
+}
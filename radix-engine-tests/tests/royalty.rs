@@ -51,6 +51,7 @@ fn test_component_royalty() {
         account_pre_balance - account_post_balance,
         commit_result.fee_summary.total_execution_cost_xrd
             + commit_result.fee_summary.total_royalty_cost_xrd
+            + commit_result.fee_summary.total_storage_cost_xrd
     );
     assert_eq!(
         component_royalty,
@@ -154,6 +155,7 @@ fn test_package_royalty() {
         account_pre_balance - account_post_balance,
         commit_result.fee_summary.total_execution_cost_xrd
             + commit_result.fee_summary.total_royalty_cost_xrd
+            + commit_result.fee_summary.total_storage_cost_xrd
     );
     assert_eq!(
         package_royalty,
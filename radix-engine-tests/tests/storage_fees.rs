@@ -0,0 +1,182 @@
+use radix_engine::errors::{ModuleError, RuntimeError};
+use radix_engine::system::kernel_modules::costing::{
+    u128_to_decimal, CostingError, FeeReserveError, FeeTable,
+};
+use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_COST_UNIT_PRICE;
+use radix_engine_interface::blueprints::resource::FromPublicKey;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn state_growth_is_charged_per_byte() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .new_account(rule!(allow_all))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert!(fee_summary.net_storage_bytes > 0);
+    assert_eq!(
+        fee_summary.total_storage_cost_xrd,
+        u128_to_decimal(DEFAULT_COST_UNIT_PRICE)
+            * FeeTable::new().storage_cost_per_byte()
+            * fee_summary.net_storage_bytes
+    );
+}
+
+#[test]
+fn storage_fee_is_paid_from_the_locked_fees() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let balance_before = test_runner.account_balance(account, RADIX_TOKEN).unwrap();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .new_account(rule!(allow_all))
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert!(fee_summary.total_storage_cost_xrd.is_positive());
+    assert_eq!(
+        balance_before - test_runner.account_balance(account, RADIX_TOKEN).unwrap(),
+        fee_summary.total_execution_cost_xrd + fee_summary.total_storage_cost_xrd
+    );
+}
+
+#[test]
+fn transfer_between_existing_vaults_does_not_grow_the_state() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .withdraw_from_account(account, RADIX_TOKEN, 1.into())
+        .call_method(
+            other_account,
+            "deposit_batch",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(fee_summary.net_storage_bytes, 0);
+    assert_eq!(fee_summary.total_storage_cost_xrd, Decimal::zero());
+}
+
+#[test]
+fn state_growth_not_covered_by_the_locked_fees_is_not_committed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let new_account = |lock_fee_amount: Decimal| {
+        ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, lock_fee_amount)
+            .new_account(rule!(allow_all))
+            .build()
+    };
+    let receipt = test_runner.execute_manifest(new_account(10.into()), vec![]);
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    let lock_fee_amount =
+        fee_summary.total_execution_cost_xrd + fee_summary.total_storage_cost_xrd / 2;
+
+    // Act
+    let receipt = test_runner.execute_manifest(new_account(lock_fee_amount), vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::CostingError(CostingError::FeeReserveError(
+                FeeReserveError::InsufficientBalance
+            )))
+        )
+    });
+    let commit_result = receipt.expect_commit_failure();
+    assert!(commit_result.new_component_addresses().is_empty());
+    assert!(commit_result.fee_summary.net_storage_bytes <= 0);
+    assert_eq!(
+        commit_result.fee_summary.total_bad_debt_xrd,
+        Decimal::zero()
+    );
+}
+
+#[test]
+fn storage_refund_does_not_make_execution_free() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(package_address, "LargeEntries", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+    let entry_size = 20_000u32;
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee(FAUCET_COMPONENT, 10.into())
+                .call_method(
+                    component_address,
+                    "insert",
+                    manifest_args!(1u32, entry_size),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+    let balance_before = test_runner.account_balance(account, RADIX_TOKEN).unwrap();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .call_method(
+            component_address,
+            "spin_and_remove",
+            manifest_args!(1u32, 10_000u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert!(fee_summary.net_storage_bytes <= -(entry_size as i64));
+    assert_eq!(
+        fee_summary.total_storage_cost_xrd,
+        -(fee_summary.total_execution_cost_xrd * FeeTable::new().storage_refund_limit_percentage()
+            / 100)
+    );
+    let fee_paid = balance_before - test_runner.account_balance(account, RADIX_TOKEN).unwrap();
+    assert!(fee_paid.is_positive());
+    assert_eq!(
+        fee_paid,
+        fee_summary.total_execution_cost_xrd + fee_summary.total_storage_cost_xrd
+    );
+}
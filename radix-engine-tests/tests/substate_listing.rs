@@ -0,0 +1,75 @@
+use radix_engine::ledger::*;
+use radix_engine::types::*;
+use scrypto_unit::*;
+
+#[test]
+fn test_list_substates_of_account_in_listing_order() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let node_id = RENodeId::GlobalObject(account.into());
+
+    // Act
+    let substates = test_runner
+        .substate_store()
+        .list_substates(&node_id, None, None, usize::MAX);
+
+    // Assert
+    assert!(substates
+        .iter()
+        .any(|(substate_id, _)| substate_id.1 == NodeModuleId::TypeInfo));
+    for (substate_id, output_value) in &substates {
+        assert_eq!(substate_id.0, node_id);
+        assert_eq!(
+            test_runner.substate_store().get_substate(substate_id),
+            Some(output_value.clone())
+        );
+    }
+    let listing_keys: Vec<(NodeModuleId, Vec<u8>)> = substates
+        .iter()
+        .map(|(SubstateId(_, module_id, offset), _)| substate_listing_key(*module_id, offset))
+        .collect();
+    assert!(listing_keys.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_list_substates_from_start_key_with_limit() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let node_id = RENodeId::GlobalObject(account.into());
+    let substate_store = test_runner.substate_store();
+    let all_substates = substate_store.list_substates(&node_id, None, None, usize::MAX);
+
+    for (index, (SubstateId(_, module_id, offset), _)) in all_substates.iter().enumerate() {
+        // Act
+        let page =
+            substate_store.list_substates(&node_id, None, Some(&(*module_id, offset.clone())), 2);
+
+        // Assert
+        let expected_end = (index + 2).min(all_substates.len());
+        assert_eq!(page, all_substates[index..expected_end].to_vec());
+    }
+}
+
+#[test]
+fn test_list_substates_of_single_module() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let node_id = RENodeId::GlobalObject(account.into());
+    let substate_store = test_runner.substate_store();
+
+    // Act
+    let type_info_substates =
+        substate_store.list_substates(&node_id, Some(NodeModuleId::TypeInfo), None, usize::MAX);
+
+    // Assert
+    let expected: Vec<(SubstateId, OutputValue)> = substate_store
+        .list_substates(&node_id, None, None, usize::MAX)
+        .into_iter()
+        .filter(|(substate_id, _)| substate_id.1 == NodeModuleId::TypeInfo)
+        .collect();
+    assert!(!expected.is_empty());
+    assert_eq!(type_info_substates, expected);
+}
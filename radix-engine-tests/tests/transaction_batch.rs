@@ -0,0 +1,157 @@
+#[cfg(not(feature = "alloc"))]
+mod transaction_batch_test {
+    use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
+    use radix_engine::types::*;
+    use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
+    use radix_engine_interface::blueprints::resource::FromPublicKey;
+    use scrypto_unit::*;
+    use transaction::builder::ManifestBuilder;
+    use transaction::model::{Executable, TestTransaction};
+
+    struct Transfer {
+        transaction: TestTransaction,
+        public_key: EcdsaSecp256k1PublicKey,
+    }
+
+    impl Transfer {
+        fn new(
+            from: (EcdsaSecp256k1PublicKey, ComponentAddress),
+            to: ComponentAddress,
+            nonce: u64,
+        ) -> Self {
+            let manifest = ManifestBuilder::new()
+                .lock_fee(from.1, 10.into())
+                .withdraw_from_account(from.1, RADIX_TOKEN, 1.into())
+                .call_method(
+                    to,
+                    "deposit_batch",
+                    manifest_args!(ManifestExpression::EntireWorktop),
+                )
+                .build();
+            Self {
+                transaction: TestTransaction::new(manifest, nonce, DEFAULT_COST_UNIT_LIMIT),
+                public_key: from.0,
+            }
+        }
+
+        fn get_executable(&self) -> Executable {
+            self.transaction
+                .get_executable(vec![NonFungibleGlobalId::from_public_key(&self.public_key)])
+        }
+    }
+
+    fn new_test_runner_with_accounts(
+        count: usize,
+    ) -> (TestRunner, Vec<(EcdsaSecp256k1PublicKey, ComponentAddress)>) {
+        let mut test_runner = TestRunner::builder().with_state_hashing().build();
+        let accounts = (0..count)
+            .map(|_| {
+                let (public_key, _, account) = test_runner.new_allocated_account();
+                (public_key, account)
+            })
+            .collect();
+        (test_runner, accounts)
+    }
+
+    /// Executes the transfers both as a batch and one after another, with the same config, and
+    /// returns the indices of the re-executed transactions of the batch.
+    fn assert_batch_matches_sequential_execution(
+        transfers: impl Fn(&[(EcdsaSecp256k1PublicKey, ComponentAddress)]) -> Vec<Transfer>,
+        execution_config: ExecutionConfig,
+    ) -> Vec<usize> {
+        // Arrange
+        let (mut batch_test_runner, accounts) = new_test_runner_with_accounts(4);
+        let (mut sequential_test_runner, _) = new_test_runner_with_accounts(4);
+        let transfers = transfers(&accounts);
+        let executables: Vec<Executable> = transfers
+            .iter()
+            .map(|transfer| transfer.get_executable())
+            .collect();
+        let fee_reserve_config = FeeReserveConfig::default();
+
+        // Act
+        let result = batch_test_runner.execute_transaction_batch_with_config(
+            &executables,
+            &fee_reserve_config,
+            &execution_config,
+        );
+        let sequential_receipts: Vec<_> = transfers
+            .iter()
+            .map(|transfer| {
+                sequential_test_runner.execute_transaction_with_config(
+                    transfer.get_executable(),
+                    &fee_reserve_config,
+                    &execution_config,
+                )
+            })
+            .collect();
+
+        // Assert
+        for (receipt, sequential_receipt) in result.receipts.iter().zip(&sequential_receipts) {
+            assert_eq!(
+                receipt.expect_commit_success().state_updates,
+                sequential_receipt.expect_commit_success().state_updates
+            );
+        }
+        assert_eq!(
+            batch_test_runner.get_state_hash(),
+            sequential_test_runner.get_state_hash()
+        );
+        for (_, account) in accounts {
+            assert_eq!(
+                batch_test_runner.get_component_resources(account),
+                sequential_test_runner.get_component_resources(account)
+            );
+        }
+        result.reexecuted
+    }
+
+    fn skipping_unchanged_substates() -> ExecutionConfig {
+        ExecutionConfig::default().with_skip_unchanged_substates(true)
+    }
+
+    #[test]
+    fn test_independent_transfers_are_not_reexecuted() {
+        let reexecuted = assert_batch_matches_sequential_execution(
+            |accounts| {
+                vec![
+                    Transfer::new(accounts[0], accounts[1].1, 1),
+                    Transfer::new(accounts[2], accounts[3].1, 2),
+                ]
+            },
+            skipping_unchanged_substates(),
+        );
+
+        assert_eq!(reexecuted, Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_conflicting_transfers_are_reexecuted_on_top_of_earlier_ones() {
+        let reexecuted = assert_batch_matches_sequential_execution(
+            |accounts| {
+                vec![
+                    Transfer::new(accounts[0], accounts[1].1, 1),
+                    Transfer::new(accounts[0], accounts[2].1, 2),
+                    Transfer::new(accounts[3], accounts[1].1, 3),
+                ]
+            },
+            skipping_unchanged_substates(),
+        );
+
+        assert_eq!(reexecuted, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_batch_matches_sequential_execution_without_skipping_unchanged_substates() {
+        assert_batch_matches_sequential_execution(
+            |accounts| {
+                vec![
+                    Transfer::new(accounts[0], accounts[1].1, 1),
+                    Transfer::new(accounts[2], accounts[3].1, 2),
+                    Transfer::new(accounts[0], accounts[2].1, 3),
+                ]
+            },
+            ExecutionConfig::default(),
+        );
+    }
+}
@@ -3,7 +3,6 @@ use super::state_machine::*;
 use crate::errors::{ApplicationError, InterpreterError, RuntimeError};
 use crate::event_schema;
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
-use crate::system::kernel_modules::costing::FIXED_LOW_FEE;
 use crate::types::*;
 use native_sdk::modules::access_rules::AccessRulesObject;
 use native_sdk::modules::metadata::Metadata;
@@ -12,7 +11,7 @@ use native_sdk::resource::{SysBucket, Vault};
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::node_modules::auth::*;
 use radix_engine_interface::api::substate_api::LockFlags;
-use radix_engine_interface::api::types::ClientCostingReason;
+use radix_engine_interface::api::types::{ClientCostUnits, ClientCostingReason};
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::constants::{ACCESS_CONTROLLER_PACKAGE, PACKAGE_TOKEN};
@@ -298,7 +297,10 @@ impl AccessControllerNativePackage {
     {
         match export_name {
             ACCESS_CONTROLLER_CREATE_GLOBAL_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 if receiver.is_some() {
                     return Err(RuntimeError::InterpreterError(
@@ -308,7 +310,10 @@ impl AccessControllerNativePackage {
                 Self::create_global(input, api)
             }
             ACCESS_CONTROLLER_CREATE_PROOF_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -316,7 +321,10 @@ impl AccessControllerNativePackage {
                 Self::create_proof(receiver, input, api)
             }
             ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -324,7 +332,10 @@ impl AccessControllerNativePackage {
                 Self::initiate_recovery_as_primary(receiver, input, api)
             }
             ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -332,7 +343,10 @@ impl AccessControllerNativePackage {
                 Self::initiate_recovery_as_recovery(receiver, input, api)
             }
             ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -340,7 +354,10 @@ impl AccessControllerNativePackage {
                 Self::quick_confirm_primary_role_recovery_proposal(receiver, input, api)
             }
             ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -348,7 +365,10 @@ impl AccessControllerNativePackage {
                 Self::quick_confirm_recovery_role_recovery_proposal(receiver, input, api)
             }
             ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -356,7 +376,10 @@ impl AccessControllerNativePackage {
                 Self::timed_confirm_recovery(receiver, input, api)
             }
             ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -364,7 +387,10 @@ impl AccessControllerNativePackage {
                 Self::cancel_primary_role_recovery_proposal(receiver, input, api)
             }
             ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -372,7 +398,10 @@ impl AccessControllerNativePackage {
                 Self::cancel_recovery_role_recovery_proposal(receiver, input, api)
             }
             ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -380,7 +409,10 @@ impl AccessControllerNativePackage {
                 Self::lock_primary_role(receiver, input, api)
             }
             ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -388,7 +420,10 @@ impl AccessControllerNativePackage {
                 Self::unlock_primary_role(receiver, input, api)
             }
             ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -0,0 +1,13 @@
+use crate::types::*;
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub enum WithdrawEvent {
+    Fungible(ResourceAddress, Decimal),
+    NonFungible(ResourceAddress, BTreeSet<NonFungibleLocalId>),
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub enum DepositEvent {
+    Fungible(ResourceAddress, Decimal),
+    NonFungible(ResourceAddress, BTreeSet<NonFungibleLocalId>),
+}
@@ -1,3 +1,5 @@
+mod events;
 mod package;
 
+pub use events::*;
 pub use package::*;
@@ -1,9 +1,10 @@
+use crate::blueprints::account::{DepositEvent, WithdrawEvent};
 use crate::errors::RuntimeError;
 use crate::errors::{ApplicationError, InterpreterError};
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
 use crate::system::node::{RENodeInit, RENodeModuleInit};
 use crate::system::node_modules::type_info::TypeInfoSubstate;
-use crate::types::*;
+use crate::{event_schema, types::*};
 use native_sdk::modules::access_rules::AccessRulesObject;
 use native_sdk::modules::metadata::Metadata;
 use native_sdk::modules::royalty::ComponentRoyalty;
@@ -12,14 +13,15 @@ use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::resource::AccessRule;
 use radix_engine_interface::blueprints::resource::AccessRulesConfig;
+use radix_engine_interface::blueprints::resource::Bucket;
 use radix_engine_interface::blueprints::resource::MethodKey;
 use radix_engine_interface::schema::{
     BlueprintSchema, FunctionSchema, KeyValueStoreSchema, PackageSchema, Receiver,
 };
 
-use crate::system::kernel_modules::costing::FIXED_LOW_FEE;
 use native_sdk::resource::{SysBucket, Vault};
-use radix_engine_interface::api::types::ClientCostingReason;
+use native_sdk::runtime::Runtime;
+use radix_engine_interface::api::types::{ClientCostUnits, ClientCostingReason};
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct AccountSubstate {
@@ -194,6 +196,14 @@ impl AccountNativePackage {
             },
         );
 
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                WithdrawEvent,
+                DepositEvent
+            ]
+        };
+
         let schema = generate_full_schema(aggregator);
         PackageSchema {
             blueprints: btreemap!(
@@ -201,7 +211,7 @@ impl AccountNativePackage {
                     schema,
                     substates,
                     functions,
-                    event_schema: [].into()
+                    event_schema
                 }
             ),
         }
@@ -218,7 +228,10 @@ impl AccountNativePackage {
     {
         match export_name {
             ACCOUNT_CREATE_GLOBAL_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 if receiver.is_some() {
                     return Err(RuntimeError::InterpreterError(
@@ -228,7 +241,10 @@ impl AccountNativePackage {
                 Self::create_global(input, api)
             }
             ACCOUNT_CREATE_LOCAL_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 if receiver.is_some() {
                     return Err(RuntimeError::InterpreterError(
@@ -238,7 +254,10 @@ impl AccountNativePackage {
                 Self::create_local(input, api)
             }
             ACCOUNT_LOCK_FEE_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -246,7 +265,10 @@ impl AccountNativePackage {
                 Self::lock_fee(receiver, input, api)
             }
             ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -254,7 +276,10 @@ impl AccountNativePackage {
                 Self::lock_contingent_fee(receiver, input, api)
             }
             ACCOUNT_DEPOSIT_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -262,7 +287,10 @@ impl AccountNativePackage {
                 Self::deposit(receiver, input, api)
             }
             ACCOUNT_DEPOSIT_BATCH_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -270,7 +298,10 @@ impl AccountNativePackage {
                 Self::deposit_batch(receiver, input, api)
             }
             ACCOUNT_WITHDRAW_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -278,7 +309,10 @@ impl AccountNativePackage {
                 Self::withdraw(receiver, input, api)
             }
             ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -286,7 +320,10 @@ impl AccountNativePackage {
                 Self::withdraw_non_fungibles(receiver, input, api)
             }
             ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -294,7 +331,10 @@ impl AccountNativePackage {
                 Self::lock_fee_and_withdraw(receiver, input, api)
             }
             ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -302,7 +342,10 @@ impl AccountNativePackage {
                 Self::lock_fee_and_withdraw_non_fungibles(receiver, input, api)
             }
             ACCOUNT_CREATE_PROOF_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -310,7 +353,10 @@ impl AccountNativePackage {
                 Self::create_proof(receiver, input, api)
             }
             ACCOUNT_CREATE_PROOF_BY_AMOUNT_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -318,7 +364,10 @@ impl AccountNativePackage {
                 Self::create_proof_by_amount(receiver, input, api)
             }
             ACCOUNT_CREATE_PROOF_BY_IDS_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -532,6 +581,12 @@ impl AccountNativePackage {
 
         let resource_address = input.bucket.sys_resource_address(api)?;
         let encoded_key = scrypto_encode(&resource_address).expect("Impossible Case!");
+        let event = Self::bucket_event(
+            &input.bucket,
+            DepositEvent::Fungible,
+            DepositEvent::NonFungible,
+            api,
+        )?;
 
         let handle = api.sys_lock_substate(
             receiver,
@@ -579,6 +634,10 @@ impl AccountNativePackage {
         api.sys_drop_lock(kv_store_entry_lock_handle)?;
         api.sys_drop_lock(handle)?;
 
+        if let Some(event) = event {
+            Runtime::emit_event(api, event)?;
+        }
+
         Ok(IndexedScryptoValue::from_typed(&()))
     }
 
@@ -607,6 +666,12 @@ impl AccountNativePackage {
         for bucket in input.buckets {
             let resource_address = bucket.sys_resource_address(api)?;
             let encoded_key = scrypto_encode(&resource_address).expect("Impossible Case!");
+            let event = Self::bucket_event(
+                &bucket,
+                DepositEvent::Fungible,
+                DepositEvent::NonFungible,
+                api,
+            )?;
 
             // Getting an RW lock handle on the KVStore ENTRY
             let kv_store_entry_lock_handle = {
@@ -645,6 +710,10 @@ impl AccountNativePackage {
             vault.sys_put(bucket, api)?;
 
             api.sys_drop_lock(kv_store_entry_lock_handle)?;
+
+            if let Some(event) = event {
+                Runtime::emit_event(api, event)?;
+            }
         }
 
         api.sys_drop_lock(handle)?;
@@ -704,6 +773,47 @@ impl AccountNativePackage {
         Ok(rtn)
     }
 
+    fn emit_withdraw_event<Y>(bucket: &Bucket, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let event = Self::bucket_event(
+            bucket,
+            WithdrawEvent::Fungible,
+            WithdrawEvent::NonFungible,
+            api,
+        )?;
+        if let Some(event) = event {
+            Runtime::emit_event(api, event)?;
+        }
+        Ok(())
+    }
+
+    /// Describes the contents of a bucket as an account event, or returns `None` if the bucket is
+    /// empty.
+    fn bucket_event<Y, T>(
+        bucket: &Bucket,
+        fungible: fn(ResourceAddress, Decimal) -> T,
+        non_fungible: fn(ResourceAddress, BTreeSet<NonFungibleLocalId>) -> T,
+        api: &mut Y,
+    ) -> Result<Option<T>, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let resource_address = bucket.sys_resource_address(api)?;
+        let event = match resource_address {
+            ResourceAddress::Fungible(..) => {
+                let amount = bucket.sys_amount(api)?;
+                (!amount.is_zero()).then(|| fungible(resource_address, amount))
+            }
+            ResourceAddress::NonFungible(..) => {
+                let ids = bucket.sys_non_fungible_local_ids(api)?;
+                (!ids.is_empty()).then(|| non_fungible(resource_address, ids))
+            }
+        };
+        Ok(event)
+    }
+
     fn withdraw<Y>(
         receiver: RENodeId,
         input: IndexedScryptoValue,
@@ -722,6 +832,7 @@ impl AccountNativePackage {
             |vault, api| vault.sys_take(input.amount, api),
             api,
         )?;
+        Self::emit_withdraw_event(&bucket, api)?;
 
         Ok(IndexedScryptoValue::from_typed(&bucket))
     }
@@ -744,6 +855,7 @@ impl AccountNativePackage {
             |vault, api| vault.sys_take_non_fungibles(input.ids, api),
             api,
         )?;
+        Self::emit_withdraw_event(&bucket, api)?;
 
         Ok(IndexedScryptoValue::from_typed(&bucket))
     }
@@ -768,6 +880,7 @@ impl AccountNativePackage {
             |vault, api| vault.sys_take(input.amount, api),
             api,
         )?;
+        Self::emit_withdraw_event(&bucket, api)?;
 
         Ok(IndexedScryptoValue::from_typed(&bucket))
     }
@@ -792,6 +905,7 @@ impl AccountNativePackage {
             |vault, api| vault.sys_take_non_fungibles(input.ids, api),
             api,
         )?;
+        Self::emit_withdraw_event(&bucket, api)?;
 
         Ok(IndexedScryptoValue::from_typed(&bucket))
     }
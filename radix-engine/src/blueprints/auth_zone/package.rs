@@ -1,13 +1,12 @@
 use crate::errors::*;
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
 use crate::system::kernel_modules::auth::convert_contextless;
-use crate::system::kernel_modules::costing::{FIXED_HIGH_FEE, FIXED_LOW_FEE};
 use crate::system::node::RENodeModuleInit;
 use crate::system::node_modules::type_info::TypeInfoSubstate;
 use crate::types::*;
 use native_sdk::resource::SysProof;
 use radix_engine_interface::api::node_modules::auth::*;
-use radix_engine_interface::api::types::ClientCostingReason;
+use radix_engine_interface::api::types::{ClientCostUnits, ClientCostingReason};
 use radix_engine_interface::api::{ClientApi, LockFlags};
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::schema::{BlueprintSchema, FunctionSchema, PackageSchema, Receiver};
@@ -145,7 +144,10 @@ impl AuthZoneNativePackage {
     {
         match export_name {
             AUTH_ZONE_POP_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -153,7 +155,10 @@ impl AuthZoneNativePackage {
                 AuthZoneBlueprint::pop(receiver, input, api)
             }
             AUTH_ZONE_PUSH_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -161,7 +166,10 @@ impl AuthZoneNativePackage {
                 AuthZoneBlueprint::push(receiver, input, api)
             }
             AUTH_ZONE_CREATE_PROOF_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -169,7 +177,10 @@ impl AuthZoneNativePackage {
                 AuthZoneBlueprint::create_proof(receiver, input, api)
             }
             AUTH_ZONE_CREATE_PROOF_BY_AMOUNT_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -177,7 +188,10 @@ impl AuthZoneNativePackage {
                 AuthZoneBlueprint::create_proof_by_amount(receiver, input, api)
             }
             AUTH_ZONE_CREATE_PROOF_BY_IDS_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -185,7 +199,10 @@ impl AuthZoneNativePackage {
                 AuthZoneBlueprint::create_proof_by_ids(receiver, input, api)
             }
             AUTH_ZONE_CLEAR_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -193,7 +210,10 @@ impl AuthZoneNativePackage {
                 AuthZoneBlueprint::clear(receiver, input, api)
             }
             AUTH_ZONE_CLEAR_SIGNATURE_PROOFS_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -201,7 +221,10 @@ impl AuthZoneNativePackage {
                 AuthZoneBlueprint::clear_signature_proofs(receiver, input, api)
             }
             AUTH_ZONE_DRAIN_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -209,7 +232,10 @@ impl AuthZoneNativePackage {
                 AuthZoneBlueprint::drain(receiver, input, api)
             }
             AUTH_ZONE_ASSERT_ACCESS_RULE_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
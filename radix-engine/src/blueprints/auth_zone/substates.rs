@@ -113,14 +113,16 @@ impl AuthZone {
 
     pub fn clear_signature_proofs(&mut self) {
         self.virtual_resources
-            .retain(|x| x != &ECDSA_SECP256K1_TOKEN && x != &EDDSA_ED25519_TOKEN);
-        self.virtual_non_fungibles.retain(|x| {
-            x.resource_address() != ECDSA_SECP256K1_TOKEN
-                && x.resource_address() != EDDSA_ED25519_TOKEN
-        });
-        self.virtual_non_fungibles_non_extending.retain(|x| {
-            x.resource_address() != ECDSA_SECP256K1_TOKEN
-                && x.resource_address() != EDDSA_ED25519_TOKEN
-        });
+            .retain(|x| !Self::is_signature_resource(x));
+        self.virtual_non_fungibles
+            .retain(|x| !Self::is_signature_resource(&x.resource_address()));
+        self.virtual_non_fungibles_non_extending
+            .retain(|x| !Self::is_signature_resource(&x.resource_address()));
+    }
+
+    fn is_signature_resource(resource_address: &ResourceAddress) -> bool {
+        resource_address == &ECDSA_SECP256K1_TOKEN
+            || resource_address == &EDDSA_ED25519_TOKEN
+            || resource_address == &BLS12381_TOKEN
     }
 }
@@ -1,13 +1,12 @@
 use crate::errors::{InterpreterError, RuntimeError};
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
-use crate::system::kernel_modules::costing::{FIXED_HIGH_FEE, FIXED_LOW_FEE};
 use crate::types::*;
 use native_sdk::modules::access_rules::AccessRulesObject;
 use native_sdk::modules::metadata::Metadata;
 use native_sdk::modules::royalty::ComponentRoyalty;
 use radix_engine_interface::api::node_modules::auth::AuthAddresses;
 use radix_engine_interface::api::substate_api::LockFlags;
-use radix_engine_interface::api::types::ClientCostingReason;
+use radix_engine_interface::api::types::{ClientCostUnits, ClientCostingReason};
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::clock::ClockCreateInput;
 use radix_engine_interface::blueprints::clock::TimePrecision;
@@ -106,7 +105,10 @@ impl ClockNativePackage {
     {
         match export_name {
             CLOCK_CREATE_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 if receiver.is_some() {
                     return Err(RuntimeError::InterpreterError(
@@ -116,7 +118,10 @@ impl ClockNativePackage {
                 Self::create(input, api)
             }
             CLOCK_GET_CURRENT_TIME_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -124,7 +129,10 @@ impl ClockNativePackage {
                 Self::get_current_time(receiver, input, api)
             }
             CLOCK_SET_CURRENT_TIME_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -132,7 +140,10 @@ impl ClockNativePackage {
                 Self::set_current_time(receiver, input, api)
             }
             CLOCK_COMPARE_CURRENT_TIME_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -2,6 +2,7 @@ use super::{EpochChangeEvent, RoundChangeEvent, ValidatorCreator};
 use crate::errors::RuntimeError;
 use crate::errors::{ApplicationError, InterpreterError};
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
+use crate::system::kernel_modules::costing::FeeTable;
 use crate::types::*;
 use native_sdk::modules::access_rules::AccessRulesObject;
 use native_sdk::modules::metadata::Metadata;
@@ -39,9 +40,17 @@ pub struct ValidatorSetSubstate {
     pub epoch: u64,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct EpochManagerSetFeeTableInput {
+    pub fee_table: FeeTable,
+}
+
+pub type EpochManagerSetFeeTableOutput = ();
+
 #[derive(Debug, Clone, Eq, PartialEq, Sbor)]
 pub enum EpochManagerError {
     InvalidRoundUpdate { from: u64, to: u64 },
+    InvalidFeeTableVersionUpdate { from: u32, to: u32 },
 }
 
 pub struct EpochManagerBlueprint;
@@ -152,6 +161,7 @@ impl EpochManagerBlueprint {
                 scrypto_encode(&epoch_manager).unwrap(),
                 scrypto_encode(&current_validator_set).unwrap(),
                 scrypto_encode(&preparing_validator_set).unwrap(),
+                scrypto_encode(&FeeTable::new()).unwrap(),
             ],
         )?;
 
@@ -202,6 +212,13 @@ impl EpochManagerBlueprint {
             ),
             rule!(require(AuthAddresses::system_role())), // Set epoch only used for debugging
         );
+        access_rules.set_method_access_rule(
+            MethodKey::new(
+                NodeModuleId::SELF,
+                EPOCH_MANAGER_SET_FEE_TABLE_IDENT.to_string(),
+            ),
+            rule!(require(AuthAddresses::system_role())),
+        );
 
         let access_rules = AccessRulesObject::sys_new(access_rules, api)?;
         let metadata = Metadata::sys_create(api)?;
@@ -333,6 +350,41 @@ impl EpochManagerBlueprint {
         Ok(IndexedScryptoValue::from_typed(&()))
     }
 
+    pub(crate) fn set_fee_table<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: EpochManagerSetFeeTableInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::EpochManager(EpochManagerOffset::FeeTable),
+            LockFlags::MUTABLE,
+        )?;
+
+        let fee_table: &mut FeeTable = api.kernel_get_substate_ref_mut(handle)?;
+        if input.fee_table.version() <= fee_table.version() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(
+                    EpochManagerError::InvalidFeeTableVersionUpdate {
+                        from: fee_table.version(),
+                        to: input.fee_table.version(),
+                    },
+                ),
+            ));
+        }
+        *fee_table = input.fee_table;
+        api.sys_drop_lock(handle)?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
     pub(crate) fn create_validator<Y>(
         receiver: RENodeId,
         input: IndexedScryptoValue,
@@ -2,10 +2,10 @@ use crate::blueprints::epoch_manager::{EpochManagerBlueprint, ValidatorBlueprint
 use crate::errors::InterpreterError;
 use crate::errors::RuntimeError;
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
-use crate::system::kernel_modules::costing::FIXED_LOW_FEE;
+use crate::system::kernel_modules::costing::FeeTable;
 use crate::{event_schema, types::*};
 use radix_engine_interface::api::node_modules::auth::AuthAddresses;
-use radix_engine_interface::api::types::ClientCostingReason;
+use radix_engine_interface::api::types::{ClientCostUnits, ClientCostingReason};
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::epoch_manager::*;
 use radix_engine_interface::blueprints::resource::{require, AccessRule, FnKey};
@@ -23,6 +23,7 @@ impl EpochManagerNativePackage {
         substates.push(aggregator.add_child_type_and_descendents::<EpochManagerSubstate>());
         substates.push(aggregator.add_child_type_and_descendents::<ValidatorSetSubstate>());
         substates.push(aggregator.add_child_type_and_descendents::<ValidatorSetSubstate>());
+        substates.push(aggregator.add_child_type_and_descendents::<FeeTable>());
 
         let mut functions = BTreeMap::new();
         functions.insert(
@@ -54,6 +55,16 @@ impl EpochManagerNativePackage {
                 export_name: EPOCH_MANAGER_SET_EPOCH_IDENT.to_string(),
             },
         );
+        functions.insert(
+            EPOCH_MANAGER_SET_FEE_TABLE_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<EpochManagerSetFeeTableInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<EpochManagerSetFeeTableOutput>(),
+                export_name: EPOCH_MANAGER_SET_FEE_TABLE_IDENT.to_string(),
+            },
+        );
         functions.insert(
             EPOCH_MANAGER_NEXT_ROUND_IDENT.to_string(),
             FunctionSchema {
@@ -225,7 +236,10 @@ impl EpochManagerNativePackage {
     {
         match export_name {
             EPOCH_MANAGER_CREATE_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 if receiver.is_some() {
                     return Err(RuntimeError::InterpreterError(
@@ -235,7 +249,10 @@ impl EpochManagerNativePackage {
                 EpochManagerBlueprint::create(input, api)
             }
             EPOCH_MANAGER_GET_CURRENT_EPOCH_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -243,15 +260,32 @@ impl EpochManagerNativePackage {
                 EpochManagerBlueprint::get_current_epoch(receiver, input, api)
             }
             EPOCH_MANAGER_SET_EPOCH_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
                 ))?;
                 EpochManagerBlueprint::set_epoch(receiver, input, api)
             }
+            EPOCH_MANAGER_SET_FEE_TABLE_IDENT => {
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+                EpochManagerBlueprint::set_fee_table(receiver, input, api)
+            }
             EPOCH_MANAGER_NEXT_ROUND_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -259,7 +293,10 @@ impl EpochManagerNativePackage {
                 EpochManagerBlueprint::next_round(receiver, input, api)
             }
             EPOCH_MANAGER_CREATE_VALIDATOR_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -267,7 +304,10 @@ impl EpochManagerNativePackage {
                 EpochManagerBlueprint::create_validator(receiver, input, api)
             }
             EPOCH_MANAGER_UPDATE_VALIDATOR_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -275,7 +315,10 @@ impl EpochManagerNativePackage {
                 EpochManagerBlueprint::update_validator(receiver, input, api)
             }
             VALIDATOR_REGISTER_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -283,7 +326,10 @@ impl EpochManagerNativePackage {
                 ValidatorBlueprint::register(receiver, input, api)
             }
             VALIDATOR_UNREGISTER_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -291,7 +337,10 @@ impl EpochManagerNativePackage {
                 ValidatorBlueprint::unregister(receiver, input, api)
             }
             VALIDATOR_STAKE_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -299,7 +348,10 @@ impl EpochManagerNativePackage {
                 ValidatorBlueprint::stake(receiver, input, api)
             }
             VALIDATOR_UNSTAKE_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -307,7 +359,10 @@ impl EpochManagerNativePackage {
                 ValidatorBlueprint::unstake(receiver, input, api)
             }
             VALIDATOR_CLAIM_XRD_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -315,7 +370,10 @@ impl EpochManagerNativePackage {
                 ValidatorBlueprint::claim_xrd(receiver, input, api)
             }
             VALIDATOR_UPDATE_KEY_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -323,7 +381,10 @@ impl EpochManagerNativePackage {
                 ValidatorBlueprint::update_key(receiver, input, api)
             }
             VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT => {
-                api.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedLowFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
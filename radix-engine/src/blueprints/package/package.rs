@@ -1,7 +1,6 @@
 use super::PackageCodeTypeSubstate;
 use crate::errors::*;
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
-use crate::system::kernel_modules::costing::{FIXED_HIGH_FEE, FIXED_MEDIUM_FEE};
 use crate::system::node::{RENodeInit, RENodeModuleInit};
 use crate::system::node_modules::access_rules::{
     FunctionAccessRulesSubstate, MethodAccessRulesSubstate,
@@ -14,7 +13,7 @@ use native_sdk::resource::{ResourceManager, Vault};
 use radix_engine_interface::api::component::{
     ComponentRoyaltyAccumulatorSubstate, ComponentRoyaltyConfigSubstate,
 };
-use radix_engine_interface::api::types::ClientCostingReason;
+use radix_engine_interface::api::types::{ClientCostUnits, ClientCostingReason};
 use radix_engine_interface::api::{ClientApi, LockFlags};
 use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::blueprints::resource::{require, AccessRule, AccessRulesConfig, FnKey};
@@ -35,6 +34,19 @@ pub enum PackageError {
         actual: Option<String>,
     },
     InvalidEventSchema,
+
+    PackageNotUpgradeable,
+    BlueprintRemoved(String),
+    IncompatibleSubstates(String),
+    IncompatibleState(String),
+    InvalidMigration {
+        blueprint: String,
+        function: String,
+    },
+    MigrationRemoved {
+        blueprint: String,
+        function: String,
+    },
 }
 
 fn validate_package_schema(schema: &PackageSchema) -> Result<(), PackageError> {
@@ -92,6 +104,239 @@ fn validate_package_event_schema(schema: &PackageSchema) -> Result<(), PackageEr
     Ok(())
 }
 
+/// Checks that every value of the old type is also a valid value of the new type, i.e. that the
+/// new type only adds enum variants or relaxes validations.
+fn is_type_compatible(
+    old_schema: &ScryptoSchema,
+    old_type: LocalTypeIndex,
+    new_schema: &ScryptoSchema,
+    new_type: LocalTypeIndex,
+    visited: &mut Vec<(LocalTypeIndex, LocalTypeIndex)>,
+) -> bool {
+    // Recursive types are compatible unless shown otherwise
+    if visited.contains(&(old_type, new_type)) {
+        return true;
+    }
+    visited.push((old_type, new_type));
+
+    let (old_kind, new_kind) = match (
+        old_schema.resolve_type_kind(old_type),
+        new_schema.resolve_type_kind(new_type),
+    ) {
+        (Some(old_kind), Some(new_kind)) => (old_kind, new_kind),
+        _ => return false,
+    };
+    if let TypeKind::Any = new_kind {
+        return true;
+    }
+
+    let old_validation = old_schema.resolve_type_validation(old_type);
+    let new_validation = new_schema.resolve_type_validation(new_type);
+    if old_validation != new_validation && !matches!(new_validation, Some(TypeValidation::None)) {
+        return false;
+    }
+
+    let mut is_compatible = |old_type: &LocalTypeIndex, new_type: &LocalTypeIndex| {
+        is_type_compatible(old_schema, *old_type, new_schema, *new_type, visited)
+    };
+    match (old_kind, new_kind) {
+        (
+            TypeKind::Array {
+                element_type: old_element,
+            },
+            TypeKind::Array {
+                element_type: new_element,
+            },
+        ) => is_compatible(old_element, new_element),
+        (
+            TypeKind::Tuple {
+                field_types: old_fields,
+            },
+            TypeKind::Tuple {
+                field_types: new_fields,
+            },
+        ) => {
+            old_fields.len() == new_fields.len()
+                && old_fields
+                    .iter()
+                    .zip(new_fields)
+                    .all(|(old, new)| is_compatible(old, new))
+        }
+        (
+            TypeKind::Enum {
+                variants: old_variants,
+            },
+            TypeKind::Enum {
+                variants: new_variants,
+            },
+        ) => old_variants.iter().all(|(discriminator, old_fields)| {
+            new_variants.get(discriminator).map_or(false, |new_fields| {
+                old_fields.len() == new_fields.len()
+                    && old_fields
+                        .iter()
+                        .zip(new_fields)
+                        .all(|(old, new)| is_compatible(old, new))
+            })
+        }),
+        (
+            TypeKind::Map {
+                key_type: old_key,
+                value_type: old_value,
+            },
+            TypeKind::Map {
+                key_type: new_key,
+                value_type: new_value,
+            },
+        ) => is_compatible(old_key, new_key) && is_compatible(old_value, new_value),
+        (TypeKind::Custom(old_custom), TypeKind::Custom(new_custom)) => old_custom == new_custom,
+        // Remaining kinds have no type parameters
+        (old_kind, new_kind) => {
+            sbor::rust::mem::discriminant(old_kind) == sbor::rust::mem::discriminant(new_kind)
+        }
+    }
+}
+
+/// Checks that the given function of a blueprint can migrate component state from the old state
+/// type to the new one, i.e. that it takes the old state as its only argument and returns the
+/// new state.
+fn is_valid_migration(
+    blueprint_schema: &BlueprintSchema,
+    function: &str,
+    old_state: (&ScryptoSchema, LocalTypeIndex),
+    new_state: (&ScryptoSchema, LocalTypeIndex),
+) -> bool {
+    let function_schema = match blueprint_schema.functions.get(function) {
+        Some(function_schema) if function_schema.receiver.is_none() => function_schema,
+        _ => return false,
+    };
+    let input_type = match blueprint_schema
+        .schema
+        .resolve_type_kind(function_schema.input)
+    {
+        Some(TypeKind::Tuple { field_types }) if field_types.len() == 1 => field_types[0],
+        _ => return false,
+    };
+    is_type_compatible(
+        old_state.0,
+        old_state.1,
+        &blueprint_schema.schema,
+        input_type,
+        &mut Vec::new(),
+    ) && is_type_compatible(
+        &blueprint_schema.schema,
+        function_schema.output,
+        new_state.0,
+        new_state.1,
+        &mut Vec::new(),
+    )
+}
+
+/// Checks that the schema of new code can take over the components of the old code (including
+/// the ones which still have to run the migrations of earlier upgrades), and records the declared
+/// migrations against the current version.
+fn upgrade_package_info(
+    old_schema: &PackageSchema,
+    new_schema: &PackageSchema,
+    mut upgrade_info: PackageUpgradeInfo,
+    migrations: BTreeMap<String, String>,
+) -> Result<PackageUpgradeInfo, PackageError> {
+    for (blueprint, function) in &migrations {
+        if !old_schema.blueprints.contains_key(blueprint) {
+            return Err(PackageError::InvalidMigration {
+                blueprint: blueprint.clone(),
+                function: function.clone(),
+            });
+        }
+    }
+
+    let mut new_migrations = Vec::new();
+    for (blueprint, old) in &old_schema.blueprints {
+        let new = new_schema
+            .blueprints
+            .get(blueprint)
+            .ok_or(PackageError::BlueprintRemoved(blueprint.clone()))?;
+        if old.substates.len() != new.substates.len() {
+            return Err(PackageError::IncompatibleSubstates(blueprint.clone()));
+        }
+
+        if let Some(function) = migrations.get(blueprint) {
+            let migration = match (old.substates.get(0), new.substates.get(0)) {
+                (Some(old_state), Some(new_state))
+                    if is_valid_migration(
+                        new,
+                        function,
+                        (&old.schema, *old_state),
+                        (&new.schema, *new_state),
+                    ) =>
+                {
+                    PackageMigration {
+                        function: function.clone(),
+                        old_state_schema: old.schema.clone(),
+                        old_state_type: *old_state,
+                        new_state_schema: new.schema.clone(),
+                        new_state_type: *new_state,
+                    }
+                }
+                _ => {
+                    return Err(PackageError::InvalidMigration {
+                        blueprint: blueprint.clone(),
+                        function: function.clone(),
+                    })
+                }
+            };
+            new_migrations.push((blueprint.clone(), migration));
+        } else {
+            for (old_state, new_state) in old.substates.iter().zip(&new.substates) {
+                if !is_type_compatible(
+                    &old.schema,
+                    *old_state,
+                    &new.schema,
+                    *new_state,
+                    &mut Vec::new(),
+                ) {
+                    return Err(PackageError::IncompatibleState(blueprint.clone()));
+                }
+            }
+        }
+    }
+
+    // Components may still have to run the migrations of earlier upgrades, with the new code
+    for (blueprint, recorded) in &upgrade_info.migrations {
+        for migration in recorded.values() {
+            let new = new_schema
+                .blueprints
+                .get(blueprint)
+                .filter(|new| new.functions.contains_key(&migration.function))
+                .ok_or_else(|| PackageError::MigrationRemoved {
+                    blueprint: blueprint.clone(),
+                    function: migration.function.clone(),
+                })?;
+            if !is_valid_migration(
+                new,
+                &migration.function,
+                (&migration.old_state_schema, migration.old_state_type),
+                (&migration.new_state_schema, migration.new_state_type),
+            ) {
+                return Err(PackageError::InvalidMigration {
+                    blueprint: blueprint.clone(),
+                    function: migration.function.clone(),
+                });
+            }
+        }
+    }
+
+    for (blueprint, migration) in new_migrations {
+        upgrade_info
+            .migrations
+            .entry(blueprint)
+            .or_default()
+            .insert(upgrade_info.version, migration);
+    }
+    upgrade_info.version += 1;
+
+    Ok(upgrade_info)
+}
+
 fn globalize_package<Y>(
     package_address: Option<[u8; 26]>,
     info: PackageInfoSubstate,
@@ -191,6 +436,17 @@ impl PackageNativePackage {
                 export_name: PACKAGE_PUBLISH_WASM_IDENT.to_string(),
             },
         );
+        functions.insert(
+            PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT.to_string(),
+            FunctionSchema {
+                receiver: None,
+                input: aggregator
+                    .add_child_type_and_descendents::<PackagePublishWasmUpgradeableInput>(),
+                output: aggregator
+                    .add_child_type_and_descendents::<PackagePublishWasmUpgradeableOutput>(),
+                export_name: PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT.to_string(),
+            },
+        );
         functions.insert(
             PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
             FunctionSchema {
@@ -200,6 +456,15 @@ impl PackageNativePackage {
                 export_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            PACKAGE_UPGRADE_WASM_IDENT.to_string(),
+            FunctionSchema {
+                receiver: Some(schema::Receiver::SelfRefMut),
+                input: aggregator.add_child_type_and_descendents::<PackageUpgradeWasmInput>(),
+                output: aggregator.add_child_type_and_descendents::<PackageUpgradeWasmOutput>(),
+                export_name: PACKAGE_UPGRADE_WASM_IDENT.to_string(),
+            },
+        );
         functions.insert(
             PACKAGE_SET_ROYALTY_CONFIG_IDENT.to_string(),
             FunctionSchema {
@@ -242,6 +507,13 @@ impl PackageNativePackage {
             ),
             rule!(allow_all),
         );
+        access_rules.insert(
+            FnKey::new(
+                PACKAGE_BLUEPRINT.to_string(),
+                PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT.to_string(),
+            ),
+            rule!(allow_all),
+        );
         access_rules.insert(
             FnKey::new(
                 PACKAGE_BLUEPRINT.to_string(),
@@ -263,7 +535,10 @@ impl PackageNativePackage {
     {
         match export_name {
             PACKAGE_PUBLISH_NATIVE_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 if receiver.is_some() {
                     return Err(RuntimeError::InterpreterError(
@@ -274,7 +549,10 @@ impl PackageNativePackage {
                 Self::publish_native(input, api)
             }
             PACKAGE_PUBLISH_WASM_IDENT => {
-                api.consume_cost_units(FIXED_HIGH_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 if receiver.is_some() {
                     return Err(RuntimeError::InterpreterError(
@@ -284,9 +562,38 @@ impl PackageNativePackage {
 
                 Self::publish_wasm(input, api)
             }
+            PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT => {
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
+
+                if receiver.is_some() {
+                    return Err(RuntimeError::InterpreterError(
+                        InterpreterError::NativeUnexpectedReceiver(export_name.to_string()),
+                    ));
+                }
+
+                Self::publish_wasm_upgradeable(input, api)
+            }
+            PACKAGE_UPGRADE_WASM_IDENT => {
+                api.consume_cost_units(
+                    ClientCostUnits::FixedHighFee,
+                    ClientCostingReason::RunNative,
+                )?;
+
+                let receiver = receiver.ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::NativeExpectedReceiver(export_name.to_string()),
+                ))?;
+
+                Self::upgrade_wasm(receiver, input, api)
+            }
 
             PACKAGE_SET_ROYALTY_CONFIG_IDENT => {
-                api.consume_cost_units(FIXED_MEDIUM_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedMediumFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -295,7 +602,10 @@ impl PackageNativePackage {
                 Self::set_royalty_config(receiver, input, api)
             }
             PACKAGE_CLAIM_ROYALTY_IDENT => {
-                api.consume_cost_units(FIXED_MEDIUM_FEE, ClientCostingReason::RunNative)?;
+                api.consume_cost_units(
+                    ClientCostUnits::FixedMediumFee,
+                    ClientCostingReason::RunNative,
+                )?;
 
                 let receiver = receiver.ok_or(RuntimeError::InterpreterError(
                     InterpreterError::NativeExpectedReceiver(export_name.to_string()),
@@ -331,6 +641,7 @@ impl PackageNativePackage {
             schema: input.schema,
             dependent_resources: input.dependent_resources.into_iter().collect(),
             dependent_components: input.dependent_components.into_iter().collect(),
+            upgrade_info: None,
         };
         let code_type = PackageCodeTypeSubstate::Native;
         let code = PackageCodeSubstate {
@@ -369,6 +680,31 @@ impl PackageNativePackage {
             RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
         })?;
 
+        Self::globalize_wasm_package(input, None, api)
+    }
+
+    pub(crate) fn publish_wasm_upgradeable<Y>(
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: PackagePublishWasmUpgradeableInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        Self::globalize_wasm_package(input, Some(PackageUpgradeInfo::default()), api)
+    }
+
+    fn globalize_wasm_package<Y>(
+        input: PackagePublishWasmInput,
+        upgrade_info: Option<PackageUpgradeInfo>,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
         // Validate schema
         validate_package_schema(&input.schema)
             .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
@@ -389,6 +725,7 @@ impl PackageNativePackage {
             schema: input.schema,
             dependent_resources: BTreeSet::new(),
             dependent_components: BTreeSet::new(),
+            upgrade_info,
         };
 
         let code_type = PackageCodeTypeSubstate::Wasm;
@@ -415,6 +752,65 @@ impl PackageNativePackage {
         )
     }
 
+    pub(crate) fn upgrade_wasm<Y>(
+        receiver: RENodeId,
+        input: IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + KernelSubstateApi + ClientApi<RuntimeError>,
+    {
+        let input: PackageUpgradeWasmInput = input.as_typed().map_err(|e| {
+            RuntimeError::InterpreterError(InterpreterError::ScryptoInputDecodeError(e))
+        })?;
+
+        // Validate schema
+        validate_package_schema(&input.schema)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
+        validate_package_event_schema(&input.schema)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
+
+        // Validate WASM
+        WasmValidator::default()
+            .validate(&input.code, &input.schema)
+            .map_err(|e| {
+                RuntimeError::ApplicationError(ApplicationError::PackageError(
+                    PackageError::InvalidWasm(e),
+                ))
+            })?;
+
+        // Components are migrated lazily, so the new code must be able to take over their state
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::Package(PackageOffset::Info),
+            LockFlags::MUTABLE,
+        )?;
+        let info: &mut PackageInfoSubstate = api.kernel_get_substate_ref_mut(handle)?;
+        let upgrade_info = info
+            .upgrade_info
+            .clone()
+            .ok_or(RuntimeError::ApplicationError(
+                ApplicationError::PackageError(PackageError::PackageNotUpgradeable),
+            ))?;
+        let upgrade_info =
+            upgrade_package_info(&info.schema, &input.schema, upgrade_info, input.migrations)
+                .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
+        info.schema = input.schema;
+        info.upgrade_info = Some(upgrade_info);
+        api.kernel_drop_lock(handle)?;
+
+        let handle = api.sys_lock_substate(
+            receiver,
+            SubstateOffset::Package(PackageOffset::Code),
+            LockFlags::MUTABLE,
+        )?;
+        let code: &mut PackageCodeSubstate = api.kernel_get_substate_ref_mut(handle)?;
+        code.code = input.code;
+        api.kernel_drop_lock(handle)?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
     pub(crate) fn set_royalty_config<Y>(
         receiver: RENodeId,
         input: IndexedScryptoValue,
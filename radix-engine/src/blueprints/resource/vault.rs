@@ -108,8 +108,6 @@ impl FungibleVault {
         })?;
         api.sys_drop_lock(handle)?;
 
-        Runtime::emit_event(api, WithdrawResourceEvent::Amount(amount))?;
-
         Ok(taken)
     }
 
@@ -125,8 +123,6 @@ impl FungibleVault {
             return Ok(());
         }
 
-        let event = DepositResourceEvent::Amount(resource.amount());
-
         let handle = api.sys_lock_substate(
             node_id,
             SubstateOffset::Vault(VaultOffset::LiquidFungible),
@@ -140,8 +136,6 @@ impl FungibleVault {
         })?;
         api.sys_drop_lock(handle)?;
 
-        Runtime::emit_event(api, event)?;
-
         Ok(())
     }
 
@@ -312,8 +306,6 @@ impl NonFungibleVault {
         })?;
         api.sys_drop_lock(handle)?;
 
-        Runtime::emit_event(api, WithdrawResourceEvent::Amount(amount))?;
-
         Ok(taken)
     }
 
@@ -338,8 +330,6 @@ impl NonFungibleVault {
             .map_err(|e| RuntimeError::ApplicationError(ApplicationError::VaultError(e)))?;
         api.sys_drop_lock(handle)?;
 
-        Runtime::emit_event(api, WithdrawResourceEvent::Ids(ids.clone()))?;
-
         Ok(taken)
     }
 
@@ -355,8 +345,6 @@ impl NonFungibleVault {
             return Ok(());
         }
 
-        let event = DepositResourceEvent::Ids(resource.ids().clone());
-
         let handle = api.sys_lock_substate(
             node_id,
             SubstateOffset::Vault(VaultOffset::LiquidNonFungible),
@@ -371,8 +359,6 @@ impl NonFungibleVault {
         })?;
         api.sys_drop_lock(handle)?;
 
-        Runtime::emit_event(api, event)?;
-
         Ok(())
     }
 
@@ -574,6 +560,8 @@ impl VaultBlueprint {
         };
         let bucket_id = node_id.into();
 
+        Runtime::emit_event(api, WithdrawResourceEvent::Amount(input.amount))?;
+
         Ok(IndexedScryptoValue::from_typed(&Bucket(bucket_id)))
     }
 
@@ -599,6 +587,10 @@ impl VaultBlueprint {
             // Take
             let taken =
                 NonFungibleVault::take_non_fungibles(receiver, &input.non_fungible_local_ids, api)?;
+            Runtime::emit_event(
+                api,
+                WithdrawResourceEvent::Ids(input.non_fungible_local_ids),
+            )?;
 
             // Create node
             let bucket_id = api.new_object(
@@ -648,10 +640,18 @@ impl VaultBlueprint {
         // Put
         match other_bucket.resource {
             DroppedBucketResource::Fungible(r) => {
-                FungibleVault::put(receiver, r, api)?;
+                let event = DepositResourceEvent::Amount(r.amount());
+                if !r.is_empty() {
+                    FungibleVault::put(receiver, r, api)?;
+                    Runtime::emit_event(api, event)?;
+                }
             }
             DroppedBucketResource::NonFungible(r) => {
-                NonFungibleVault::put(receiver, r, api)?;
+                let event = DepositResourceEvent::Ids(r.ids().clone());
+                if !r.is_empty() {
+                    NonFungibleVault::put(receiver, r, api)?;
+                    Runtime::emit_event(api, event)?;
+                }
             }
         }
         Ok(IndexedScryptoValue::from_typed(&()))
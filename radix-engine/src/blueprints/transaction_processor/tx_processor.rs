@@ -311,6 +311,41 @@ impl TransactionProcessorBlueprint {
 
                     InstructionOutput::CallReturn(result_indexed.into())
                 }
+                Instruction::UpgradePackage {
+                    package_address,
+                    code,
+                    schema,
+                    migrations,
+                } => {
+                    let code = processor.get_blob(&code)?;
+                    let schema = processor.get_blob(&schema)?;
+                    let schema = scrypto_decode::<PackageSchema>(schema).map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                            TransactionProcessorError::InvalidPackageSchema(e),
+                        ))
+                    })?;
+
+                    let result = api.call_module_method(
+                        RENodeId::GlobalObject(package_address.into()),
+                        NodeModuleId::SELF,
+                        PACKAGE_UPGRADE_WASM_IDENT,
+                        scrypto_encode(&PackageUpgradeWasmInput {
+                            code: code.clone(),
+                            schema,
+                            migrations: migrations.clone(),
+                        })
+                        .unwrap(),
+                    )?;
+
+                    let result_indexed = IndexedScryptoValue::from_vec(result).unwrap();
+                    TransactionProcessor::move_proofs_to_authzone_and_buckets_to_worktop(
+                        &result_indexed,
+                        &worktop,
+                        api,
+                    )?;
+
+                    InstructionOutput::CallReturn(result_indexed.into())
+                }
                 Instruction::BurnResource { bucket_id } => {
                     let bucket = processor.take_bucket(&bucket_id)?;
                     let rtn = api.call_function(
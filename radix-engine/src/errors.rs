@@ -234,6 +234,9 @@ pub enum InterpreterError {
 
     ScryptoOutputDecodeError(DecodeError),
     ScryptoOutputSchemaNotMatch(String, String),
+
+    ScryptoMigratedStateSchemaNotMatch(String, String),
+    ScryptoMigrationChangedOwnedNodes(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
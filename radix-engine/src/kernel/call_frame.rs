@@ -343,6 +343,10 @@ impl CallFrame {
             RENodeId::GlobalObject(EDDSA_ED25519_TOKEN.into()),
             RENodeVisibilityOrigin::Normal,
         );
+        frame.add_ref(
+            RENodeId::GlobalObject(BLS12381_TOKEN.into()),
+            RENodeVisibilityOrigin::Normal,
+        );
         frame.add_ref(
             RENodeId::GlobalObject(PACKAGE_TOKEN.into()),
             RENodeVisibilityOrigin::Normal,
@@ -633,6 +637,27 @@ impl CallFrame {
         Ok(ref_mut)
     }
 
+    /// Lists the keys of the (non-empty) entries of a visible key-value store, in ascending
+    /// order, starting from the given key (inclusive, if given), up to the given limit.
+    pub fn list_kv_store_keys<'s>(
+        &self,
+        heap: &Heap,
+        track: &mut Track<'s>,
+        node_id: RENodeId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        self.check_node_visibility(&node_id)?;
+
+        let keys = if heap.contains_node(&node_id) {
+            heap.list_kv_store_keys(node_id, from, limit)?
+        } else {
+            track.list_kv_store_keys(node_id, from, limit)
+        };
+
+        Ok(keys)
+    }
+
     pub fn get_node_visibility(&self, node_id: &RENodeId) -> Option<RENodeVisibilityOrigin> {
         if self.owned_root_nodes.contains_key(node_id) {
             Some(RENodeVisibilityOrigin::Normal)
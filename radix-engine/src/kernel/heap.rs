@@ -5,8 +5,8 @@ use crate::system::node_modules::access_rules::AuthZoneStackSubstate;
 use crate::system::node_substates::{RuntimeSubstate, SubstateRef, SubstateRefMut};
 use crate::types::HashMap;
 use radix_engine_interface::api::types::{
-    AuthZoneStackOffset, BucketOffset, NodeModuleId, ProofOffset, RENodeId, SubstateId,
-    SubstateOffset,
+    AuthZoneStackOffset, BucketOffset, KeyValueStoreOffset, NodeModuleId, ProofOffset, RENodeId,
+    SubstateId, SubstateOffset,
 };
 use radix_engine_interface::blueprints::resource::{
     LiquidFungibleResource, LiquidNonFungibleResource, ResourceType,
@@ -97,6 +97,38 @@ impl Heap {
         }
     }
 
+    /// Lists the keys of the (non-empty) entries of the given key-value store, in ascending order,
+    /// starting from the given key (inclusive, if given), up to the given limit.
+    pub fn list_kv_store_keys(
+        &self,
+        node_id: RENodeId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Vec<u8>>, CallFrameError> {
+        let node = self
+            .nodes
+            .get(&node_id)
+            .ok_or(CallFrameError::RENodeNotOwned(node_id))?;
+
+        let mut keys: Vec<Vec<u8>> = node
+            .substates
+            .iter()
+            .filter_map(
+                |((module_id, offset), substate)| match (module_id, offset, substate) {
+                    (
+                        NodeModuleId::SELF,
+                        SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+                        RuntimeSubstate::KeyValueStoreEntry(Some(..)),
+                    ) if from.map_or(true, |from| key.as_slice() >= from) => Some(key.clone()),
+                    _ => None,
+                },
+            )
+            .collect();
+        keys.sort();
+        keys.truncate(limit);
+        Ok(keys)
+    }
+
     pub fn create_node(&mut self, node_id: RENodeId, node: HeapRENode) {
         self.nodes.insert(node_id, node);
     }
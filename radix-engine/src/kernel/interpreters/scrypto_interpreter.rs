@@ -17,7 +17,12 @@ use crate::system::node_modules::metadata::MetadataNativePackage;
 use crate::system::node_modules::royalty::RoyaltyNativePackage;
 use crate::system::node_modules::type_info::{TypeInfoBlueprint, TypeInfoSubstate};
 use crate::types::*;
-use crate::wasm::{WasmEngine, WasmInstance, WasmInstrumenter, WasmMeteringConfig, WasmRuntime};
+use crate::wasm::{
+    CodeKey, WasmEngine, WasmInstance, WasmInstrumenter, WasmMeteringConfig, WasmRuntime,
+};
+use radix_engine_interface::api::component::{
+    ComponentStateSubstate, ComponentStateVersionSubstate,
+};
 use radix_engine_interface::api::node_modules::auth::ACCESS_RULES_BLUEPRINT;
 use radix_engine_interface::api::node_modules::metadata::METADATA_BLUEPRINT;
 use radix_engine_interface::api::node_modules::royalty::COMPONENT_ROYALTY_BLUEPRINT;
@@ -90,6 +95,98 @@ fn validate_output(
     Ok(value)
 }
 
+/// Brings the state of a component up to the current version of its upgradeable package, by
+/// running the migration functions recorded for each version it missed, oldest first.
+///
+/// Migrations run within the frame of the method call which triggered them, and must keep
+/// owning exactly the nodes the state owned before.
+fn migrate_component_state<Y, W>(
+    node_id: RENodeId,
+    blueprint_name: &str,
+    blueprint_schema: &BlueprintSchema,
+    upgrade_info: &PackageUpgradeInfo,
+    wasm_instance: &mut W::WasmInstance,
+    api: &mut Y,
+) -> Result<(), RuntimeError>
+where
+    Y: KernelNodeApi + KernelSubstateApi + KernelWasmApi<W> + ClientApi<RuntimeError>,
+    W: WasmEngine,
+{
+    let version_handle = api.kernel_lock_substate(
+        node_id,
+        NodeModuleId::SELF,
+        SubstateOffset::Component(ComponentOffset::StateVersion),
+        LockFlags::MUTABLE,
+    )?;
+    let version: &ComponentStateVersionSubstate = api.kernel_get_substate_ref(version_handle)?;
+    let state_version = version.0;
+    if state_version >= upgrade_info.version {
+        api.kernel_drop_lock(version_handle)?;
+        return Ok(());
+    }
+
+    let state_handle = api.kernel_lock_substate(
+        node_id,
+        NodeModuleId::SELF,
+        SubstateOffset::Component(ComponentOffset::State0),
+        LockFlags::MUTABLE,
+    )?;
+    let state: &ComponentStateSubstate = api.kernel_get_substate_ref(state_handle)?;
+    let mut state = IndexedScryptoValue::from_scrypto_value(state.0.clone());
+    let mut owned_nodes = state.owned_node_ids().clone();
+
+    for version in state_version..upgrade_info.version {
+        if let Some(ident) = upgrade_info.migration(blueprint_name, version) {
+            let args = IndexedScryptoValue::from_typed(&(state.to_scrypto_value(),));
+            let export_name = validate_input(blueprint_schema, ident, false, &args)?;
+
+            let output = {
+                let mut runtime: Box<dyn WasmRuntime> = Box::new(ScryptoRuntime::new(api));
+                let input = vec![runtime
+                    .allocate_buffer(args.into())
+                    .expect("Failed to allocate buffer")];
+                wasm_instance.invoke_export(&export_name, input, &mut runtime)?
+            };
+
+            state = validate_output(blueprint_schema, ident, output)?;
+        }
+    }
+
+    // Blueprints without a migration must have kept a compatible state type, which is checked
+    // on upgrade, but the result of migrations is only known now.
+    if let Some(state_type) = blueprint_schema.substates.get(0) {
+        validate_payload_against_schema(state.as_slice(), &blueprint_schema.schema, *state_type)
+            .map_err(|err| {
+                RuntimeError::InterpreterError(
+                    InterpreterError::ScryptoMigratedStateSchemaNotMatch(
+                        blueprint_name.to_string(),
+                        err.error_message(&blueprint_schema.schema),
+                    ),
+                )
+            })?;
+    }
+
+    let mut migrated_owned_nodes = state.owned_node_ids().clone();
+    owned_nodes.sort();
+    migrated_owned_nodes.sort();
+    if owned_nodes != migrated_owned_nodes {
+        return Err(RuntimeError::InterpreterError(
+            InterpreterError::ScryptoMigrationChangedOwnedNodes(blueprint_name.to_string()),
+        ));
+    }
+
+    let substate: &mut ComponentStateSubstate = api.kernel_get_substate_ref_mut(state_handle)?;
+    *substate = ComponentStateSubstate(state.to_scrypto_value());
+    api.kernel_drop_lock(state_handle)?;
+
+    let version: &mut ComponentStateVersionSubstate =
+        api.kernel_get_substate_ref_mut(version_handle)?;
+    version.0 = upgrade_info.version;
+    api.kernel_drop_lock(version_handle)?;
+
+    Ok(())
+}
+
 impl ExecutableInvocation for MethodInvocation {
     type Exec = ScryptoExecutor;
 
@@ -169,6 +266,7 @@ impl ExecutableInvocation for MethodInvocation {
                             .insert(RENodeId::GlobalObject(ECDSA_SECP256K1_TOKEN.into()));
                         node_refs_to_copy
                             .insert(RENodeId::GlobalObject(EDDSA_ED25519_TOKEN.into()));
+                        node_refs_to_copy.insert(RENodeId::GlobalObject(BLS12381_TOKEN.into()));
                     }
                     _ => {}
                 }
@@ -249,6 +347,7 @@ impl ExecutableInvocation for FunctionInvocation {
                             .insert(RENodeId::GlobalObject(ECDSA_SECP256K1_TOKEN.into()));
                         node_refs_to_copy
                             .insert(RENodeId::GlobalObject(EDDSA_ED25519_TOKEN.into()));
+                        node_refs_to_copy.insert(RENodeId::GlobalObject(BLS12381_TOKEN.into()));
                     }
                     _ => {}
                 }
@@ -339,7 +438,7 @@ impl Executor for ScryptoExecutor {
             api.kernel_drop_lock(handle)?;
 
             // Load schema
-            let schema = {
+            let (schema, upgrade_info) = {
                 let handle = api.kernel_lock_substate(
                     RENodeId::GlobalObject(self.fn_identifier.package_address.into()),
                     NodeModuleId::SELF,
@@ -358,8 +457,9 @@ impl Executor for ScryptoExecutor {
                         ),
                     ))?
                     .clone();
+                let upgrade_info = package_info.upgrade_info.clone();
                 api.kernel_drop_lock(handle)?;
-                schema
+                (schema, upgrade_info)
             };
 
             //  Validate input
@@ -412,15 +512,28 @@ impl Executor for ScryptoExecutor {
                             SubstateOffset::Package(PackageOffset::Code),
                             LockFlags::read_only(),
                         )?;
-                        let wasm_instance = api.kernel_create_wasm_instance(
-                            self.fn_identifier.package_address,
-                            handle,
-                        )?;
+                        let wasm_instance = api.kernel_create_wasm_instance(handle)?;
                         api.kernel_drop_lock(handle)?;
 
                         wasm_instance
                     };
 
+                    // Migrate the receiver's state if its package has been upgraded since
+                    if let (
+                        Some(upgrade_info),
+                        Some(MethodIdentifier(node_id, NodeModuleId::SELF, ..)),
+                    ) = (&upgrade_info, &self.receiver)
+                    {
+                        migrate_component_state::<Y, W>(
+                            *node_id,
+                            &self.fn_identifier.blueprint_name,
+                            &schema,
+                            upgrade_info,
+                            &mut wasm_instance,
+                            api,
+                        )?;
+                    }
+
                     let output = {
                         let mut runtime: Box<dyn WasmRuntime> = Box::new(ScryptoRuntime::new(api));
 
@@ -539,10 +652,10 @@ impl<W: WasmEngine + Default> Default for ScryptoInterpreter<W> {
 }
 
 impl<W: WasmEngine> ScryptoInterpreter<W> {
-    pub fn create_instance(&self, package_address: PackageAddress, code: &[u8]) -> W::WasmInstance {
+    pub fn create_instance(&self, code_key: CodeKey, code: &[u8]) -> W::WasmInstance {
         let instrumented_code =
             self.wasm_instrumenter
-                .instrument(package_address, code, self.wasm_metering_config);
+                .instrument(code_key, code, self.wasm_metering_config);
         self.wasm_engine.instantiate(&instrumented_code)
     }
 }
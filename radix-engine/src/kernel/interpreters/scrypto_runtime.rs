@@ -4,8 +4,8 @@ use crate::system::kernel_modules::costing::*;
 use crate::types::*;
 use crate::wasm::*;
 use radix_engine_interface::api::substate_api::LockFlags;
-use radix_engine_interface::api::types::ClientCostingReason;
 use radix_engine_interface::api::types::Level;
+use radix_engine_interface::api::types::{ClientCostUnits, ClientCostingReason};
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::schema::KeyValueStoreSchema;
 use sbor::rust::vec::Vec;
@@ -205,6 +205,50 @@ where
         Ok(())
     }
 
+    fn remove_key_value_store_entry(
+        &mut self,
+        node_id: Vec<u8>,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let node_id =
+            scrypto_decode::<RENodeId>(&node_id).map_err(WasmRuntimeError::InvalidNodeId)?;
+
+        let entry = self.api.sys_remove_key_value_store_entry(node_id, key)?;
+
+        self.allocate_buffer(entry)
+    }
+
+    fn list_key_value_store_keys(
+        &mut self,
+        node_id: Vec<u8>,
+        from: Vec<u8>,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let node_id =
+            scrypto_decode::<RENodeId>(&node_id).map_err(WasmRuntimeError::InvalidNodeId)?;
+        let from = scrypto_decode::<Option<Vec<u8>>>(&from)
+            .map_err(WasmRuntimeError::InvalidKeyValueStoreKey)?;
+
+        let keys = self
+            .api
+            .sys_list_key_value_store_keys(node_id, from, limit)?;
+
+        let buffer = scrypto_encode(&keys).expect("Failed to encode key-value store keys");
+        self.allocate_buffer(buffer)
+    }
+
+    fn count_key_value_store_entries(
+        &mut self,
+        node_id: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let node_id =
+            scrypto_decode::<RENodeId>(&node_id).map_err(WasmRuntimeError::InvalidNodeId)?;
+
+        let count = self.api.sys_count_key_value_store_entries(node_id)?;
+
+        Ok(count)
+    }
+
     fn get_actor(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         let actor = self.api.get_fn_identifier()?;
 
@@ -214,7 +258,7 @@ where
 
     fn consume_cost_units(&mut self, n: u32) -> Result<(), InvokeError<WasmRuntimeError>> {
         self.api
-            .consume_cost_units(n, ClientCostingReason::RunWasm)
+            .consume_cost_units(ClientCostUnits::Exact(n), ClientCostingReason::RunWasm)
             .map_err(InvokeError::downstream)
     }
 
@@ -260,6 +304,21 @@ where
         Ok(())
     }
 
+    fn log_structured_message(
+        &mut self,
+        level: Vec<u8>,
+        message: Vec<u8>,
+        fields: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api.log_structured_message(
+            scrypto_decode::<Level>(&level).map_err(WasmRuntimeError::InvalidLogLevel)?,
+            String::from_utf8(message).map_err(|_| WasmRuntimeError::InvalidString)?,
+            scrypto_decode::<Vec<(String, String)>>(&fields)
+                .map_err(WasmRuntimeError::InvalidLogFields)?,
+        )?;
+        Ok(())
+    }
+
     fn get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         let hash = self.api.get_transaction_hash()?;
 
@@ -271,6 +330,92 @@ where
 
         self.allocate_buffer(scrypto_encode(&uuid).expect("Failed to encode UUID"))
     }
+
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let public_key = EcdsaSecp256k1PublicKey::try_from(public_key.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidPublicKey)?;
+
+        let is_valid = self
+            .api
+            .verify_ecdsa_secp256k1(message, public_key, signature)?;
+
+        Ok(is_valid as u32)
+    }
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let public_key = EddsaEd25519PublicKey::try_from(public_key.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidPublicKey)?;
+
+        let is_valid = self
+            .api
+            .verify_eddsa_ed25519(message, public_key, signature)?;
+
+        Ok(is_valid as u32)
+    }
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let hash = self.api.keccak256_hash(data)?;
+
+        self.allocate_buffer(scrypto_encode(&hash).expect("Failed to encode hash"))
+    }
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let hash = self.api.sha256_hash(data)?;
+
+        self.allocate_buffer(scrypto_encode(&hash).expect("Failed to encode hash"))
+    }
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let hash = self.api.blake2b_256_hash(data)?;
+
+        self.allocate_buffer(scrypto_encode(&hash).expect("Failed to encode hash"))
+    }
+
+    fn verify_bls12381(
+        &mut self,
+        message_hash: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let message_hash =
+            Hash::try_from(message_hash.as_slice()).map_err(|_| WasmRuntimeError::InvalidHash)?;
+        let public_key = Bls12381G1PublicKey::try_from(public_key.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidPublicKey)?;
+
+        let is_valid = self
+            .api
+            .verify_bls12381(message_hash, public_key, signature)?;
+
+        Ok(is_valid as u32)
+    }
+
+    fn aggregate_verify_bls12381(
+        &mut self,
+        message_hash: Vec<u8>,
+        public_keys: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let message_hash =
+            Hash::try_from(message_hash.as_slice()).map_err(|_| WasmRuntimeError::InvalidHash)?;
+        let public_keys = scrypto_decode::<Vec<(Bls12381G1PublicKey, Vec<u8>)>>(&public_keys)
+            .map_err(WasmRuntimeError::InvalidPublicKeys)?;
+
+        let is_valid = self
+            .api
+            .aggregate_verify_bls12381(message_hash, public_keys, signature)?;
+
+        Ok(is_valid as u32)
+    }
 }
 
 /// A `Nop` runtime accepts any external function calls by doing nothing and returning void.
@@ -372,6 +517,30 @@ impl WasmRuntime for NopWasmRuntime {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn remove_key_value_store_entry(
+        &mut self,
+        node_id: Vec<u8>,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn list_key_value_store_keys(
+        &mut self,
+        node_id: Vec<u8>,
+        from: Vec<u8>,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn count_key_value_store_entries(
+        &mut self,
+        node_id: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn get_actor(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
@@ -412,6 +581,15 @@ impl WasmRuntime for NopWasmRuntime {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn log_structured_message(
+        &mut self,
+        level: Vec<u8>,
+        message: Vec<u8>,
+        fields: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
@@ -419,4 +597,52 @@ impl WasmRuntime for NopWasmRuntime {
     fn generate_uuid(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
+
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn verify_bls12381(
+        &mut self,
+        message_hash: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
+    fn aggregate_verify_bls12381(
+        &mut self,
+        message_hash: Vec<u8>,
+        public_keys: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
 }
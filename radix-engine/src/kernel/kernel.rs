@@ -1048,6 +1048,25 @@ where
 
         Ok(substate_ref_mut.into())
     }
+
+    fn kernel_list_kv_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        from: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        let keys = self.current_frame.list_kv_store_keys(
+            &self.heap,
+            &mut self.track,
+            node_id,
+            from.as_deref(),
+            limit as usize,
+        )?;
+
+        KernelModuleMixer::on_list_substates(self, &node_id, keys.len())?;
+
+        Ok(keys)
+    }
 }
 
 impl<'g, 's, W> KernelWasmApi<W> for Kernel<'g, 's, W>
@@ -1056,15 +1075,13 @@ where
 {
     fn kernel_create_wasm_instance(
         &mut self,
-        package_address: PackageAddress,
         handle: LockHandle,
     ) -> Result<W::WasmInstance, RuntimeError> {
         let package_code: &PackageCodeSubstate = self.kernel_get_substate_ref(handle)?;
         let code = package_code.code.clone();
+        let code_key = hash(&code);
 
-        Ok(self
-            .scrypto_interpreter
-            .create_instance(package_address, &code))
+        Ok(self.scrypto_interpreter.create_instance(code_key, &code))
     }
 }
 
@@ -79,12 +79,19 @@ pub trait KernelSubstateApi {
     where
         &'a mut S: From<SubstateRefMut<'a>>,
         'b: 'a;
+    /// Lists the keys of the (non-empty) entries of a visible key-value store, in ascending order
+    /// of their encoding, starting from the given key (inclusive, if given), up to the given limit
+    fn kernel_list_kv_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        from: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<Vec<u8>>, RuntimeError>;
 }
 
 pub trait KernelWasmApi<W: WasmEngine> {
     fn kernel_create_wasm_instance(
         &mut self,
-        package_address: PackageAddress,
         handle: LockHandle,
     ) -> Result<W::WasmInstance, RuntimeError>;
 }
@@ -174,4 +174,12 @@ pub trait KernelModule {
     ) -> Result<(), RuntimeError> {
         Ok(())
     }
+    #[inline(always)]
+    fn on_list_substates<Y: KernelModuleApi<RuntimeError>>(
+        _api: &mut Y,
+        _node_id: &RENodeId,
+        _count: usize,
+    ) -> Result<(), RuntimeError> {
+        Ok(())
+    }
 }
@@ -4,6 +4,7 @@ use crate::errors::*;
 use crate::kernel::call_frame::CallFrameUpdate;
 use crate::kernel::module::KernelModule;
 use crate::system::kernel_modules::auth::AuthModule;
+use crate::system::kernel_modules::cost_profiler::CostProfilerModule;
 use crate::system::kernel_modules::costing::CostingModule;
 use crate::system::kernel_modules::costing::FeeTable;
 use crate::system::kernel_modules::costing::SystemLoanFeeReserve;
@@ -38,6 +39,7 @@ bitflags! {
         const EXECUTION_TRACE = 0x01 << 6;
         const TRANSACTION_LIMITS = 0x01 << 7;
         const EVENTS = 0x01 << 8;
+        const COST_PROFILER = 0x01 << 9;
     }
 }
 
@@ -55,6 +57,7 @@ pub struct KernelModuleMixer {
     pub execution_trace: ExecutionTraceModule,
     pub transaction_limits: TransactionLimitsModule,
     pub events: EventsModule,
+    pub cost_profiler: CostProfilerModule,
 }
 
 impl KernelModuleMixer {
@@ -75,6 +78,10 @@ impl KernelModuleMixer {
             modules |= EnabledModules::EXECUTION_TRACE;
         }
 
+        if execution_config.cost_profiling {
+            modules |= EnabledModules::COST_PROFILER;
+        }
+
         if !execution_config.genesis {
             modules |= EnabledModules::COSTING;
             modules |= EnabledModules::AUTH;
@@ -89,16 +96,12 @@ impl KernelModuleMixer {
         Self {
             enabled_modules: modules,
             kernel_debug: KernelTraceModule {},
-            costing: CostingModule {
-                fee_reserve,
-                fee_table,
-                max_call_depth: execution_config.max_call_depth,
-            },
+            costing: CostingModule::new(fee_reserve, fee_table, execution_config.max_call_depth),
             node_move: NodeMoveModule {},
             auth: AuthModule {
                 params: auth_zone_params.clone(),
             },
-            logger: LoggerModule::default(),
+            logger: LoggerModule::new(execution_config.min_log_level),
             transaction_runtime: TransactionRuntimeModule {
                 tx_hash,
                 next_id: 0,
@@ -115,6 +118,7 @@ impl KernelModuleMixer {
                 execution_config.execution_trace.unwrap_or(0),
             ),
             events: EventsModule::default(),
+            cost_profiler: CostProfilerModule::default(),
         }
     }
 }
@@ -172,6 +176,11 @@ impl KernelModule for KernelModuleMixer {
             EventsModule::on_init(api)?;
         }
 
+        // Enable cost profiler
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::on_init(api)?;
+        }
+
         Ok(())
     }
 
@@ -204,6 +213,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::on_teardown(api)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::on_teardown(api)?;
+        }
         Ok(())
     }
 
@@ -240,6 +252,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::before_invoke(api, identifier, input_size)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::before_invoke(api, identifier, input_size)?;
+        }
         Ok(())
     }
 
@@ -277,6 +292,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::before_push_frame(api, actor, update, args)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::before_push_frame(api, actor, update, args)?;
+        }
         Ok(())
     }
 
@@ -312,6 +330,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::on_execution_start(api, caller)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::on_execution_start(api, caller)?;
+        }
         Ok(())
     }
 
@@ -348,6 +369,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::on_execution_finish(api, caller, update)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::on_execution_finish(api, caller, update)?;
+        }
         Ok(())
     }
 
@@ -380,6 +404,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::after_pop_frame(api)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::after_pop_frame(api)?;
+        }
         Ok(())
     }
 
@@ -415,6 +442,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::after_invoke(api, output_size)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::after_invoke(api, output_size)?;
+        }
         Ok(())
     }
 
@@ -450,6 +480,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::on_allocate_node_id(api, node_type)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::on_allocate_node_id(api, node_type)?;
+        }
         Ok(())
     }
 
@@ -492,6 +525,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::before_create_node(api, node_id, node_init, node_module_init)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::before_create_node(api, node_id, node_init, node_module_init)?;
+        }
         Ok(())
     }
 
@@ -527,6 +563,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::after_create_node(api, node_id)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::after_create_node(api, node_id)?;
+        }
         Ok(())
     }
 
@@ -562,6 +601,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::before_drop_node(api, node_id)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::before_drop_node(api, node_id)?;
+        }
         Ok(())
     }
 
@@ -594,6 +636,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::after_drop_node(api)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::after_drop_node(api)?;
+        }
         Ok(())
     }
 
@@ -632,6 +677,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::before_lock_substate(api, node_id, module_id, offset, flags)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::before_lock_substate(api, node_id, module_id, offset, flags)?;
+        }
         Ok(())
     }
 
@@ -668,6 +716,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::after_lock_substate(api, handle, size)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::after_lock_substate(api, handle, size)?;
+        }
         Ok(())
     }
 
@@ -704,6 +755,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::on_read_substate(api, lock_handle, size)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::on_read_substate(api, lock_handle, size)?;
+        }
         Ok(())
     }
 
@@ -740,6 +794,9 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::on_write_substate(api, lock_handle, size)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::on_write_substate(api, lock_handle, size)?;
+        }
         Ok(())
     }
 
@@ -775,6 +832,48 @@ impl KernelModule for KernelModuleMixer {
         if modules.contains(EnabledModules::EVENTS) {
             EventsModule::on_drop_lock(api, lock_handle)?;
         }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::on_drop_lock(api, lock_handle)?;
+        }
+        Ok(())
+    }
+
+    fn on_list_substates<Y: KernelModuleApi<RuntimeError>>(
+        api: &mut Y,
+        node_id: &RENodeId,
+        count: usize,
+    ) -> Result<(), RuntimeError> {
+        let modules: EnabledModules = api.kernel_get_module_state().enabled_modules;
+        if modules.contains(EnabledModules::KERNEL_DEBUG) {
+            KernelTraceModule::on_list_substates(api, node_id, count)?;
+        }
+        if modules.contains(EnabledModules::COSTING) {
+            CostingModule::on_list_substates(api, node_id, count)?;
+        }
+        if modules.contains(EnabledModules::NODE_MOVE) {
+            NodeMoveModule::on_list_substates(api, node_id, count)?;
+        }
+        if modules.contains(EnabledModules::AUTH) {
+            AuthModule::on_list_substates(api, node_id, count)?;
+        }
+        if modules.contains(EnabledModules::LOGGER) {
+            LoggerModule::on_list_substates(api, node_id, count)?;
+        }
+        if modules.contains(EnabledModules::TRANSACTION_RUNTIME) {
+            TransactionRuntimeModule::on_list_substates(api, node_id, count)?;
+        }
+        if modules.contains(EnabledModules::EXECUTION_TRACE) {
+            ExecutionTraceModule::on_list_substates(api, node_id, count)?;
+        }
+        if modules.contains(EnabledModules::TRANSACTION_LIMITS) {
+            TransactionLimitsModule::on_list_substates(api, node_id, count)?;
+        }
+        if modules.contains(EnabledModules::EVENTS) {
+            EventsModule::on_list_substates(api, node_id, count)?;
+        }
+        if modules.contains(EnabledModules::COST_PROFILER) {
+            CostProfilerModule::on_list_substates(api, node_id, count)?;
+        }
         Ok(())
     }
 }
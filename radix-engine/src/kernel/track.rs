@@ -5,6 +5,7 @@ use crate::state_manager::StateDiff;
 use crate::system::kernel_modules::costing::FinalizingFeeReserve;
 use crate::system::kernel_modules::costing::{CostingError, FeeReserveError};
 use crate::system::kernel_modules::costing::{FeeSummary, SystemLoanFeeReserve};
+use crate::system::kernel_modules::logger::LogRecord;
 use crate::system::node_modules::type_info::TypeInfoSubstate;
 use crate::system::node_substates::{
     PersistedSubstate, RuntimeSubstate, SubstateRef, SubstateRefMut,
@@ -17,7 +18,6 @@ use crate::transaction::TransactionResult;
 use crate::transaction::{AbortReason, AbortResult, CommitResult};
 use crate::types::*;
 use radix_engine_interface::api::substate_api::LockFlags;
-use radix_engine_interface::api::types::Level;
 use radix_engine_interface::api::types::*;
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use radix_engine_interface::blueprints::resource::VAULT_BLUEPRINT;
@@ -63,6 +63,68 @@ pub struct LoadedSubstate {
 pub struct Track<'s> {
     substate_store: &'s dyn ReadableSubstateStore,
     loaded_substates: IndexMap<SubstateId, LoadedSubstate>,
+    /// Every substate looked up in the store, including the ones which turned out to be missing
+    read_substates: BTreeSet<SubstateId>,
+    /// Every substate accessed for writing, regardless of whether the changes get committed
+    mutable_substates: BTreeSet<SubstateId>,
+    /// Every key-value store whose entries were listed from the store
+    listed_nodes: BTreeSet<RENodeId>,
+    /// Whether the existing substates left unchanged are omitted from the state updates
+    skip_unchanged_substates: bool,
+}
+
+/// The substates a transaction has read from and written to the substate store.
+///
+/// Two transactions may be executed in either order (with the same results) if neither of them
+/// reads a substate written by the other one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct SubstateFootprint {
+    pub reads: BTreeSet<SubstateId>,
+    pub writes: BTreeSet<SubstateId>,
+    /// The substates which were accessed for writing (including the ones whose changes were
+    /// rolled back, i.e. which are not among the `writes`)
+    pub mutable_accesses: BTreeSet<SubstateId>,
+    /// The key-value stores whose entries were listed (which depends on all of their entries,
+    /// including the ones added later)
+    pub listed_nodes: BTreeSet<RENodeId>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum SubstateAccess {
+    ReadOnly,
+    Mutable,
+}
+
+pub type SubstateFootprintByNode =
+    BTreeMap<RENodeId, BTreeMap<NodeModuleId, BTreeMap<SubstateOffset, SubstateAccess>>>;
+
+impl SubstateFootprint {
+    /// Groups all the accessed substates by node and module, labelled with their access type.
+    pub fn by_node(&self) -> SubstateFootprintByNode {
+        let mut footprint = SubstateFootprintByNode::new();
+        for substate_id in self
+            .reads
+            .iter()
+            .chain(self.writes.iter())
+            .chain(self.mutable_accesses.iter())
+        {
+            let access = if self.writes.contains(substate_id)
+                || self.mutable_accesses.contains(substate_id)
+            {
+                SubstateAccess::Mutable
+            } else {
+                SubstateAccess::ReadOnly
+            };
+            let SubstateId(node_id, module_id, offset) = substate_id;
+            footprint
+                .entry(*node_id)
+                .or_default()
+                .entry(*module_id)
+                .or_default()
+                .insert(offset.clone(), access);
+        }
+        footprint
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -84,11 +146,23 @@ impl<'s> Track<'s> {
         Self {
             substate_store,
             loaded_substates: index_map_new(),
+            read_substates: BTreeSet::new(),
+            mutable_substates: BTreeSet::new(),
+            listed_nodes: BTreeSet::new(),
+            skip_unchanged_substates: false,
         }
     }
 
+    /// Omits the existing substates whose value is left unchanged from the state updates, instead
+    /// of re-writing them with a new version (see `ExecutionConfig::skip_unchanged_substates`).
+    pub fn with_skip_unchanged_substates(mut self, skip_unchanged_substates: bool) -> Self {
+        self.skip_unchanged_substates = skip_unchanged_substates;
+        self
+    }
+
     /// Returns a copy of the substate associated with the given address, if exists
     fn load_substate(&mut self, substate_id: &SubstateId) -> Option<OutputValue> {
+        self.read_substates.insert(substate_id.clone());
         self.substate_store.get_substate(substate_id)
     }
 
@@ -152,6 +226,7 @@ impl<'s> Track<'s> {
                         ));
                     }
                     loaded_substate.lock_state = LockState::Write;
+                    self.mutable_substates.insert(substate_id);
                 } else {
                     loaded_substate.lock_state = LockState::Read(n + 1);
                 }
@@ -335,6 +410,7 @@ impl<'s> Track<'s> {
         match (node_id, offset) {
             (_, SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(..))) => {
                 let substate_id = SubstateId(node_id, module_id, offset.clone());
+                self.mutable_substates.insert(substate_id.clone());
                 if !self.loaded_substates.contains_key(&substate_id) {
                     let output = self.load_substate(&substate_id);
                     let (substate, version) = output
@@ -364,13 +440,64 @@ impl<'s> Track<'s> {
         }
     }
 
+    /// Lists the keys of the (non-empty) entries of the given key-value store, as of the changes
+    /// made so far (see `ReadableSubstateStore::list_kv_store_keys()`).
+    pub fn list_kv_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<Vec<u8>> {
+        let kv_store_id = match node_id {
+            RENodeId::KeyValueStore(kv_store_id) => kv_store_id,
+            _ => return Vec::new(),
+        };
+        self.listed_nodes.insert(node_id);
+
+        let overlay = self
+            .loaded_substates
+            .iter()
+            .filter_map(|(substate_id, loaded)| match substate_id {
+                SubstateId(
+                    loaded_node_id,
+                    NodeModuleId::SELF,
+                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+                ) if *loaded_node_id == node_id => Some((
+                    key.clone(),
+                    matches!(
+                        loaded.substate,
+                        RuntimeSubstate::KeyValueStoreEntry(Some(..))
+                    ),
+                )),
+                _ => None,
+            })
+            .collect();
+        let substate_store = self.substate_store;
+        merge_kv_store_keys(
+            |from, limit| substate_store.list_kv_store_keys(&kv_store_id, from, limit),
+            overlay,
+            from,
+            limit,
+        )
+    }
+
+    /// Discards all the changes, returning the footprint of the substates accessed so far.
+    pub fn discard(self) -> SubstateFootprint {
+        SubstateFootprint {
+            reads: self.read_substates,
+            writes: BTreeSet::new(),
+            mutable_accesses: self.mutable_substates,
+            listed_nodes: self.listed_nodes,
+        }
+    }
+
     pub fn finalize(
         mut self,
         mut invoke_result: Result<Vec<InstructionOutput>, RuntimeError>,
         mut fee_reserve: SystemLoanFeeReserve,
         application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
-        application_logs: Vec<(Level, String)>,
-    ) -> TransactionResult {
+        application_logs: Vec<LogRecord>,
+    ) -> (TransactionResult, SubstateFootprint) {
         // A `SuccessButFeeLoanNotRepaid` error is issued if a transaction finishes before
         // the SYSTEM_LOAN_AMOUNT is reached (which trigger a repay event) and even though
         // enough fee has been locked.
@@ -384,11 +511,11 @@ impl<'s> Track<'s> {
             }
         }
 
-        match determine_result_type(invoke_result, fee_reserve.fully_repaid()) {
+        let result = match determine_result_type(invoke_result, fee_reserve.fully_repaid()) {
             TransactionResultType::Commit(invoke_result) => {
                 let is_success = invoke_result.is_ok();
 
-                // Commit/rollback royalty
+                // Commit/rollback royalty and cleanup credits
                 if is_success {
                     for (_, (recipient_vault_id, amount)) in fee_reserve.royalty_cost() {
                         let node_id = RENodeId::Object(recipient_vault_id);
@@ -407,6 +534,7 @@ impl<'s> Track<'s> {
                     }
                 } else {
                     fee_reserve.revert_royalty();
+                    fee_reserve.revert_credits();
                 }
 
                 // Keep/rollback events
@@ -419,9 +547,24 @@ impl<'s> Track<'s> {
                 // Keep logs always, for better debuggability
                 let application_logs = application_logs;
 
+                let mut loaded_substates = self.loaded_substates;
+                if self.skip_unchanged_substates {
+                    let substate_store = self.substate_store;
+                    loaded_substates.retain(|id, loaded| match &loaded.metastate {
+                        SubstateMetaState::New => true,
+                        SubstateMetaState::Existing {
+                            state: ExistingMetaState::Updated(Some(..)),
+                            ..
+                        } => true,
+                        SubstateMetaState::Existing { .. } => substate_store
+                            .get_substate(id)
+                            .map(|output| output.substate != loaded.substate.clone_to_persisted())
+                            .unwrap_or(true),
+                    });
+                }
                 let finalizing_track = FinalizingTrack {
                     substate_store: self.substate_store,
-                    loaded_substates: self.loaded_substates.into_iter().collect(),
+                    loaded_substates,
                 };
                 TransactionResult::Commit(finalizing_track.calculate_commit_result(
                     invoke_result,
@@ -438,7 +581,25 @@ impl<'s> Track<'s> {
             TransactionResultType::Abort(abort_reason) => TransactionResult::Abort(AbortResult {
                 reason: abort_reason,
             }),
-        }
+        };
+
+        let footprint = SubstateFootprint {
+            reads: self.read_substates,
+            mutable_accesses: self.mutable_substates,
+            writes: match &result {
+                TransactionResult::Commit(commit) => commit
+                    .state_updates
+                    .up_substate_ids()
+                    .into_iter()
+                    .chain(commit.state_updates.removed_substate_ids())
+                    .cloned()
+                    .collect(),
+                TransactionResult::Reject(_) | TransactionResult::Abort(_) => BTreeSet::new(),
+            },
+            listed_nodes: self.listed_nodes,
+        };
+
+        (result, footprint)
     }
 }
 
@@ -511,43 +672,42 @@ struct FinalizingTrack<'s> {
 impl<'s> FinalizingTrack<'s> {
     fn calculate_commit_result(
         self,
-        invoke_result: Result<Vec<InstructionOutput>, RuntimeError>,
-        application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
-        application_logs: Vec<(Level, String)>,
-        fee_reserve: SystemLoanFeeReserve,
+        mut invoke_result: Result<Vec<InstructionOutput>, RuntimeError>,
+        mut application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
+        application_logs: Vec<LogRecord>,
+        mut fee_reserve: SystemLoanFeeReserve,
     ) -> CommitResult {
+        // Calculate the substates for persistence
+        let mut state_updates = self.persisted_updates(invoke_result.is_ok());
+        let mut net_storage_bytes = Self::net_storage_bytes(self.substate_store, &state_updates);
+
+        // The storage cost is only known now: a transaction whose locked fees don't cover it
+        // fails, which leaves only the fee payments to persist
+        if invoke_result.is_ok() && !fee_reserve.covers_storage_cost(net_storage_bytes) {
+            invoke_result = Err(RuntimeError::ModuleError(ModuleError::CostingError(
+                CostingError::FeeReserveError(FeeReserveError::InsufficientBalance),
+            )));
+            fee_reserve.revert_royalty();
+            fee_reserve.revert_credits();
+            application_events.clear();
+            state_updates = self.persisted_updates(false);
+            net_storage_bytes = Self::net_storage_bytes(self.substate_store, &state_updates);
+        }
         let is_success = invoke_result.is_ok();
 
-        // Calculate the substates for persistence
-        let mut state_updates = index_map_new();
-        if is_success {
-            for (id, loaded) in self.loaded_substates {
-                let old_version = match &loaded.metastate {
-                    SubstateMetaState::New => None,
-                    SubstateMetaState::Existing { old_version, .. } => Some(*old_version),
-                };
-                state_updates.insert(id, (loaded.substate.to_persisted(), old_version));
-            }
-        } else {
-            for (id, loaded) in self.loaded_substates {
-                match loaded.metastate {
-                    SubstateMetaState::Existing {
-                        old_version,
-                        state: ExistingMetaState::Updated(Some(force_persist)),
-                    } => {
-                        state_updates.insert(id, (force_persist, Some(old_version)));
-                    }
-                    _ => {}
-                }
-            }
-        };
+        // Charge (or refund) the net state growth
+        fee_reserve.set_net_storage_bytes(net_storage_bytes);
 
         // Finalize fee payments
-        let fee_summary = fee_reserve.finalize();
+        let mut fee_summary = fee_reserve.finalize();
         let mut fee_payments: IndexMap<ObjectId, Decimal> = index_map_new();
-        let mut required = fee_summary.total_execution_cost_xrd
-            + fee_summary.total_royalty_cost_xrd
-            - fee_summary.total_bad_debt_xrd;
+        let mut required = Decimal::max(
+            fee_summary.total_execution_cost_xrd
+                + fee_summary.total_royalty_cost_xrd
+                + fee_summary.total_storage_cost_xrd
+                - fee_summary.total_bad_debt_xrd,
+            Decimal::zero(),
+        );
         for (vault_id, mut locked, contingent) in fee_summary.locked_fees.iter().cloned().rev() {
             let amount = if contingent {
                 if is_success {
@@ -578,6 +738,12 @@ impl<'s> FinalizingTrack<'s> {
             *fee_payments.entry(vault_id).or_default() += amount;
         }
 
+        // Only the (negligible) storage cost of the fee payments of a failed transaction may be
+        // left uncovered
+        if required.is_positive() {
+            fee_summary.total_bad_debt_xrd += required;
+        }
+
         // TODO: update XRD total supply or disable it
         // TODO: pay tips to the lead validator
 
@@ -599,6 +765,56 @@ impl<'s> FinalizingTrack<'s> {
         }
     }
 
+    /// Returns the substates to persist: all of them if the transaction succeeded, otherwise only
+    /// the ones which have to be persisted regardless (i.e. the fee payments).
+    fn persisted_updates(
+        &self,
+        is_success: bool,
+    ) -> IndexMap<SubstateId, (PersistedSubstate, Option<u32>)> {
+        let mut state_updates = index_map_new();
+        for (id, loaded) in &self.loaded_substates {
+            match &loaded.metastate {
+                SubstateMetaState::New if is_success => {
+                    state_updates.insert(id.clone(), (loaded.substate.clone_to_persisted(), None));
+                }
+                SubstateMetaState::Existing { old_version, .. } if is_success => {
+                    state_updates.insert(
+                        id.clone(),
+                        (loaded.substate.clone_to_persisted(), Some(*old_version)),
+                    );
+                }
+                SubstateMetaState::Existing {
+                    old_version,
+                    state: ExistingMetaState::Updated(Some(force_persist)),
+                } => {
+                    state_updates.insert(id.clone(), (force_persist.clone(), Some(*old_version)));
+                }
+                _ => {}
+            }
+        }
+        state_updates
+    }
+
+    /// Returns the number of bytes added to the substate store by the given updates, minus the
+    /// number of bytes they free.
+    fn net_storage_bytes(
+        substate_store: &dyn ReadableSubstateStore,
+        state_updates: &IndexMap<SubstateId, (PersistedSubstate, Option<u32>)>,
+    ) -> i64 {
+        let mut net_storage_bytes = 0i64;
+        for (substate_id, (substate, old_version)) in state_updates {
+            if !Self::is_removal(substate) {
+                net_storage_bytes += scrypto_encode(substate).unwrap().len() as i64;
+            }
+            if old_version.is_some() {
+                if let Some(output) = substate_store.get_substate(substate_id) {
+                    net_storage_bytes -= scrypto_encode(&output.substate).unwrap().len() as i64;
+                }
+            }
+        }
+        net_storage_bytes
+    }
+
     pub fn summarize_update(
         substate_store: &dyn ReadableSubstateStore,
         state_updates: &IndexMap<SubstateId, (PersistedSubstate, Option<u32>)>,
@@ -642,6 +858,16 @@ impl<'s> FinalizingTrack<'s> {
         let mut diff = StateDiff::new();
 
         for (substate_id, (substate, ..)) in state_updates {
+            // A removed substate only goes down (if it was there in the first place)
+            if Self::is_removal(&substate) {
+                if let Some(existing_output_id) =
+                    Self::get_substate_output_id(substate_store, &substate_id)
+                {
+                    diff.down_substates.insert(existing_output_id);
+                }
+                continue;
+            }
+
             let next_version = if let Some(existing_output_id) =
                 Self::get_substate_output_id(substate_store, &substate_id)
             {
@@ -661,6 +887,12 @@ impl<'s> FinalizingTrack<'s> {
         diff
     }
 
+    /// Returns whether persisting the given substate means deleting it (i.e. whether it is an
+    /// empty key-value store entry).
+    fn is_removal(substate: &PersistedSubstate) -> bool {
+        matches!(substate, PersistedSubstate::KeyValueStoreEntry(None))
+    }
+
     fn get_substate_output_id(
         substate_store: &dyn ReadableSubstateStore,
         substate_id: &SubstateId,
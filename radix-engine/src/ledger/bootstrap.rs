@@ -411,6 +411,27 @@ pub fn create_genesis(
         });
     }
 
+    // BLS12-381 Token
+    {
+        let metadata: BTreeMap<String, String> = BTreeMap::new();
+        let mut access_rules = BTreeMap::new();
+        access_rules.insert(Withdraw, (rule!(allow_all), rule!(deny_all)));
+        let resource_address = BLS12381_TOKEN.to_array_without_entity_id();
+        pre_allocated_ids.insert(RENodeId::GlobalObject(BLS12381_TOKEN.into()));
+        instructions.push(Instruction::CallFunction {
+            package_address: RESOURCE_MANAGER_PACKAGE,
+            blueprint_name: NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_string(),
+            function_name: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_ADDRESS_IDENT.to_string(),
+            args: to_manifest_value(&NonFungibleResourceManagerCreateWithAddressInput {
+                id_type: NonFungibleIdType::Bytes,
+                non_fungible_schema: NonFungibleDataSchema::new_schema::<()>(),
+                metadata,
+                access_rules,
+                resource_address,
+            }),
+        });
+    }
+
     // System Token
     {
         let metadata: BTreeMap<String, String> = BTreeMap::new();
@@ -515,9 +536,7 @@ pub fn create_genesis(
             }
             .into(),
         );
-        let component_address = ComponentAddress::virtual_account_from_public_key(
-            &PublicKey::EcdsaSecp256k1(public_key),
-        );
+        let component_address = ComponentAddress::virtual_account_from_public_key(&public_key);
         instructions.push(
             Instruction::CallMethod {
                 component_address: component_address,
@@ -678,9 +697,8 @@ mod tests {
         let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
         let mut substate_store = TypedInMemorySubstateStore::new();
         let account_public_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap().public_key();
-        let account_component_address = ComponentAddress::virtual_account_from_public_key(
-            &PublicKey::EcdsaSecp256k1(account_public_key.clone()),
-        );
+        let account_component_address =
+            ComponentAddress::virtual_account_from_public_key(&account_public_key);
         let allocation_amount = dec!("100");
         let mut account_xrd_allocations = BTreeMap::new();
         account_xrd_allocations.insert(account_public_key, allocation_amount);
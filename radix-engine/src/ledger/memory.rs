@@ -1,7 +1,9 @@
 use crate::kernel::interpreters::ScryptoInterpreter;
 use crate::ledger::*;
 use crate::ledger::{OutputValue, WriteableSubstateStore};
+use crate::state_manager::{CommitLogStore, CommitReceipt, CommittedTransaction, StateDiff};
 use crate::system::node_substates::PersistedSubstate;
+use crate::transaction::CommitResult;
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::types::{
@@ -9,18 +11,45 @@ use radix_engine_interface::api::types::{
 };
 
 /// A substate store that stores all typed substates in host memory.
+/// If enabled (since they grow with every commit), it also retains all the past values of the
+/// substates (see `with_history()`) and the log of committed transactions (see
+/// `with_commit_log()`).
 #[derive(Debug, PartialEq, Eq)]
 pub struct TypedInMemorySubstateStore {
     substates: HashMap<SubstateId, OutputValue>,
+    /// All the values of each substate, with the state versions they were put (or removed, if
+    /// `None`) at, in ascending order - or `None` if the history is not retained.
+    substate_history: Option<HashMap<SubstateId, Vec<(u64, Option<OutputValue>)>>>,
+    state_version: u64,
+    /// The logged transactions, or `None` if the log is not retained.
+    commit_log: Option<Vec<CommittedTransaction>>,
 }
 
 impl TypedInMemorySubstateStore {
     pub fn new() -> Self {
         Self {
             substates: HashMap::new(),
+            substate_history: None,
+            state_version: 0,
+            commit_log: None,
         }
     }
 
+    /// Makes the store retain all the past values of the substates, so that it can serve reads
+    /// at past state versions (see `VersionedSubstateStore`).
+    /// It has to be requested before anything is put into the store.
+    pub fn with_history(mut self) -> Self {
+        self.substate_history = Some(HashMap::new());
+        self
+    }
+
+    /// Makes the store log the transactions committed via `CommitLogStore::commit_and_log()`
+    /// (otherwise the log stays empty).
+    pub fn with_commit_log(mut self) -> Self {
+        self.commit_log = Some(Vec::new());
+        self
+    }
+
     pub fn with_bootstrap<W: WasmEngine>(scrypto_interpreter: &ScryptoInterpreter<W>) -> Self {
         let mut substate_store = Self::new();
         bootstrap(&mut substate_store, scrypto_interpreter);
@@ -51,6 +80,26 @@ impl TypedInMemorySubstateStore {
             }
         }
     }
+
+    /// Records the value put (or removed, if `None`) at the current state version, if the history
+    /// is retained.
+    fn record_history(&mut self, substate_id: SubstateId, substate: Option<OutputValue>) {
+        if let Some(substate_history) = &mut self.substate_history {
+            let history = substate_history.entry(substate_id).or_default();
+            if let Some((version, _)) = history.last() {
+                if *version == self.state_version {
+                    history.pop();
+                }
+            }
+            history.push((self.state_version, substate));
+        }
+    }
+
+    fn substate_history(&self) -> &HashMap<SubstateId, Vec<(u64, Option<OutputValue>)>> {
+        self.substate_history
+            .as_ref()
+            .expect("Substate history is not retained (see `with_history()`)")
+    }
 }
 
 impl Default for TypedInMemorySubstateStore {
@@ -63,12 +112,131 @@ impl ReadableSubstateStore for TypedInMemorySubstateStore {
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
         self.substates.get(substate_id).cloned()
     }
+
+    fn list_kv_store_keys(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<Vec<u8>> {
+        select_kv_store_keys(self.get_kv_store_entries(kv_store_id), from, limit)
+    }
 }
 
 impl WriteableSubstateStore for TypedInMemorySubstateStore {
     fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+        if self.substate_history.is_some() {
+            self.record_history(substate_id.clone(), Some(substate.clone()));
+        }
         self.substates.insert(substate_id, substate);
     }
+
+    fn remove_substate(&mut self, substate_id: &SubstateId) {
+        if self.substates.remove(substate_id).is_some() {
+            self.record_history(substate_id.clone(), None);
+        }
+    }
+
+    fn commit(&mut self, state_diff: &StateDiff) -> CommitReceipt {
+        self.state_version += 1;
+        state_diff.put_substates(self)
+    }
+}
+
+impl VersionedSubstateStore for TypedInMemorySubstateStore {
+    fn get_state_version(&self) -> u64 {
+        self.state_version
+    }
+
+    fn get_substate_at(&self, substate_id: &SubstateId, state_version: u64) -> Option<OutputValue> {
+        self.substate_history()
+            .get(substate_id)
+            .and_then(|history| value_at(history, state_version))
+            .cloned()
+    }
+
+    fn list_substates_at(
+        &self,
+        node_id: &RENodeId,
+        module_id: Option<NodeModuleId>,
+        from: Option<&(NodeModuleId, SubstateOffset)>,
+        limit: usize,
+        state_version: u64,
+    ) -> Vec<(SubstateId, OutputValue)> {
+        let substates = self
+            .substate_history()
+            .iter()
+            .filter(|(substate_id, _)| substate_id.0 == *node_id)
+            .filter_map(|(substate_id, history)| {
+                value_at(history, state_version).map(|value| (substate_id.clone(), value.clone()))
+            });
+        select_substates(substates, node_id, module_id, from, limit)
+    }
+
+    fn get_kv_store_entries_at(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        state_version: u64,
+    ) -> HashMap<Vec<u8>, PersistedSubstate> {
+        self.substate_history()
+            .iter()
+            .filter_map(|(substate_id, history)| {
+                if let SubstateId(
+                    RENodeId::KeyValueStore(id),
+                    NodeModuleId::SELF,
+                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(entry_id)),
+                ) = substate_id
+                {
+                    if id == kv_store_id {
+                        value_at(history, state_version)
+                            .map(|value| (entry_id.clone(), value.substate.clone()))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+impl CommitLogStore for TypedInMemorySubstateStore {
+    fn commit_and_log(
+        &mut self,
+        transaction_hash: Hash,
+        commit_result: &CommitResult,
+    ) -> CommittedTransaction {
+        let commit_receipt = self.commit(&commit_result.state_updates);
+        let committed_transaction = CommittedTransaction::new(
+            self.state_version,
+            transaction_hash,
+            commit_result,
+            commit_receipt,
+        );
+        if let Some(commit_log) = &mut self.commit_log {
+            commit_log.push(committed_transaction.clone());
+        }
+        committed_transaction
+    }
+
+    fn get_commit_log(&self, from_state_version: u64, limit: usize) -> Vec<CommittedTransaction> {
+        let commit_log = match &self.commit_log {
+            Some(commit_log) => commit_log,
+            None => return Vec::new(),
+        };
+        let start = commit_log.partition_point(|entry| entry.state_version < from_state_version);
+        commit_log[start..].iter().take(limit).cloned().collect()
+    }
+}
+
+/// Returns the latest of the values put at or before the given state version, unless it was
+/// removed since.
+fn value_at(history: &[(u64, Option<OutputValue>)], state_version: u64) -> Option<&OutputValue> {
+    let count = history.partition_point(|(version, _)| *version <= state_version);
+    count
+        .checked_sub(1)
+        .and_then(|index| history[index].1.as_ref())
 }
 
 impl QueryableSubstateStore for TypedInMemorySubstateStore {
@@ -96,4 +264,19 @@ impl QueryableSubstateStore for TypedInMemorySubstateStore {
             })
             .collect()
     }
+
+    fn list_substates(
+        &self,
+        node_id: &RENodeId,
+        module_id: Option<NodeModuleId>,
+        from: Option<&(NodeModuleId, SubstateOffset)>,
+        limit: usize,
+    ) -> Vec<(SubstateId, OutputValue)> {
+        let substates = self
+            .substates
+            .iter()
+            .filter(|(substate_id, _)| substate_id.0 == *node_id)
+            .map(|(substate_id, value)| (substate_id.clone(), value.clone()));
+        select_substates(substates, node_id, module_id, from, limit)
+    }
 }
@@ -1,3 +1,4 @@
+use crate::state_manager::{CommitReceipt, StateDiff};
 use crate::{system::node_substates::PersistedSubstate, types::*};
 
 pub trait QueryableSubstateStore {
@@ -5,6 +6,60 @@ pub trait QueryableSubstateStore {
         &self,
         kv_store_id: &KeyValueStoreId,
     ) -> HashMap<Vec<u8>, PersistedSubstate>;
+
+    /// Lists the substates of the given RENode (or only the ones of its given module).
+    /// The substates are listed in the same order by every store (see `substate_listing_key()`),
+    /// starting from the given module and offset (inclusive, if given), up to the given limit.
+    fn list_substates(
+        &self,
+        node_id: &RENodeId,
+        module_id: Option<NodeModuleId>,
+        from: Option<&(NodeModuleId, SubstateOffset)>,
+        limit: usize,
+    ) -> Vec<(SubstateId, OutputValue)>;
+}
+
+/// Returns the key by which substates are ordered within a listing (see
+/// `QueryableSubstateStore::list_substates()`): the module first, then the SBOR encoding of the
+/// offset.
+pub fn substate_listing_key(
+    module_id: NodeModuleId,
+    offset: &SubstateOffset,
+) -> (NodeModuleId, Vec<u8>) {
+    (
+        module_id,
+        scrypto_encode(offset).expect("Could not encode substate offset"),
+    )
+}
+
+/// Implements `QueryableSubstateStore::list_substates()` on top of any (unordered) collection of
+/// substates.
+pub fn select_substates<I: IntoIterator<Item = (SubstateId, OutputValue)>>(
+    substates: I,
+    node_id: &RENodeId,
+    module_id: Option<NodeModuleId>,
+    from: Option<&(NodeModuleId, SubstateOffset)>,
+    limit: usize,
+) -> Vec<(SubstateId, OutputValue)> {
+    let from_key = from.map(|(module_id, offset)| substate_listing_key(*module_id, offset));
+    let mut selected = substates
+        .into_iter()
+        .filter(|(SubstateId(substate_node_id, substate_module_id, _), _)| {
+            substate_node_id == node_id
+                && module_id.map_or(true, |module_id| *substate_module_id == module_id)
+        })
+        .map(|(substate_id, output_value)| {
+            let key = substate_listing_key(substate_id.1, &substate_id.2);
+            (key, (substate_id, output_value))
+        })
+        .filter(|(key, _)| from_key.as_ref().map_or(true, |from_key| key >= from_key))
+        .collect::<Vec<_>>();
+    selected.sort_by(|(left, _), (right, _)| left.cmp(right));
+    selected
+        .into_iter()
+        .take(limit)
+        .map(|(_, substate)| substate)
+        .collect()
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, ScryptoSbor)]
@@ -22,12 +77,217 @@ pub struct OutputValue {
 
 pub trait ReadableSubstateStore {
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue>;
+
+    /// Lists the keys of the (non-empty) entries of the given key-value store, in ascending order
+    /// of their encoding, starting from the given key (inclusive, if given), up to the given limit.
+    fn list_kv_store_keys(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<Vec<u8>>;
+}
+
+/// Implements `ReadableSubstateStore::list_kv_store_keys()` on top of any (unordered) collection of
+/// key-value store entries.
+pub fn select_kv_store_keys<I: IntoIterator<Item = (Vec<u8>, PersistedSubstate)>>(
+    entries: I,
+    from: Option<&[u8]>,
+    limit: usize,
+) -> Vec<Vec<u8>> {
+    let mut selected = entries
+        .into_iter()
+        .filter(|(key, substate)| {
+            matches!(substate, PersistedSubstate::KeyValueStoreEntry(Some(..)))
+                && from.map_or(true, |from| key.as_slice() >= from)
+        })
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+    selected.sort();
+    selected.truncate(limit);
+    selected
+}
+
+/// Implements `ReadableSubstateStore::list_kv_store_keys()` for a view which overlays some
+/// entries (mapped to whether they are present in the view) on top of a base listing.
+pub fn merge_kv_store_keys<F: Fn(Option<&[u8]>, usize) -> Vec<Vec<u8>>>(
+    list_base_keys: F,
+    overlay: BTreeMap<Vec<u8>, bool>,
+    from: Option<&[u8]>,
+    limit: usize,
+) -> Vec<Vec<u8>> {
+    let mut keys: BTreeSet<Vec<u8>> = overlay
+        .iter()
+        .filter(|(key, present)| **present && from.map_or(true, |from| key.as_slice() >= from))
+        .map(|(key, _)| key.clone())
+        .collect();
+
+    // Base keys are fetched until there are enough of them left after the overlay's removals,
+    // since every base key listed later sorts after all of these.
+    let mut cursor = from.map(|from| from.to_vec());
+    let mut base_count = 0usize;
+    while base_count < limit {
+        let base_keys = list_base_keys(cursor.as_deref(), limit);
+        let exhausted = base_keys.len() < limit;
+        for key in base_keys {
+            if overlay.get(&key) != Some(&false) {
+                base_count += 1;
+                keys.insert(key.clone());
+            }
+            // The smallest key after this one
+            let mut next = key;
+            next.push(0);
+            cursor = Some(next);
+        }
+        if exhausted {
+            break;
+        }
+    }
+
+    keys.into_iter().take(limit).collect()
 }
 
 pub trait WriteableSubstateStore {
     fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue);
+
+    /// Deletes a substate, which went down without coming back up (e.g. a removed key-value
+    /// store entry).
+    fn remove_substate(&mut self, substate_id: &SubstateId);
+
+    /// Applies all the state changes of a single transaction.
+    /// By default, this simply puts the up-substates (and removes the deleted ones) one by one - a store which is able to write
+    /// them atomically (or which maintains any data derived from them) should override it.
+    fn commit(&mut self, state_diff: &StateDiff) -> CommitReceipt
+    where
+        Self: Sized,
+    {
+        state_diff.put_substates(self)
+    }
 }
 
 pub trait SubstateStore: ReadableSubstateStore + WriteableSubstateStore {}
 
 impl<T: ReadableSubstateStore + WriteableSubstateStore> SubstateStore for T {}
+
+/// A substate store which retains the past values of the substates.
+/// Its state version is the number of commits (see `WriteableSubstateStore::commit()`) applied
+/// so far; substates put outside of a commit are not guaranteed to be retained.
+pub trait VersionedSubstateStore {
+    /// Returns the current state version.
+    fn get_state_version(&self) -> u64;
+
+    /// Returns the value the substate had at the given state version (i.e. right after that
+    /// many commits), or `None` if it did not exist back then.
+    fn get_substate_at(&self, substate_id: &SubstateId, state_version: u64) -> Option<OutputValue>;
+
+    /// Returns the entries the key-value store had at the given state version.
+    fn get_kv_store_entries_at(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        state_version: u64,
+    ) -> HashMap<Vec<u8>, PersistedSubstate>;
+
+    /// Lists the substates the RENode had at the given state version (see
+    /// `QueryableSubstateStore::list_substates()`).
+    fn list_substates_at(
+        &self,
+        node_id: &RENodeId,
+        module_id: Option<NodeModuleId>,
+        from: Option<&(NodeModuleId, SubstateOffset)>,
+        limit: usize,
+        state_version: u64,
+    ) -> Vec<(SubstateId, OutputValue)>;
+
+    /// Returns a read-only view of the state at the given version, or an error if the version was
+    /// not reached yet.
+    fn at_version(
+        &self,
+        state_version: u64,
+    ) -> Result<HistoricalSubstateStore<Self>, HistoricalSubstateStoreError>
+    where
+        Self: Sized,
+    {
+        HistoricalSubstateStore::new(self, state_version)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistoricalSubstateStoreError {
+    StateVersionNotReached {
+        state_version: u64,
+        current_version: u64,
+    },
+}
+
+/// A read-only view of a `VersionedSubstateStore` at a past state version.
+/// It can be used wherever a current-state reader is expected (e.g. by `StateTreeTraverser` or
+/// `ResourceAccounter`).
+pub struct HistoricalSubstateStore<'s, S: VersionedSubstateStore> {
+    substate_store: &'s S,
+    state_version: u64,
+}
+
+impl<'s, S: VersionedSubstateStore> HistoricalSubstateStore<'s, S> {
+    /// Creates a view of the given store at the given state version, or returns an error if the
+    /// version was not reached by the store yet.
+    pub fn new(
+        substate_store: &'s S,
+        state_version: u64,
+    ) -> Result<Self, HistoricalSubstateStoreError> {
+        let current_version = substate_store.get_state_version();
+        if state_version > current_version {
+            return Err(HistoricalSubstateStoreError::StateVersionNotReached {
+                state_version,
+                current_version,
+            });
+        }
+        Ok(Self {
+            substate_store,
+            state_version,
+        })
+    }
+
+    pub fn state_version(&self) -> u64 {
+        self.state_version
+    }
+}
+
+impl<'s, S: VersionedSubstateStore> ReadableSubstateStore for HistoricalSubstateStore<'s, S> {
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        self.substate_store
+            .get_substate_at(substate_id, self.state_version)
+    }
+
+    fn list_kv_store_keys(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<Vec<u8>> {
+        let entries = self
+            .substate_store
+            .get_kv_store_entries_at(kv_store_id, self.state_version);
+        select_kv_store_keys(entries, from, limit)
+    }
+}
+
+impl<'s, S: VersionedSubstateStore> QueryableSubstateStore for HistoricalSubstateStore<'s, S> {
+    fn get_kv_store_entries(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+    ) -> HashMap<Vec<u8>, PersistedSubstate> {
+        self.substate_store
+            .get_kv_store_entries_at(kv_store_id, self.state_version)
+    }
+
+    fn list_substates(
+        &self,
+        node_id: &RENodeId,
+        module_id: Option<NodeModuleId>,
+        from: Option<&(NodeModuleId, SubstateOffset)>,
+        limit: usize,
+    ) -> Vec<(SubstateId, OutputValue)> {
+        self.substate_store
+            .list_substates_at(node_id, module_id, from, limit, self.state_version)
+    }
+}
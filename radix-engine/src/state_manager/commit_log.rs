@@ -0,0 +1,123 @@
+use crate::ledger::*;
+use crate::state_manager::{CommitReceipt, StateDiff};
+use crate::transaction::{CommitResult, StateUpdateSummary};
+use crate::types::*;
+
+/// An entry of the commit log: everything about a single transaction's commit.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct CommittedTransaction {
+    /// The state version reached by committing the transaction (i.e. the entry's position in
+    /// the log).
+    pub state_version: u64,
+    pub transaction_hash: Hash,
+    pub state_updates: StateDiff,
+    pub state_update_summary: StateUpdateSummary,
+    pub application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
+    /// The substates brought down by the commit.
+    pub inputs: Vec<OutputId>,
+    /// The substates brought up by the commit.
+    pub outputs: Vec<OutputId>,
+}
+
+impl CommittedTransaction {
+    pub fn new(
+        state_version: u64,
+        transaction_hash: Hash,
+        commit_result: &CommitResult,
+        commit_receipt: CommitReceipt,
+    ) -> Self {
+        Self {
+            state_version,
+            transaction_hash,
+            state_updates: commit_result.state_updates.clone(),
+            state_update_summary: commit_result.state_update_summary.clone(),
+            application_events: commit_result.application_events.clone(),
+            inputs: commit_receipt.inputs,
+            outputs: commit_receipt.outputs,
+        }
+    }
+}
+
+/// A substate store which keeps an ordered log of the transactions committed to it, next to the
+/// substates themselves.
+/// Note: only the transactions committed via `commit_and_log()` are logged (i.e. the state
+/// versions of the log entries may have gaps, e.g. for the genesis transactions).
+pub trait CommitLogStore {
+    /// Commits the transaction's state updates and appends it to the log (atomically, if the
+    /// store supports it).
+    fn commit_and_log(
+        &mut self,
+        transaction_hash: Hash,
+        commit_result: &CommitResult,
+    ) -> CommittedTransaction;
+
+    /// Returns at most `limit` log entries, starting from the given state version (inclusive).
+    fn get_commit_log(&self, from_state_version: u64, limit: usize) -> Vec<CommittedTransaction>;
+}
+
+/// A subscriber notified about each transaction committed via a `CommitFeed`.
+pub trait CommitSubscriber {
+    fn on_commit(&mut self, committed_transaction: &CommittedTransaction);
+}
+
+/// The commit path which notifies the subscribers about every committed transaction, in order.
+pub struct CommitFeed {
+    subscribers: Vec<Box<dyn CommitSubscriber>>,
+}
+
+impl CommitFeed {
+    /// The number of log entries read at once when replaying.
+    const REPLAY_PAGE_SIZE: usize = 100;
+
+    pub fn new() -> Self {
+        Self {
+            subscribers: Vec::new(),
+        }
+    }
+
+    pub fn subscribe(&mut self, subscriber: Box<dyn CommitSubscriber>) {
+        self.subscribers.push(subscriber);
+    }
+
+    /// Commits the transaction to the given store and notifies all the subscribers about it.
+    pub fn commit<S: CommitLogStore>(
+        &mut self,
+        store: &mut S,
+        transaction_hash: Hash,
+        commit_result: &CommitResult,
+    ) -> CommittedTransaction {
+        let committed_transaction = store.commit_and_log(transaction_hash, commit_result);
+        for subscriber in &mut self.subscribers {
+            subscriber.on_commit(&committed_transaction);
+        }
+        committed_transaction
+    }
+
+    /// Feeds the logged transactions committed at or after the given state version to the
+    /// subscriber (e.g. to let it catch up after a restart). Returns the state version following
+    /// the last replayed transaction (or the given one, if there was nothing to replay).
+    pub fn replay<S: CommitLogStore>(
+        store: &S,
+        from_state_version: u64,
+        subscriber: &mut dyn CommitSubscriber,
+    ) -> u64 {
+        let mut next_state_version = from_state_version;
+        loop {
+            let entries = store.get_commit_log(next_state_version, Self::REPLAY_PAGE_SIZE);
+            let last_page = entries.len() < Self::REPLAY_PAGE_SIZE;
+            for committed_transaction in &entries {
+                subscriber.on_commit(committed_transaction);
+                next_state_version = committed_transaction.state_version + 1;
+            }
+            if last_page {
+                return next_state_version;
+            }
+        }
+    }
+}
+
+impl Default for CommitFeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,11 +1,13 @@
 use crate::ledger::*;
+use crate::system::node_substates::PersistedSubstate;
 use crate::types::*;
 
 /// Nodes form an acyclic graph towards the parent
 struct StagedSubstateStoreNode {
     parent_id: u64,
     locked: bool,
-    outputs: BTreeMap<SubstateId, OutputValue>,
+    /// The substates put (or removed, if `None`) by this node
+    outputs: BTreeMap<SubstateId, Option<OutputValue>>,
 }
 
 impl StagedSubstateStoreNode {
@@ -103,7 +105,10 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> StagedSubstateStoreM
         self.merge_to_parent_recurse(node.parent_id, true);
 
         for (substate_id, output) in node.outputs {
-            self.root.put_substate(substate_id, output);
+            match output {
+                Some(output) => self.root.put_substate(substate_id, output),
+                None => self.root.remove_substate(&substate_id),
+            }
         }
 
         if !remove_children {
@@ -125,7 +130,7 @@ impl<'t, 's, S: ReadableSubstateStore> StagedSubstateStore<'t, 's, S> {
 
         let node = self.stores.nodes.get(&id).unwrap();
         if let Some(output) = node.outputs.get(substate_id) {
-            return Some(output.clone());
+            return output.clone();
         }
 
         self.get_substate_recurse(substate_id, node.parent_id)
@@ -136,6 +141,51 @@ impl<'t, 's, S: ReadableSubstateStore> ReadableSubstateStore for StagedSubstateS
     fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
         self.get_substate_recurse(substate_id, self.id)
     }
+
+    fn list_kv_store_keys(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<Vec<u8>> {
+        // The entries staged by the nodes on the path to the root, the nearest ones first
+        let mut overlay = BTreeMap::new();
+        let mut id = self.id;
+        while id != 0 {
+            let node = self.stores.nodes.get(&id).unwrap();
+            for (substate_id, output) in &node.outputs {
+                if let SubstateId(
+                    RENodeId::KeyValueStore(node_kv_store_id),
+                    NodeModuleId::SELF,
+                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+                ) = substate_id
+                {
+                    if node_kv_store_id == kv_store_id {
+                        let present = matches!(
+                            output,
+                            Some(OutputValue {
+                                substate: PersistedSubstate::KeyValueStoreEntry(Some(..)),
+                                ..
+                            })
+                        );
+                        overlay.entry(key.clone()).or_insert(present);
+                    }
+                }
+            }
+            id = node.parent_id;
+        }
+
+        merge_kv_store_keys(
+            |from, limit| {
+                self.stores
+                    .root
+                    .list_kv_store_keys(kv_store_id, from, limit)
+            },
+            overlay,
+            from,
+            limit,
+        )
+    }
 }
 
 impl<'t, 's, S: ReadableSubstateStore> WriteableSubstateStore for StagedSubstateStore<'t, 's, S> {
@@ -144,7 +194,16 @@ impl<'t, 's, S: ReadableSubstateStore> WriteableSubstateStore for StagedSubstate
             panic!("Cannot write to root store");
         } else {
             let node = self.stores.nodes.get_mut(&self.id).unwrap();
-            node.outputs.insert(substate_id, output);
+            node.outputs.insert(substate_id, Some(output));
+        }
+    }
+
+    fn remove_substate(&mut self, substate_id: &SubstateId) {
+        if self.id == 0 {
+            panic!("Cannot write to root store");
+        } else {
+            let node = self.stores.nodes.get_mut(&self.id).unwrap();
+            node.outputs.insert(substate_id.clone(), None);
         }
     }
 }
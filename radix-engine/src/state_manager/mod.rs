@@ -1,7 +1,9 @@
+pub mod commit_log;
 pub mod commit_receipt;
 pub mod deprecated_staging;
 pub mod state_diff;
 
+pub use commit_log::*;
 pub use commit_receipt::*;
 pub use state_diff::*;
 
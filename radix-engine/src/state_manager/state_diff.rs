@@ -3,7 +3,7 @@ use crate::state_manager::CommitReceipt;
 use crate::types::*;
 use radix_engine_interface::crypto::hash;
 
-#[derive(Debug, Clone, ScryptoSbor)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct StateDiff {
     pub up_substates: BTreeMap<SubstateId, OutputValue>,
     pub down_substates: BTreeSet<OutputId>,
@@ -28,6 +28,25 @@ impl StateDiff {
 
     /// Applies the state changes to some substate store.
     pub fn commit<S: WriteableSubstateStore>(&self, store: &mut S) -> CommitReceipt {
+        store.commit(self)
+    }
+
+    /// Puts the up-substates into some substate store one by one, and removes the substates
+    /// which went down without coming back up (i.e. without any atomicity guarantees). This is
+    /// the default implementation of `WriteableSubstateStore::commit()`.
+    pub fn put_substates<S: WriteableSubstateStore>(&self, store: &mut S) -> CommitReceipt {
+        let receipt = self.to_commit_receipt();
+        for (substate_id, output_value) in &self.up_substates {
+            store.put_substate(substate_id.clone(), output_value.clone());
+        }
+        for substate_id in self.removed_substate_ids() {
+            store.remove_substate(substate_id);
+        }
+        receipt
+    }
+
+    /// Lists the down and up `OutputId`s of the state changes (i.e. without applying them).
+    pub fn to_commit_receipt(&self) -> CommitReceipt {
         let mut receipt = CommitReceipt::new();
 
         for output_id in &self.down_substates {
@@ -47,7 +66,6 @@ impl StateDiff {
                 version: output_value.version,
             };
             receipt.up(output_id);
-            store.put_substate(substate_id.clone(), output_value.clone());
         }
 
         receipt
@@ -64,6 +82,16 @@ impl StateDiff {
             .collect()
     }
 
+    /// Returns the IDs of the substates which went down without coming back up (e.g. removed
+    /// key-value store entries).
+    pub fn removed_substate_ids(&self) -> BTreeSet<&SubstateId> {
+        self.down_substates
+            .iter()
+            .map(|output| &output.substate_id)
+            .filter(|substate_id| !self.up_substates.contains_key(substate_id))
+            .collect()
+    }
+
     pub fn up_substate_offsets(&self) -> BTreeMap<&SubstateOffset, usize> {
         let mut counter = BTreeMap::new();
         for s in &self.up_substates {
@@ -5,8 +5,9 @@ use crate::errors::{
 use crate::kernel::actor::{Actor, ActorIdentifier};
 use crate::kernel::kernel::Kernel;
 use crate::kernel::kernel_api::*;
-use crate::system::kernel_modules::costing::FIXED_LOW_FEE;
+use crate::system::kernel_modules::costing::CostingEntry;
 use crate::system::kernel_modules::events::EventError;
+use crate::system::kernel_modules::logger::LogRecord;
 use crate::system::node::RENodeInit;
 use crate::system::node::RENodeModuleInit;
 use crate::system::node_modules::access_rules::MethodAccessRulesSubstate;
@@ -16,14 +17,15 @@ use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::component::{
     ComponentRoyaltyAccumulatorSubstate, ComponentRoyaltyConfigSubstate, ComponentStateSubstate,
+    ComponentStateVersionSubstate,
 };
 use radix_engine_interface::api::node_modules::auth::*;
 use radix_engine_interface::api::node_modules::metadata::*;
 use radix_engine_interface::api::node_modules::royalty::*;
 use radix_engine_interface::api::substate_api::LockFlags;
-use radix_engine_interface::api::types::ClientCostingReason;
 use radix_engine_interface::api::types::Level;
 use radix_engine_interface::api::types::*;
+use radix_engine_interface::api::types::{ClientCostUnits, ClientCostingReason};
 use radix_engine_interface::api::*;
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
@@ -34,9 +36,19 @@ use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::schema::KeyValueStoreSchema;
 use sbor::rust::string::ToString;
 use sbor::rust::vec::Vec;
+use transaction::bls12381::Bls12381G2Signature;
+use transaction::ecdsa_secp256k1::EcdsaSecp256k1Signature;
+use transaction::eddsa_ed25519::EddsaEd25519Signature;
+use transaction::validation::{
+    aggregate_verify_bls12381, verify_bls12381, verify_ecdsa_secp256k1, verify_eddsa_ed25519,
+};
 
 use super::kernel_modules::costing::CostingReason;
 
+/// The number of entries listed (and charged for) at a time when counting the entries of a
+/// key-value store.
+const KV_STORE_COUNT_PAGE_SIZE: u32 = 100;
+
 impl<'g, 's, W> ClientSubstateApi<RuntimeError> for Kernel<'g, 's, W>
 where
     W: WasmEngine,
@@ -121,9 +133,26 @@ where
                 *state = next
             }
             RuntimeSubstate::KeyValueStoreEntry(next) => {
+                // Removing an entry is credited part of the cost of the writes made to it
+                let substate_id = SubstateId(node_id, module_id, offset);
+                let is_removal = next.is_none();
+                let write_cost_units = if is_removal {
+                    self.kernel_get_module_state()
+                        .costing
+                        .take_kv_entry_write_cost(&substate_id)
+                } else {
+                    0
+                };
+
                 let entry: &mut Option<ScryptoValue> =
                     self.kernel_get_substate_ref_mut(lock_handle)?;
                 *entry = next;
+
+                if is_removal {
+                    self.kernel_get_module_state()
+                        .costing
+                        .on_kv_entry_removed(&substate_id, write_cost_units)?;
+                }
             }
             _ => return Err(RuntimeError::SystemError(SystemError::InvalidSubstateWrite)),
         }
@@ -137,6 +166,57 @@ where
 
         self.kernel_drop_lock(lock_handle)
     }
+
+    fn sys_remove_key_value_store_entry(
+        &mut self,
+        node_id: RENodeId,
+        key: Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        // An entry is removed by emptying it, which deletes it from the store on commit
+        let handle = self.sys_lock_substate(
+            node_id,
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+            LockFlags::MUTABLE,
+        )?;
+        let entry = self.sys_read_substate(handle)?;
+        let removed: Option<ScryptoValue> = Option::None;
+        self.sys_write_substate(handle, scrypto_encode(&removed).unwrap())?;
+        self.sys_drop_lock(handle)?;
+
+        Ok(entry)
+    }
+
+    fn sys_list_key_value_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        from: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        self.kernel_list_kv_store_keys(node_id, from, limit)
+    }
+
+    fn sys_count_key_value_store_entries(
+        &mut self,
+        node_id: RENodeId,
+    ) -> Result<u32, RuntimeError> {
+        // Counting is charged like listing all the entries, page by page, so that it stops as
+        // soon as the fee reserve runs out (rather than after scanning the whole store)
+        let mut count = 0u32;
+        let mut from = None;
+        loop {
+            let keys = self.kernel_list_kv_store_keys(node_id, from, KV_STORE_COUNT_PAGE_SIZE)?;
+            count += keys.len() as u32;
+            match keys.last() {
+                Some(last_key) if keys.len() == KV_STORE_COUNT_PAGE_SIZE as usize => {
+                    // The smallest key greater than the last listed one
+                    let mut next_key = last_key.clone();
+                    next_key.push(0);
+                    from = Some(next_key);
+                }
+                _ => return Ok(count),
+            }
+        }
+    }
 }
 
 impl<'g, 's, W> ClientActorApi<RuntimeError> for Kernel<'g, 's, W>
@@ -170,6 +250,7 @@ where
             LockFlags::read_only(),
         )?;
         let package: &PackageInfoSubstate = self.kernel_get_substate_ref(handle)?;
+        let state_version = package.upgrade_info.as_ref().map(|info| info.version);
         let schema =
             package
                 .schema
@@ -308,7 +389,8 @@ where
                     RENodeInit::Object(btreemap!(
                         SubstateOffset::EpochManager(EpochManagerOffset::EpochManager) => RuntimeSubstate::EpochManager(parser.decode_next()),
                         SubstateOffset::EpochManager(EpochManagerOffset::CurrentValidatorSet) => RuntimeSubstate::ValidatorSet(parser.decode_next()),
-                        SubstateOffset::EpochManager(EpochManagerOffset::PreparingValidatorSet) => RuntimeSubstate::ValidatorSet(parser.decode_next())
+                        SubstateOffset::EpochManager(EpochManagerOffset::PreparingValidatorSet) => RuntimeSubstate::ValidatorSet(parser.decode_next()),
+                        SubstateOffset::EpochManager(EpochManagerOffset::FeeTable) => RuntimeSubstate::FeeTable(parser.decode_next())
                     )),
                     AllocateEntityType::Object,
                 ),
@@ -336,14 +418,23 @@ where
                 )),
                 AllocateEntityType::Object,
             ),
-            _ => (
-                RENodeInit::Object(btreemap!(
+            _ => {
+                let mut substates = btreemap!(
                     SubstateOffset::Component(ComponentOffset::State0) => RuntimeSubstate::ComponentState(
                         ComponentStateSubstate (parser.decode_next::<ScryptoValue>())
                     )
-                )),
-                AllocateEntityType::Object,
-            ),
+                );
+                // Components of upgradeable packages track which code version their state is for
+                if let Some(version) = state_version {
+                    substates.insert(
+                        SubstateOffset::Component(ComponentOffset::StateVersion),
+                        RuntimeSubstate::ComponentStateVersion(ComponentStateVersionSubstate(
+                            version,
+                        )),
+                    );
+                }
+                (RENodeInit::Object(substates), AllocateEntityType::Object)
+            }
         };
         parser.end();
 
@@ -688,7 +779,7 @@ where
 {
     fn consume_cost_units(
         &mut self,
-        units: u32,
+        units: ClientCostUnits,
         reason: ClientCostingReason,
     ) -> Result<(), RuntimeError> {
         // No costing applied
@@ -699,7 +790,7 @@ where
                 ClientCostingReason::RunNative => CostingReason::RunNative,
                 ClientCostingReason::RunSystem => CostingReason::RunSystem,
             },
-            |_| units,
+            |fee_table| fee_table.client_cost(units),
             5,
         )
     }
@@ -752,7 +843,7 @@ where
 {
     fn emit_event(&mut self, event_name: String, event_data: Vec<u8>) -> Result<(), RuntimeError> {
         // Costing event emission.
-        self.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunSystem)?;
+        self.consume_cost_units(ClientCostUnits::FixedLowFee, ClientCostingReason::RunSystem)?;
 
         let actor = self.kernel_get_current_actor();
 
@@ -883,11 +974,43 @@ where
     W: WasmEngine,
 {
     fn log_message(&mut self, level: Level, message: String) -> Result<(), RuntimeError> {
-        self.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunSystem)?;
+        self.log_structured_message(level, message, Vec::new())
+    }
+
+    fn log_structured_message(
+        &mut self,
+        level: Level,
+        message: String,
+        fields: Vec<(String, String)>,
+    ) -> Result<(), RuntimeError> {
+        // The messages below the minimum level of the transaction are dropped before being
+        // charged for
+        if !self.kernel_get_module_state().logger.is_enabled(level) {
+            return Ok(());
+        }
 
+        let size = fields.iter().fold(message.len(), |size, (key, value)| {
+            size + key.len() + value.len()
+        });
         self.kernel_get_module_state()
-            .logger
-            .add_log(level, message);
+            .costing
+            .apply_execution_cost(
+                CostingReason::RunSystem,
+                |fee_table| {
+                    fee_table.kernel_api_cost(CostingEntry::LogMessage { size: size as u32 })
+                },
+                1,
+            )?;
+
+        let actor = self.kernel_get_current_actor();
+        let depth = self.kernel_get_current_depth();
+        self.kernel_get_module_state().logger.add_log(LogRecord {
+            level,
+            message,
+            fields,
+            actor,
+            depth,
+        });
         Ok(())
     }
 }
@@ -897,7 +1020,7 @@ where
     W: WasmEngine,
 {
     fn get_transaction_hash(&mut self) -> Result<Hash, RuntimeError> {
-        self.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunSystem)?;
+        self.consume_cost_units(ClientCostUnits::FixedLowFee, ClientCostingReason::RunSystem)?;
 
         Ok(self
             .kernel_get_module_state()
@@ -906,7 +1029,7 @@ where
     }
 
     fn generate_uuid(&mut self) -> Result<u128, RuntimeError> {
-        self.consume_cost_units(FIXED_LOW_FEE, ClientCostingReason::RunSystem)?;
+        self.consume_cost_units(ClientCostUnits::FixedLowFee, ClientCostingReason::RunSystem)?;
 
         Ok(self
             .kernel_get_module_state()
@@ -915,4 +1038,143 @@ where
     }
 }
 
+impl<'g, 's, W> ClientCryptoUtilsApi<RuntimeError> for Kernel<'g, 's, W>
+where
+    W: WasmEngine,
+{
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message: Vec<u8>,
+        public_key: EcdsaSecp256k1PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, RuntimeError> {
+        self.kernel_get_module_state()
+            .costing
+            .apply_execution_cost(
+                CostingReason::RunCryptoUtils,
+                |fee_table| {
+                    fee_table.kernel_api_cost(CostingEntry::VerifyEcdsaSecp256k1 {
+                        size: message.len() as u32,
+                    })
+                },
+                1,
+            )?;
+
+        Ok(EcdsaSecp256k1Signature::try_from(signature.as_slice())
+            .map(|signature| verify_ecdsa_secp256k1(&hash(&message), &public_key, &signature))
+            .unwrap_or(false))
+    }
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: EddsaEd25519PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, RuntimeError> {
+        self.kernel_get_module_state()
+            .costing
+            .apply_execution_cost(
+                CostingReason::RunCryptoUtils,
+                |fee_table| {
+                    fee_table.kernel_api_cost(CostingEntry::VerifyEddsaEd25519 {
+                        size: message.len() as u32,
+                    })
+                },
+                1,
+            )?;
+
+        Ok(EddsaEd25519Signature::try_from(signature.as_slice())
+            .map(|signature| verify_eddsa_ed25519(&hash(&message), &public_key, &signature))
+            .unwrap_or(false))
+    }
+
+    fn verify_bls12381(
+        &mut self,
+        message_hash: Hash,
+        public_key: Bls12381G1PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, RuntimeError> {
+        self.kernel_get_module_state()
+            .costing
+            .apply_execution_cost(
+                CostingReason::RunCryptoUtils,
+                |fee_table| fee_table.kernel_api_cost(CostingEntry::VerifyBls12381),
+                1,
+            )?;
+
+        Ok(Bls12381G2Signature::try_from(signature.as_slice())
+            .map(|signature| verify_bls12381(&message_hash, &public_key, &signature))
+            .unwrap_or(false))
+    }
+
+    fn aggregate_verify_bls12381(
+        &mut self,
+        message_hash: Hash,
+        public_keys: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+        signature: Vec<u8>,
+    ) -> Result<bool, RuntimeError> {
+        self.kernel_get_module_state()
+            .costing
+            .apply_execution_cost(
+                CostingReason::RunCryptoUtils,
+                |fee_table| {
+                    fee_table.kernel_api_cost(CostingEntry::AggregateVerifyBls12381 {
+                        count: public_keys.len() as u32,
+                    })
+                },
+                1,
+            )?;
+
+        let public_keys = match public_keys
+            .into_iter()
+            .map(|(public_key, proof)| {
+                Bls12381G2Signature::try_from(proof.as_slice()).map(|proof| (public_key, proof))
+            })
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(public_keys) => public_keys,
+            Err(..) => return Ok(false),
+        };
+
+        Ok(Bls12381G2Signature::try_from(signature.as_slice())
+            .map(|signature| aggregate_verify_bls12381(&message_hash, &public_keys, &signature))
+            .unwrap_or(false))
+    }
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Hash, RuntimeError> {
+        self.consume_hash_cost(&data)?;
+
+        Ok(keccak256_hash(data))
+    }
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Hash, RuntimeError> {
+        self.consume_hash_cost(&data)?;
+
+        Ok(sha256_hash(data))
+    }
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Hash, RuntimeError> {
+        self.consume_hash_cost(&data)?;
+
+        Ok(blake2b_256_hash(data))
+    }
+}
+
+impl<'g, 's, W> Kernel<'g, 's, W>
+where
+    W: WasmEngine,
+{
+    fn consume_hash_cost(&mut self, data: &[u8]) -> Result<(), RuntimeError> {
+        self.kernel_get_module_state().costing.apply_execution_cost(
+            CostingReason::RunCryptoUtils,
+            |fee_table| {
+                fee_table.kernel_api_cost(CostingEntry::Hash {
+                    size: data.len() as u32,
+                })
+            },
+            1,
+        )
+    }
+}
+
 impl<'g, 's, W> ClientApi<RuntimeError> for Kernel<'g, 's, W> where W: WasmEngine {}
@@ -321,6 +321,47 @@ pub fn convert(
     }
 }
 
+fn is_list_state_dependent(list: &SoftResourceOrNonFungibleList) -> bool {
+    match list {
+        SoftResourceOrNonFungibleList::Static(resources) => resources
+            .iter()
+            .any(|resource| matches!(resource, SoftResourceOrNonFungible::Dynamic(..))),
+        SoftResourceOrNonFungibleList::Dynamic(..) => true,
+    }
+}
+
+fn is_auth_rule_state_dependent(auth_rule: &AccessRuleNode) -> bool {
+    match auth_rule {
+        AccessRuleNode::ProofRule(proof_rule) => match proof_rule {
+            ProofRule::Require(resource) => {
+                matches!(resource, SoftResourceOrNonFungible::Dynamic(..))
+            }
+            ProofRule::AmountOf(amount, resource) => {
+                matches!(amount, SoftDecimal::Dynamic(..))
+                    || matches!(resource, SoftResource::Dynamic(..))
+            }
+            ProofRule::CountOf(count, resources) => {
+                matches!(count, SoftCount::Dynamic(..)) || is_list_state_dependent(resources)
+            }
+            ProofRule::AllOf(resources) | ProofRule::AnyOf(resources) => {
+                is_list_state_dependent(resources)
+            }
+        },
+        AccessRuleNode::AnyOf(rules) | AccessRuleNode::AllOf(rules) => {
+            rules.iter().any(is_auth_rule_state_dependent)
+        }
+    }
+}
+
+/// Returns whether the given `AccessRule` refers to the state of the component it protects, i.e.
+/// whether converting it needs the context of `convert()`.
+pub fn is_state_dependent(method_auth: &AccessRule) -> bool {
+    match method_auth {
+        AccessRule::Protected(auth_rule) => is_auth_rule_state_dependent(auth_rule),
+        AccessRule::AllowAll | AccessRule::DenyAll => false,
+    }
+}
+
 pub fn convert_contextless(method_auth: &AccessRule) -> MethodAuthorization {
     convert(
         &BlueprintSchema::default().schema,
@@ -1,4 +1,4 @@
-use super::auth_converter::convert_contextless;
+use super::auth_converter::{convert_contextless, is_state_dependent};
 use super::method_authorization::MethodAuthorization;
 use super::HardAuthRule;
 use super::HardProofRule;
@@ -19,7 +19,9 @@ use crate::system::node_modules::access_rules::{
 };
 use crate::system::node_modules::type_info::{TypeInfoBlueprint, TypeInfoSubstate};
 use crate::types::*;
-use radix_engine_interface::api::component::ComponentStateSubstate;
+use radix_engine_interface::api::component::{
+    ComponentStateSubstate, ComponentStateVersionSubstate,
+};
 use radix_engine_interface::api::node_modules::auth::*;
 use radix_engine_interface::api::substate_api::LockFlags;
 use radix_engine_interface::api::types::{
@@ -35,6 +37,9 @@ use transaction::model::AuthZoneParams;
 pub enum AuthError {
     VisibilityError(RENodeId),
     Unauthorized(Box<Unauthorized>),
+    /// The access rule refers to the state of a component which has yet to be migrated to the
+    /// current version of its package (by calling one of its methods not guarded by such a rule)
+    UnmigratedComponentState(RENodeId),
 }
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct Unauthorized(pub Option<ActorIdentifier>, pub MethodAuthorization);
@@ -243,7 +248,7 @@ impl AuthModule {
         key: MethodKey,
         api: &mut Y,
     ) -> Result<MethodAuthorization, RuntimeError> {
-        let (blueprint_schema, index) = {
+        let (blueprint_schema, index, package_version) = {
             let type_info = TypeInfoBlueprint::get_type(receiver, api)?;
             let (package_address, blueprint_ident) = match type_info {
                 TypeInfoSubstate::Object {
@@ -276,8 +281,10 @@ impl AuthModule {
                 }
             };
 
+            let package_version = package.upgrade_info.as_ref().map(|info| info.version);
+
             api.kernel_drop_lock(handle)?;
-            (schema, index)
+            (schema, index, package_version)
         };
 
         let state = {
@@ -294,6 +301,51 @@ impl AuthModule {
             state
         };
 
+        // The state of a component whose package has been upgraded is only migrated when one of
+        // its methods runs, until then it may not match the current schema, which rules referring
+        // to the state are resolved against.
+        if let Some(package_version) = package_version {
+            let handle = api.kernel_lock_substate(
+                receiver,
+                NodeModuleId::SELF,
+                SubstateOffset::Component(ComponentOffset::StateVersion),
+                LockFlags::read_only(),
+            )?;
+            let state_version: &ComponentStateVersionSubstate =
+                api.kernel_get_substate_ref(handle)?;
+            let is_stale = state_version.0 < package_version;
+            api.kernel_drop_lock(handle)?;
+
+            if is_stale
+                && validate_payload_against_schema(
+                    state.as_slice(),
+                    &blueprint_schema.schema,
+                    index,
+                )
+                .is_err()
+            {
+                let handle = api.kernel_lock_substate(
+                    receiver,
+                    module_id,
+                    SubstateOffset::AccessRules(AccessRulesOffset::AccessRules),
+                    LockFlags::read_only(),
+                )?;
+                let access_rules: &MethodAccessRulesSubstate =
+                    api.kernel_get_substate_ref(handle)?;
+                let method_auth = access_rules.access_rules.get(&key);
+                let depends_on_state = is_state_dependent(method_auth);
+                let authorization = convert_contextless(method_auth);
+                api.kernel_drop_lock(handle)?;
+
+                if depends_on_state {
+                    return Err(RuntimeError::ModuleError(ModuleError::AuthError(
+                        AuthError::UnmigratedComponentState(receiver),
+                    )));
+                }
+                return Ok(authorization);
+            }
+        }
+
         let handle = api.kernel_lock_substate(
             receiver,
             module_id,
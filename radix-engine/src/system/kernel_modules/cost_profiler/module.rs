@@ -0,0 +1,268 @@
+use crate::errors::*;
+use crate::kernel::actor::Actor;
+use crate::kernel::call_frame::CallFrameUpdate;
+use crate::kernel::kernel_api::KernelModuleApi;
+use crate::kernel::module::KernelModule;
+use crate::system::kernel_modules::costing::CostingReason;
+use crate::types::*;
+use radix_engine_interface::address::AddressDisplayContext;
+use sbor::rust::collections::*;
+use utils::ContextualDisplay;
+
+//===================================================================================
+// Note: CostProfiler must not produce any error or transactional side effect!
+//===================================================================================
+
+/// A function being executed in a call frame.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, ScryptoSbor)]
+pub struct ProfiledFrame {
+    pub package_address: PackageAddress,
+    pub blueprint_name: String,
+    pub ident: String,
+    pub depth: usize,
+}
+
+/// The costs incurred by a call stack, excluding the ones of the calls it made.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct CallStackCost {
+    /// The number of times the call stack has been entered
+    pub calls: u32,
+    /// The execution cost units consumed, by reason
+    pub execution_cost_breakdown: BTreeMap<CostingReason, u32>,
+    pub substate_locks: u32,
+    pub substate_reads: u32,
+    pub substate_read_bytes: u64,
+    pub substate_writes: u32,
+    pub substate_write_bytes: u64,
+}
+
+impl CallStackCost {
+    pub fn execution_cost_sum(&self) -> u32 {
+        self.execution_cost_breakdown.values().sum()
+    }
+
+    /// The cost units consumed by WASM instruction metering.
+    pub fn wasm_cost_units(&self) -> u32 {
+        self.execution_cost_breakdown
+            .get(&CostingReason::RunWasm)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// The costs of a transaction execution, attributed to the call stacks which incurred them.
+///
+/// The transaction-level costs (base, payload and signature verification) are not part of the
+/// profile, as no call frame is responsible for them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct CostProfile {
+    /// The costs of each call stack (outermost frame first), in the order they were entered.
+    /// The costs incurred outside of any call frame are keyed by the empty stack.
+    pub call_stacks: IndexMap<Vec<ProfiledFrame>, CallStackCost>,
+}
+
+impl CostProfile {
+    pub fn execution_cost_sum(&self) -> u32 {
+        self.call_stacks
+            .values()
+            .map(|cost| cost.execution_cost_sum())
+            .sum()
+    }
+
+    /// Returns the execution cost units consumed by the given function, including the calls it
+    /// made.
+    pub fn inclusive_execution_cost(
+        &self,
+        package_address: PackageAddress,
+        blueprint_name: &str,
+        ident: &str,
+    ) -> u32 {
+        self.call_stacks
+            .iter()
+            .filter(|(stack, _)| {
+                stack.iter().any(|frame| {
+                    frame.package_address == package_address
+                        && frame.blueprint_name == blueprint_name
+                        && frame.ident == ident
+                })
+            })
+            .map(|(_, cost)| cost.execution_cost_sum())
+            .sum()
+    }
+
+    /// Formats the execution costs in the collapsed stack format (one `frame;frame;... count`
+    /// line per call stack), as consumed by `flamegraph.pl`, `inferno` and the like.
+    pub fn to_collapsed_stacks<'a, T: Into<AddressDisplayContext<'a>>>(
+        &self,
+        context: T,
+    ) -> String {
+        let context = context.into();
+        let mut output = String::new();
+        for (stack, cost) in &self.call_stacks {
+            let cost_units = cost.execution_cost_sum();
+            if cost_units == 0 {
+                continue;
+            }
+            let frames: Vec<String> = if stack.is_empty() {
+                vec!["<root>".to_owned()]
+            } else {
+                stack
+                    .iter()
+                    .map(|frame| {
+                        format!(
+                            "{}::{}::{}",
+                            frame.package_address.display(context),
+                            frame.blueprint_name,
+                            frame.ident
+                        )
+                    })
+                    .collect()
+            };
+            output.push_str(&frames.join(";"));
+            output.push_str(&format!(" {}\n", cost_units));
+        }
+        output
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CostProfilerModule {
+    /// The frames of the current call stack, outermost first
+    stack: Vec<ProfiledFrame>,
+    /// The execution cost committed when costs were last attributed
+    attributed_execution_cost: BTreeMap<CostingReason, u32>,
+    profile: CostProfile,
+}
+
+impl CostProfilerModule {
+    pub fn collect_profile(self) -> CostProfile {
+        self.profile
+    }
+
+    fn current_cost(&mut self) -> &mut CallStackCost {
+        self.profile
+            .call_stacks
+            .entry(self.stack.clone())
+            .or_default()
+    }
+
+    /// Attributes the execution cost committed since the last call to the current call stack.
+    fn attribute_execution_cost(&mut self, execution_cost: BTreeMap<CostingReason, u32>) {
+        let mut consumed = BTreeMap::new();
+        for (reason, cost_units) in &execution_cost {
+            match reason {
+                // Charged upfront, and only committed once the system loan is repaid
+                CostingReason::TxBaseCost
+                | CostingReason::TxPayloadCost
+                | CostingReason::TxSignatureVerification => continue,
+                _ => {}
+            }
+            let attributed = self
+                .attributed_execution_cost
+                .get(reason)
+                .cloned()
+                .unwrap_or_default();
+            if *cost_units > attributed {
+                consumed.insert(*reason, cost_units - attributed);
+            }
+        }
+        self.attributed_execution_cost = execution_cost;
+
+        if !consumed.is_empty() {
+            let current_cost = self.current_cost();
+            for (reason, cost_units) in consumed {
+                *current_cost
+                    .execution_cost_breakdown
+                    .entry(reason)
+                    .or_default() += cost_units;
+            }
+        }
+    }
+}
+
+fn attribute_execution_cost<Y: KernelModuleApi<RuntimeError>>(api: &mut Y) {
+    let modules = api.kernel_get_module_state();
+    let execution_cost = modules.costing.fee_reserve.execution_cost();
+    modules
+        .cost_profiler
+        .attribute_execution_cost(execution_cost);
+}
+
+impl KernelModule for CostProfilerModule {
+    fn on_teardown<Y: KernelModuleApi<RuntimeError>>(api: &mut Y) -> Result<(), RuntimeError> {
+        attribute_execution_cost(api);
+        Ok(())
+    }
+
+    fn before_push_frame<Y: KernelModuleApi<RuntimeError>>(
+        api: &mut Y,
+        callee: &Option<Actor>,
+        _update: &mut CallFrameUpdate,
+        _args: &IndexedScryptoValue,
+    ) -> Result<(), RuntimeError> {
+        attribute_execution_cost(api);
+
+        if let Some(Actor { fn_identifier, .. }) = callee {
+            let depth = api.kernel_get_current_depth() + 1;
+            let cost_profiler = &mut api.kernel_get_module_state().cost_profiler;
+            cost_profiler.stack.push(ProfiledFrame {
+                package_address: fn_identifier.package_address,
+                blueprint_name: fn_identifier.blueprint_name.clone(),
+                ident: fn_identifier.ident.clone(),
+                depth,
+            });
+            cost_profiler.current_cost().calls += 1;
+        }
+        Ok(())
+    }
+
+    fn after_pop_frame<Y: KernelModuleApi<RuntimeError>>(api: &mut Y) -> Result<(), RuntimeError> {
+        attribute_execution_cost(api);
+
+        let depth = api.kernel_get_current_depth();
+        let cost_profiler = &mut api.kernel_get_module_state().cost_profiler;
+        while cost_profiler
+            .stack
+            .last()
+            .map(|frame| frame.depth > depth)
+            .unwrap_or(false)
+        {
+            cost_profiler.stack.pop();
+        }
+        Ok(())
+    }
+
+    fn after_lock_substate<Y: KernelModuleApi<RuntimeError>>(
+        api: &mut Y,
+        _lock_handle: LockHandle,
+        _size: usize,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_module_state()
+            .cost_profiler
+            .current_cost()
+            .substate_locks += 1;
+        Ok(())
+    }
+
+    fn on_read_substate<Y: KernelModuleApi<RuntimeError>>(
+        api: &mut Y,
+        _lock_handle: LockHandle,
+        size: usize,
+    ) -> Result<(), RuntimeError> {
+        let current_cost = api.kernel_get_module_state().cost_profiler.current_cost();
+        current_cost.substate_reads += 1;
+        current_cost.substate_read_bytes += size as u64;
+        Ok(())
+    }
+
+    fn on_write_substate<Y: KernelModuleApi<RuntimeError>>(
+        api: &mut Y,
+        _lock_handle: LockHandle,
+        size: usize,
+    ) -> Result<(), RuntimeError> {
+        let current_cost = api.kernel_get_module_state().cost_profiler.current_cost();
+        current_cost.substate_writes += 1;
+        current_cost.substate_write_bytes += size as u64;
+        Ok(())
+    }
+}
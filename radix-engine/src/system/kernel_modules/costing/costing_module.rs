@@ -2,7 +2,7 @@ use super::*;
 use super::{CostingReason, FeeReserveError, FeeTable, SystemLoanFeeReserve};
 use crate::kernel::actor::{Actor, ActorIdentifier};
 use crate::kernel::call_frame::CallFrameUpdate;
-use crate::kernel::kernel_api::KernelModuleApi;
+use crate::kernel::kernel_api::{KernelModuleApi, LockInfo};
 use crate::kernel::module::KernelModule;
 use crate::system::node::RENodeModuleInit;
 use crate::types::*;
@@ -37,14 +37,45 @@ impl CanBeAbortion for CostingError {
     }
 }
 
+/// Charges the execution of a transaction against its fee reserve.
+///
+/// Transactions which clean up after themselves are credited back part of the cost of what they
+/// cleaned up (see `FeeTable::cleanup_credit()`):
+/// - dropping a node credits the cost of creating it, if it was created by the same transaction;
+/// - removing a key value store entry credits the cost of the writes to it made by the same
+///   transaction (since the entry was last removed, if it was removed before).
+///
+/// So that credits can't be gamed:
+/// - every charge is credited at most once, and only partially, while the cleanup operation is
+///   itself charged in full, so creating and cleaning up never costs less than not doing it;
+/// - credits are only settled on finalization (see `ExecutionFeeReserve::credit_execution()`),
+///   so they can't pay for further execution, and are dropped if the transaction fails.
 #[derive(Debug, Clone)]
 pub struct CostingModule {
     pub fee_reserve: SystemLoanFeeReserve,
     pub fee_table: FeeTable,
     pub max_call_depth: usize,
+    /// The cost units charged for creating the nodes of this transaction which are still alive
+    created_nodes: BTreeMap<RENodeId, u32>,
+    /// The cost units charged for the writes to key value store entries, since their last removal
+    written_kv_entries: BTreeMap<SubstateId, u32>,
 }
 
 impl CostingModule {
+    pub fn new(
+        fee_reserve: SystemLoanFeeReserve,
+        fee_table: FeeTable,
+        max_call_depth: usize,
+    ) -> Self {
+        Self {
+            fee_reserve,
+            fee_table,
+            max_call_depth,
+            created_nodes: BTreeMap::new(),
+            written_kv_entries: BTreeMap::new(),
+        }
+    }
+
     pub fn take_fee_reserve(self) -> SystemLoanFeeReserve {
         self.fee_reserve
     }
@@ -68,6 +99,43 @@ impl CostingModule {
             })
     }
 
+    /// Credits back part of the given cost units, previously charged for the given reason, for
+    /// cleaning up after the operation they were charged for.
+    fn apply_cleanup_credit(
+        &mut self,
+        reason: CostingReason,
+        cost_units: u32,
+    ) -> Result<(), RuntimeError> {
+        let credit = self.fee_table.cleanup_credit(cost_units);
+        self.fee_reserve
+            .credit_execution(credit, reason)
+            .map_err(|e| {
+                RuntimeError::ModuleError(ModuleError::CostingError(CostingError::FeeReserveError(
+                    e,
+                )))
+            })
+    }
+
+    /// Takes the cost units charged for the writes to a key value store entry which is about to
+    /// be removed; to be handed back to `on_kv_entry_removed()` once it has been.
+    pub fn take_kv_entry_write_cost(&mut self, substate_id: &SubstateId) -> u32 {
+        self.written_kv_entries.remove(substate_id).unwrap_or(0)
+    }
+
+    /// Credits back part of the cost of the writes to a key value store entry which has just been
+    /// removed (the removal itself is not credited).
+    pub fn on_kv_entry_removed(
+        &mut self,
+        substate_id: &SubstateId,
+        write_cost_units: u32,
+    ) -> Result<(), RuntimeError> {
+        self.written_kv_entries.remove(substate_id);
+        if write_cost_units > 0 {
+            self.apply_cleanup_credit(CostingReason::WriteSubstate, write_cost_units)?;
+        }
+        Ok(())
+    }
+
     pub fn credit_cost_units(
         &mut self,
         vault_id: ObjectId,
@@ -237,16 +305,28 @@ impl KernelModule for CostingModule {
 
     fn before_create_node<Y: KernelModuleApi<RuntimeError>>(
         api: &mut Y,
-        _node_id: &RENodeId,
+        node_id: &RENodeId,
         _node_init: &RENodeInit,
         _node_module_init: &BTreeMap<NodeModuleId, RENodeModuleInit>,
     ) -> Result<(), RuntimeError> {
         // TODO: calculate size
-        api.kernel_get_module_state().costing.apply_execution_cost(
-            CostingReason::CreateNode,
-            |fee_table| fee_table.kernel_api_cost(CostingEntry::CreateNode { size: 0 }),
-            1,
-        )?;
+        let costing = &mut api.kernel_get_module_state().costing;
+        let cost_units = costing
+            .fee_table
+            .kernel_api_cost(CostingEntry::CreateNode { size: 0 });
+        costing.apply_execution_cost(CostingReason::CreateNode, |_| cost_units, 1)?;
+        costing.created_nodes.insert(*node_id, cost_units);
+        Ok(())
+    }
+
+    fn before_drop_node<Y: KernelModuleApi<RuntimeError>>(
+        api: &mut Y,
+        node_id: &RENodeId,
+    ) -> Result<(), RuntimeError> {
+        let costing = &mut api.kernel_get_module_state().costing;
+        if let Some(cost_units) = costing.created_nodes.remove(node_id) {
+            costing.apply_cleanup_credit(CostingReason::CreateNode, cost_units)?;
+        }
         Ok(())
     }
 
@@ -291,16 +371,28 @@ impl KernelModule for CostingModule {
 
     fn on_write_substate<Y: KernelModuleApi<RuntimeError>>(
         api: &mut Y,
-        _lock_handle: LockHandle,
+        lock_handle: LockHandle,
         size: usize,
     ) -> Result<(), RuntimeError> {
-        api.kernel_get_module_state().costing.apply_execution_cost(
-            CostingReason::WriteSubstate,
-            |fee_table| {
-                fee_table.kernel_api_cost(CostingEntry::WriteSubstate { size: size as u32 })
-            },
-            1,
-        )?;
+        let LockInfo {
+            node_id,
+            module_id,
+            offset,
+            ..
+        } = api.kernel_get_lock_info(lock_handle)?;
+
+        let costing = &mut api.kernel_get_module_state().costing;
+        let cost_units = costing
+            .fee_table
+            .kernel_api_cost(CostingEntry::WriteSubstate { size: size as u32 });
+        costing.apply_execution_cost(CostingReason::WriteSubstate, |_| cost_units, 1)?;
+        if let SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(..)) = offset {
+            let written = costing
+                .written_kv_entries
+                .entry(SubstateId(node_id, module_id, offset))
+                .or_default();
+            *written = written.saturating_add(cost_units);
+        }
         Ok(())
     }
 
@@ -315,4 +407,20 @@ impl KernelModule for CostingModule {
         )?;
         Ok(())
     }
+    fn on_list_substates<Y: KernelModuleApi<RuntimeError>>(
+        api: &mut Y,
+        _node_id: &RENodeId,
+        count: usize,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_module_state().costing.apply_execution_cost(
+            CostingReason::ListSubstates,
+            |fee_table| {
+                fee_table.kernel_api_cost(CostingEntry::ListSubstates {
+                    count: count as u32,
+                })
+            },
+            1,
+        )?;
+        Ok(())
+    }
 }
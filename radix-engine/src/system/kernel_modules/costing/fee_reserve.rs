@@ -1,4 +1,4 @@
-use super::FeeSummary;
+use super::{FeeSummary, FeeTable};
 use crate::{errors::CanBeAbortion, transaction::AbortReason, types::*};
 use radix_engine_constants::{
     DEFAULT_COST_UNIT_LIMIT, DEFAULT_COST_UNIT_PRICE, DEFAULT_SYSTEM_LOAN,
@@ -69,6 +69,16 @@ pub trait ExecutionFeeReserve {
         fee: LiquidFungibleResource,
         contingent: bool,
     ) -> Result<LiquidFungibleResource, FeeReserveError>;
+
+    /// Credits back execution cost units previously consumed for the given reason.
+    ///
+    /// Credits are settled on finalization: they can't pay for the rest of the execution, nor
+    /// raise the cost unit limit, and they are capped by the cost units consumed for the reason.
+    fn credit_execution(
+        &mut self,
+        cost_units: u32,
+        reason: CostingReason,
+    ) -> Result<(), FeeReserveError>;
 }
 
 pub trait FinalizingFeeReserve {
@@ -103,9 +113,11 @@ pub enum CostingReason {
     ReadSubstate,
     WriteSubstate,
     DropLock,
+    ListSubstates,
     RunWasm,
     RunNative,
     RunSystem,
+    RunCryptoUtils,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, ScryptoSbor)]
@@ -116,6 +128,12 @@ pub enum RoyaltyRecipient {
 
 #[derive(Debug, Clone, ScryptoSbor)]
 pub struct SystemLoanFeeReserve {
+    /// The version of the fee table in use
+    fee_table_version: u32,
+    /// The number of cost units charged (or refunded) per byte of state growth (or shrinkage)
+    storage_cost_per_byte: u32,
+    /// The percentage of the execution cost which a storage refund may offset at most
+    storage_refund_limit_percentage: u32,
     /// The price of cost unit
     cost_unit_price: u128,
     /// The tip percentage
@@ -143,13 +161,21 @@ pub struct SystemLoanFeeReserve {
     execution_committed_sum: u32,
     /// Execution costs deferred
     execution_deferred: [u32; CostingReason::COUNT],
+    /// Execution costs credited back, to be deducted on finalization
+    execution_credited: [u32; CostingReason::COUNT],
+    execution_credited_sum: u32,
 
     /// Royalty costs
     royalty_committed: BTreeMap<RoyaltyRecipient, (ObjectId, u128)>,
+    royalty_committed_cost_units: BTreeMap<RoyaltyRecipient, u32>,
     royalty_committed_sum: u32,
 
     /// Payments made during the execution of a transaction.
     payments: Vec<(ObjectId, LiquidFungibleResource, bool)>,
+
+    /// The net number of bytes added to (if positive) or freed from (if negative) the substate
+    /// store by the transaction
+    net_storage_bytes: i64,
 }
 
 #[inline]
@@ -196,6 +222,9 @@ impl SystemLoanFeeReserve {
         let effective_royalty_price = cost_unit_price;
 
         Self {
+            fee_table_version: FeeTable::INITIAL_VERSION,
+            storage_cost_per_byte: FeeTable::new().storage_cost_per_byte(),
+            storage_refund_limit_percentage: FeeTable::new().storage_refund_limit_percentage(),
             cost_unit_price,
             tip_percentage,
             cost_unit_limit,
@@ -212,11 +241,48 @@ impl SystemLoanFeeReserve {
             execution_committed: [0u32; CostingReason::COUNT],
             execution_committed_sum: 0,
             execution_deferred: [0u32; CostingReason::COUNT],
+            execution_credited: [0u32; CostingReason::COUNT],
+            execution_credited_sum: 0,
             royalty_committed: BTreeMap::new(),
+            royalty_committed_cost_units: BTreeMap::new(),
             royalty_committed_sum: 0,
 
             payments: Vec::new(),
+
+            net_storage_bytes: 0,
+        }
+    }
+
+    pub fn set_fee_table(&mut self, fee_table: &FeeTable) {
+        self.fee_table_version = fee_table.version();
+        self.storage_cost_per_byte = fee_table.storage_cost_per_byte();
+        self.storage_refund_limit_percentage = fee_table.storage_refund_limit_percentage();
+    }
+
+    /// Records the net state growth of the transaction, which is charged (or refunded) on
+    /// finalization.
+    pub fn set_net_storage_bytes(&mut self, net_storage_bytes: i64) {
+        self.net_storage_bytes = net_storage_bytes;
+    }
+
+    /// Returns whether the locked fees (including the contingent ones) cover the storage cost of
+    /// the given net state growth, on top of the costs consumed so far.
+    pub fn covers_storage_cost(&self, net_storage_bytes: i64) -> bool {
+        if net_storage_bytes <= 0 {
+            return true;
         }
+        let locked: u128 = self
+            .payments
+            .iter()
+            .map(|(_, fee, _)| decimal_to_u128(fee.amount()))
+            .sum();
+        let consumed = self.effective_execution_price * self.execution_committed_sum as u128
+            + self.royalty_committed.values().map(|x| x.1).sum::<u128>();
+        let storage_cost = self
+            .cost_unit_price
+            .saturating_mul(self.storage_cost_per_byte as u128)
+            .saturating_mul(net_storage_bytes as u128);
+        locked.saturating_sub(consumed) >= storage_cost
     }
 
     fn check_cost_unit_limit(&self, cost_units: u32) -> Result<(), FeeReserveError> {
@@ -266,10 +332,14 @@ impl SystemLoanFeeReserve {
         } else {
             self.xrd_balance -= amount;
             self.royalty_committed
-                .entry(recipient)
+                .entry(recipient.clone())
                 .or_insert((recipient_vault_id, 0))
                 .1
                 .add_assign(amount);
+            self.royalty_committed_cost_units
+                .entry(recipient)
+                .or_default()
+                .add_assign(cost_units);
             self.royalty_committed_sum += cost_units;
             Ok(())
         }
@@ -303,9 +373,15 @@ impl SystemLoanFeeReserve {
     pub fn revert_royalty(&mut self) {
         self.xrd_balance += self.royalty_committed.values().map(|x| x.1).sum::<u128>();
         self.royalty_committed.clear();
+        self.royalty_committed_cost_units.clear();
         self.royalty_committed_sum = 0;
     }
 
+    pub fn revert_credits(&mut self) {
+        self.execution_credited = [0u32; CostingReason::COUNT];
+        self.execution_credited_sum = 0;
+    }
+
     pub fn royalty_cost(&self) -> BTreeMap<RoyaltyRecipient, (ObjectId, Decimal)> {
         self.royalty_committed
             .clone()
@@ -315,14 +391,22 @@ impl SystemLoanFeeReserve {
     }
 
     pub fn execution_cost(&self) -> BTreeMap<CostingReason, u32> {
-        self.execution_committed
+        Self::breakdown(&self.execution_committed)
+    }
+
+    pub fn execution_credit(&self) -> BTreeMap<CostingReason, u32> {
+        Self::breakdown(&self.execution_credited)
+    }
+
+    fn breakdown(cost_units: &[u32; CostingReason::COUNT]) -> BTreeMap<CostingReason, u32> {
+        cost_units
             .into_iter()
             .enumerate()
             .filter_map(|(i, sum)| {
-                if sum == 0 {
+                if *sum == 0 {
                     None
                 } else {
-                    Some((CostingReason::from_repr(i).unwrap(), sum))
+                    Some((CostingReason::from_repr(i).unwrap(), *sum))
                 }
             })
             .collect()
@@ -425,26 +509,56 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
 
         Ok(fee)
     }
+
+    fn credit_execution(
+        &mut self,
+        cost_units: u32,
+        reason: CostingReason,
+    ) -> Result<(), FeeReserveError> {
+        let credited = &mut self.execution_credited[reason as usize];
+        let creditable = self.execution_committed[reason as usize] - *credited;
+        let cost_units = min(cost_units, creditable);
+        *credited += cost_units;
+        self.execution_credited_sum += cost_units;
+        Ok(())
+    }
 }
 
 impl FinalizingFeeReserve for SystemLoanFeeReserve {
     fn finalize(self) -> FeeSummary {
         let execution_cost_breakdown = self.execution_cost();
+        let execution_credit_breakdown = self.execution_credit();
         let royalty_cost_breakdown = self.royalty_cost();
         let total_royalty_cost_xrd = royalty_cost_breakdown.values().map(|x| x.1).sum();
+        let execution_cost = self.effective_execution_price
+            * (self.execution_committed_sum - self.execution_credited_sum) as u128;
+        let storage_cost = self.cost_unit_price
+            * self.storage_cost_per_byte as u128
+            * self.net_storage_bytes.unsigned_abs() as u128;
+        let total_storage_cost_xrd = if self.net_storage_bytes < 0 {
+            // The refund may only offset a part of the execution cost (and none of the royalty)
+            let refund_limit = execution_cost * self.storage_refund_limit_percentage as u128 / 100;
+            -u128_to_decimal(storage_cost.min(refund_limit))
+        } else {
+            u128_to_decimal(storage_cost)
+        };
         FeeSummary {
+            fee_table_version: self.fee_table_version,
             cost_unit_limit: self.cost_unit_limit,
             cost_unit_price: u128_to_decimal(self.cost_unit_price),
             tip_percentage: self.tip_percentage,
-            total_execution_cost_xrd: u128_to_decimal(
-                self.effective_execution_price * self.execution_committed_sum as u128,
-            ),
+            total_execution_cost_xrd: u128_to_decimal(execution_cost),
             total_royalty_cost_xrd,
+            net_storage_bytes: self.net_storage_bytes,
+            total_storage_cost_xrd,
             total_bad_debt_xrd: u128_to_decimal(self.xrd_owed),
             locked_fees: self.payments,
             execution_cost_breakdown,
             execution_cost_sum: self.execution_committed_sum,
+            execution_credit_breakdown,
+            execution_credit_sum: self.execution_credited_sum,
             royalty_cost_breakdown,
+            royalty_cost_unit_breakdown: self.royalty_committed_cost_units,
         }
     }
 }
@@ -490,6 +604,57 @@ mod tests {
         assert_eq!(summary.total_bad_debt_xrd, dec!("0"));
     }
 
+    #[test]
+    fn test_credit_execution() {
+        let mut fee_reserve =
+            SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 0, 100, 50, false);
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID, xrd(100), false)
+            .unwrap();
+        fee_reserve
+            .consume_multiplied_execution(10, 1, CostingReason::CreateNode)
+            .unwrap();
+        fee_reserve
+            .credit_execution(4, CostingReason::CreateNode)
+            .unwrap();
+        fee_reserve
+            .credit_execution(4, CostingReason::DropNode)
+            .unwrap();
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.execution_cost_sum, 10);
+        assert_eq!(summary.execution_credit_sum, 4);
+        assert_eq!(
+            summary.execution_credit_breakdown,
+            btreemap!(
+                CostingReason::CreateNode => 4
+            )
+        );
+        assert_eq!(summary.total_execution_cost_xrd, dec!("6"));
+    }
+
+    #[test]
+    fn test_credit_execution_is_capped_by_consumption() {
+        let mut fee_reserve =
+            SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 0, 100, 50, false);
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID, xrd(100), false)
+            .unwrap();
+        fee_reserve
+            .consume_multiplied_execution(10, 1, CostingReason::WriteSubstate)
+            .unwrap();
+        fee_reserve
+            .credit_execution(8, CostingReason::WriteSubstate)
+            .unwrap();
+        fee_reserve
+            .credit_execution(8, CostingReason::WriteSubstate)
+            .unwrap();
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.execution_credit_sum, 10);
+        assert_eq!(summary.total_execution_cost_xrd, dec!("0"));
+    }
+
     #[test]
     fn test_out_of_cost_unit() {
         let mut fee_reserve = SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 2, 100, 5, false);
@@ -559,6 +724,27 @@ mod tests {
         assert_eq!(summary.locked_fees, vec![],);
     }
 
+    #[test]
+    fn test_covers_storage_cost() {
+        let mut fee_reserve =
+            SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 0, 1000, 50, false);
+        fee_reserve.set_fee_table(&FeeTable::new());
+        let storage_cost_per_byte = FeeTable::new().storage_cost_per_byte() as i64;
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID, xrd(10 * storage_cost_per_byte), false)
+            .unwrap();
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID_2, xrd(5 * storage_cost_per_byte), true)
+            .unwrap();
+        fee_reserve
+            .consume_execution(storage_cost_per_byte as u32, CostingReason::Invoke)
+            .unwrap();
+        fee_reserve.repay_all().unwrap();
+        assert!(fee_reserve.covers_storage_cost(-100));
+        assert!(fee_reserve.covers_storage_cost(14));
+        assert!(!fee_reserve.covers_storage_cost(15));
+    }
+
     #[test]
     fn test_royalty_execution_mix() {
         let mut fee_reserve =
@@ -5,6 +5,8 @@ use sbor::rust::collections::*;
 
 #[derive(Debug, Clone, ScryptoSbor)]
 pub struct FeeSummary {
+    /// The version of the fee table used to price the transaction.
+    pub fee_table_version: u32,
     /// The cost unit price in XRD.
     pub cost_unit_price: Decimal,
     /// The tip percentage
@@ -15,6 +17,11 @@ pub struct FeeSummary {
     pub total_execution_cost_xrd: Decimal,
     /// The total royalty.
     pub total_royalty_cost_xrd: Decimal,
+    /// The net number of bytes added to (or, if negative, freed from) the substate store.
+    pub net_storage_bytes: i64,
+    /// The storage cost of the net state growth, or the refund for freed storage if negative (see
+    /// `FeeTable::storage_refund_limit_percentage`).
+    pub total_storage_cost_xrd: Decimal,
     /// The (non-negative) amount of bad debt due to transaction unable to repay loan.
     pub total_bad_debt_xrd: Decimal,
     /// The vaults locked for XRD payment
@@ -23,8 +30,15 @@ pub struct FeeSummary {
     pub execution_cost_breakdown: BTreeMap<CostingReason, u32>,
     /// The total number of cost units consumed.
     pub execution_cost_sum: u32,
+    /// The execution cost credited back for cleaning up, e.g. dropping the nodes created by the
+    /// transaction; it is deducted from `total_execution_cost_xrd`
+    pub execution_credit_breakdown: BTreeMap<CostingReason, u32>,
+    /// The total number of cost units credited back.
+    pub execution_credit_sum: u32,
     /// The royalty cost breakdown
     pub royalty_cost_breakdown: BTreeMap<RoyaltyRecipient, (ObjectId, Decimal)>,
+    /// The royalty cost breakdown, in cost units
+    pub royalty_cost_unit_breakdown: BTreeMap<RoyaltyRecipient, u32>,
 }
 
 impl FeeSummary {
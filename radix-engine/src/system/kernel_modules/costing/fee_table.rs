@@ -17,27 +17,99 @@ pub enum CostingEntry {
     ReadSubstate { size: u32 },
     WriteSubstate { size: u32 },
     DropLock,
+    ListSubstates { count: u32 },
+
+    /* crypto utils */
+    VerifyEcdsaSecp256k1 { size: u32 },
+    VerifyEddsaEd25519 { size: u32 },
+    VerifyBls12381,
+    AggregateVerifyBls12381 { count: u32 },
+    Hash { size: u32 },
+
+    /* logger */
+    LogMessage { size: u32 },
     // TODO: more costing after API becomes stable.
 }
 
-#[derive(Debug, Clone, ScryptoSbor)]
+/// The prices of transaction execution, in cost units.
+///
+/// The fee table in effect is stored on ledger, under the epoch manager, and can be replaced by a
+/// protocol update transaction. Every update is expected to bump the `version`, which is reported
+/// in the `FeeSummary` of each transaction.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
 pub struct FeeTable {
-    tx_base_fee: u32,
-    tx_payload_cost_per_byte: u32,
-    tx_signature_verification_per_sig: u32,
-    tx_blob_price_per_byte: u32,
+    pub version: u32,
+
+    pub tx_base_fee: u32,
+    pub tx_payload_cost_per_byte: u32,
+    pub tx_signature_verification_per_sig: u32,
+    pub tx_blob_price_per_byte: u32,
+
+    pub fixed_low_fee: u32,
+    pub fixed_medium_fee: u32,
+    pub fixed_high_fee: u32,
+
+    pub invoke_cost_per_byte: u32,
+    pub node_cost_per_byte: u32,
+    pub read_substate_cost_per_byte: u32,
+    pub write_substate_cost_per_byte: u32,
+
+    pub storage_cost_per_byte: u32,
+    /// The percentage of the execution cost which the refund for freed storage may offset at most
+    /// (so that execution is always paid for; royalties are never offset).
+    pub storage_refund_limit_percentage: u32,
+
+    pub verify_ecdsa_secp256k1_cost: u32,
+    pub verify_eddsa_ed25519_cost: u32,
+    pub verify_bls12381_cost: u32,
+    /// Covers the verification of the key's proof of possession, too.
+    pub aggregate_verify_bls12381_cost_per_key: u32,
+    pub hash_cost_per_byte: u32,
+
+    /// The percentage of the cost of creating a node (or writing a key value store entry) which
+    /// is credited back when the node is dropped (or the entry removed) by the same transaction.
+    pub cleanup_credit_percentage: u32,
 }
 
 impl FeeTable {
+    /// The version of the built-in fee table, which is in effect until another one is set.
+    pub const INITIAL_VERSION: u32 = 1;
+
     pub fn new() -> Self {
         Self {
+            version: Self::INITIAL_VERSION,
+
             tx_base_fee: 50_000,
             tx_payload_cost_per_byte: 5,
             tx_signature_verification_per_sig: 100_000,
             tx_blob_price_per_byte: 5,
+
+            fixed_low_fee: FIXED_LOW_FEE,
+            fixed_medium_fee: FIXED_MEDIUM_FEE,
+            fixed_high_fee: FIXED_HIGH_FEE,
+
+            invoke_cost_per_byte: 10,
+            node_cost_per_byte: 100,
+            read_substate_cost_per_byte: 10,
+            write_substate_cost_per_byte: 1000,
+
+            storage_cost_per_byte: 100,
+            storage_refund_limit_percentage: 50,
+
+            verify_ecdsa_secp256k1_cost: 20_000,
+            verify_eddsa_ed25519_cost: 20_000,
+            verify_bls12381_cost: 100_000,
+            aggregate_verify_bls12381_cost_per_key: 100_000,
+            hash_cost_per_byte: 2,
+
+            cleanup_credit_percentage: 50,
         }
     }
 
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
     pub fn tx_base_fee(&self) -> u32 {
         self.tx_base_fee
     }
@@ -54,17 +126,79 @@ impl FeeTable {
         self.tx_blob_price_per_byte
     }
 
+    pub fn storage_cost_per_byte(&self) -> u32 {
+        self.storage_cost_per_byte
+    }
+
+    pub fn storage_refund_limit_percentage(&self) -> u32 {
+        self.storage_refund_limit_percentage.min(100)
+    }
+
+    /// Returns the cost units credited back for cleaning up after an operation which cost the
+    /// given cost units.
+    pub fn cleanup_credit(&self, cost_units: u32) -> u32 {
+        let credit = cost_units as u64 * self.cleanup_credit_percentage.min(100) as u64 / 100;
+        credit as u32
+    }
+
     pub fn kernel_api_cost(&self, entry: CostingEntry) -> u32 {
         match entry {
-            CostingEntry::Invoke { input_size } => FIXED_LOW_FEE + (10 * input_size) as u32,
+            CostingEntry::Invoke { input_size } => self
+                .fixed_low_fee
+                .saturating_add(self.invoke_cost_per_byte.saturating_mul(input_size)),
+
+            CostingEntry::CreateNode { size } => self
+                .fixed_medium_fee
+                .saturating_add(self.node_cost_per_byte.saturating_mul(size)),
+            CostingEntry::DropNode { size } => self
+                .fixed_medium_fee
+                .saturating_add(self.node_cost_per_byte.saturating_mul(size)),
+
+            CostingEntry::LockSubstate => self.fixed_low_fee,
+            CostingEntry::ReadSubstate { size } => self
+                .fixed_low_fee
+                .saturating_add(self.read_substate_cost_per_byte.saturating_mul(size)),
+            CostingEntry::WriteSubstate { size } => self
+                .fixed_low_fee
+                .saturating_add(self.write_substate_cost_per_byte.saturating_mul(size)),
+            CostingEntry::DropLock => self.fixed_low_fee,
+            CostingEntry::ListSubstates { count } => {
+                self.fixed_low_fee.saturating_mul(count.saturating_add(1))
+            }
 
-            CostingEntry::CreateNode { size } => FIXED_MEDIUM_FEE + (100 * size) as u32,
-            CostingEntry::DropNode { size } => FIXED_MEDIUM_FEE + (100 * size) as u32,
+            CostingEntry::VerifyEcdsaSecp256k1 { size } => self
+                .verify_ecdsa_secp256k1_cost
+                .saturating_add(self.hash_cost_per_byte.saturating_mul(size)),
+            CostingEntry::VerifyEddsaEd25519 { size } => self
+                .verify_eddsa_ed25519_cost
+                .saturating_add(self.hash_cost_per_byte.saturating_mul(size)),
+            CostingEntry::VerifyBls12381 => self.verify_bls12381_cost,
+            CostingEntry::AggregateVerifyBls12381 { count } => {
+                self.verify_bls12381_cost.saturating_add(
+                    self.aggregate_verify_bls12381_cost_per_key
+                        .saturating_mul(count),
+                )
+            }
+            CostingEntry::Hash { size } => self
+                .fixed_low_fee
+                .saturating_add(self.hash_cost_per_byte.saturating_mul(size)),
+
+            // A log message is copied out of the call frame, like the input of an invocation, but
+            // the short ones cost no more than the fixed low fee
+            CostingEntry::LogMessage { size } => self
+                .fixed_low_fee
+                .max(self.invoke_cost_per_byte.saturating_mul(size)),
+        }
+    }
 
-            CostingEntry::LockSubstate => FIXED_LOW_FEE,
-            CostingEntry::ReadSubstate { size } => FIXED_LOW_FEE + 10 * size,
-            CostingEntry::WriteSubstate { size } => FIXED_LOW_FEE + 1000 * size,
-            CostingEntry::DropLock => FIXED_LOW_FEE,
+    /// Prices the cost units consumed through the client API, with the fixed fee tiers (as used
+    /// by native blueprints and system calls) taken from this table.
+    pub fn client_cost(&self, units: ClientCostUnits) -> u32 {
+        match units {
+            ClientCostUnits::Exact(cost_units) => cost_units,
+            ClientCostUnits::FixedLowFee => self.fixed_low_fee,
+            ClientCostUnits::FixedMediumFee => self.fixed_medium_fee,
+            ClientCostUnits::FixedHighFee => self.fixed_high_fee,
         }
     }
 }
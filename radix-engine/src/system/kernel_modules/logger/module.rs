@@ -1,25 +1,64 @@
+use crate::kernel::actor::Actor;
 use crate::kernel::module::KernelModule;
-
+use crate::types::*;
 use radix_engine_interface::api::types::Level;
-use sbor::rust::string::String;
-use sbor::rust::vec::Vec;
+
+/// A log message, tagged with the call frame it was emitted from.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct LogRecord {
+    pub level: Level,
+    pub message: String,
+    /// The key/value fields of the record, in emission order.
+    pub fields: Vec<(String, String)>,
+    /// The actor of the emitting call frame (`None` for the root frame).
+    pub actor: Option<Actor>,
+    /// The depth of the emitting call frame.
+    pub depth: usize,
+}
+
+impl LogRecord {
+    /// Returns the message followed by the `key=value` fields, if any.
+    pub fn display_message(&self) -> String {
+        let mut message = self.message.clone();
+        for (key, value) in &self.fields {
+            message.push_str(&format!(" {}={}", key, value));
+        }
+        message
+    }
+}
 
 #[derive(Debug, Clone)]
-pub struct LoggerModule(Vec<(Level, String)>);
+pub struct LoggerModule {
+    /// The least severe level which is recorded; the messages of more verbose levels are
+    /// dropped (and not charged for).
+    min_level: Level,
+    records: Vec<LogRecord>,
+}
 
 impl Default for LoggerModule {
     fn default() -> Self {
-        Self(Default::default())
+        Self::new(Level::Trace)
     }
 }
 
 impl LoggerModule {
-    pub fn add_log(&mut self, level: Level, message: String) {
-        self.0.push((level, message))
+    pub fn new(min_level: Level) -> Self {
+        Self {
+            min_level,
+            records: Vec::new(),
+        }
+    }
+
+    pub fn is_enabled(&self, level: Level) -> bool {
+        level <= self.min_level
+    }
+
+    pub fn add_log(&mut self, record: LogRecord) {
+        self.records.push(record)
     }
 
-    pub fn logs(self) -> Vec<(Level, String)> {
-        self.0
+    pub fn logs(self) -> Vec<LogRecord> {
+        self.records
     }
 }
 
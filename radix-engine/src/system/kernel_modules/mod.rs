@@ -1,4 +1,5 @@
 pub mod auth;
+pub mod cost_profiler;
 pub mod costing;
 pub mod events;
 pub mod execution_trace;
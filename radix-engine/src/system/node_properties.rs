@@ -96,6 +96,7 @@ impl VisibilityProperties {
                 SubstateOffset::Package(PackageOffset::Royalty) => true,
                 SubstateOffset::Package(PackageOffset::FunctionAccessRules) => true,
                 SubstateOffset::Component(ComponentOffset::State0) => read_only,
+                SubstateOffset::Component(ComponentOffset::StateVersion) => read_only,
                 SubstateOffset::TypeInfo(_) => read_only,
                 SubstateOffset::AccessRules(_) => read_only,
                 SubstateOffset::Royalty(_) => true,
@@ -167,7 +168,10 @@ impl VisibilityProperties {
                                         // READ/WRITE component application state
                                         (
                                             RENodeId::Object(addr),
-                                            SubstateOffset::Component(ComponentOffset::State0),
+                                            SubstateOffset::Component(
+                                                ComponentOffset::State0
+                                                | ComponentOffset::StateVersion,
+                                            ),
                                         ) => addr.eq(component_address),
                                         // Otherwise, false
                                         _ => false,
@@ -200,7 +204,9 @@ impl VisibilityProperties {
                                     // READ/WRITE component application state
                                     (
                                         RENodeId::GlobalObject(Address::Component(addr)),
-                                        SubstateOffset::Component(ComponentOffset::State0),
+                                        SubstateOffset::Component(
+                                            ComponentOffset::State0 | ComponentOffset::StateVersion,
+                                        ),
                                     ) => addr.eq(component_address),
                                     // Otherwise, false
                                     _ => false,
@@ -237,7 +243,10 @@ impl VisibilityProperties {
                                         ) => true,
                                         (
                                             RENodeId::Object(addr),
-                                            SubstateOffset::Component(ComponentOffset::State0),
+                                            SubstateOffset::Component(
+                                                ComponentOffset::State0
+                                                | ComponentOffset::StateVersion,
+                                            ),
                                         ) => addr.eq(component_address),
                                         _ => false,
                                     }
@@ -254,7 +263,9 @@ impl VisibilityProperties {
                                     ) => true,
                                     (
                                         RENodeId::GlobalObject(Address::Component(addr)),
-                                        SubstateOffset::Component(ComponentOffset::State0),
+                                        SubstateOffset::Component(
+                                            ComponentOffset::State0 | ComponentOffset::StateVersion,
+                                        ),
                                     ) => addr.eq(component_address),
                                     _ => false,
                                 },
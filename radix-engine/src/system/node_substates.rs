@@ -9,6 +9,7 @@ use crate::blueprints::epoch_manager::ValidatorSubstate;
 use crate::blueprints::package::PackageCodeTypeSubstate;
 use crate::blueprints::resource::*;
 use crate::errors::*;
+use crate::system::kernel_modules::costing::FeeTable;
 use crate::system::node_modules::access_rules::FunctionAccessRulesSubstate;
 use crate::system::node_modules::type_info::TypeInfoSubstate;
 use crate::types::*;
@@ -26,11 +27,13 @@ use radix_engine_interface::blueprints::resource::LockedNonFungibleResource;
 pub enum PersistedSubstate {
     EpochManager(EpochManagerSubstate),
     ValidatorSet(ValidatorSetSubstate),
+    FeeTable(FeeTable),
     Validator(ValidatorSubstate),
     CurrentTimeRoundedToMinutes(ClockSubstate),
     ResourceManager(FungibleResourceManagerSubstate),
     NonFungibleResourceManager(NonFungibleResourceManagerSubstate),
     ComponentState(ComponentStateSubstate),
+    ComponentStateVersion(ComponentStateVersionSubstate),
     PackageInfo(PackageInfoSubstate),
     PackageCodeType(PackageCodeTypeSubstate),
     PackageCode(PackageCodeSubstate),
@@ -154,6 +157,14 @@ impl PersistedSubstate {
             panic!("Not a resource manager substate");
         }
     }
+
+    pub fn fee_table(&self) -> &FeeTable {
+        if let PersistedSubstate::FeeTable(fee_table) = self {
+            fee_table
+        } else {
+            panic!("Not a fee table substate");
+        }
+    }
 }
 
 impl Into<VaultInfoSubstate> for PersistedSubstate {
@@ -191,6 +202,7 @@ impl PersistedSubstate {
         match self {
             PersistedSubstate::EpochManager(value) => RuntimeSubstate::EpochManager(value),
             PersistedSubstate::ValidatorSet(value) => RuntimeSubstate::ValidatorSet(value),
+            PersistedSubstate::FeeTable(value) => RuntimeSubstate::FeeTable(value),
             PersistedSubstate::Validator(value) => RuntimeSubstate::Validator(value),
             PersistedSubstate::CurrentTimeRoundedToMinutes(value) => {
                 RuntimeSubstate::CurrentTimeRoundedToMinutes(value)
@@ -200,6 +212,9 @@ impl PersistedSubstate {
                 RuntimeSubstate::NonFungibleResourceManager(value)
             }
             PersistedSubstate::ComponentState(value) => RuntimeSubstate::ComponentState(value),
+            PersistedSubstate::ComponentStateVersion(value) => {
+                RuntimeSubstate::ComponentStateVersion(value)
+            }
             PersistedSubstate::PackageInfo(value) => RuntimeSubstate::PackageInfo(value),
             PersistedSubstate::PackageCodeType(value) => RuntimeSubstate::PackageCodeType(value),
             PersistedSubstate::PackageCode(value) => RuntimeSubstate::PackageCode(value),
@@ -245,11 +260,13 @@ impl PersistedSubstate {
 pub enum RuntimeSubstate {
     EpochManager(EpochManagerSubstate),
     ValidatorSet(ValidatorSetSubstate),
+    FeeTable(FeeTable),
     Validator(ValidatorSubstate),
     CurrentTimeRoundedToMinutes(ClockSubstate),
     ResourceManager(FungibleResourceManagerSubstate),
     NonFungibleResourceManager(NonFungibleResourceManagerSubstate),
     ComponentState(ComponentStateSubstate),
+    ComponentStateVersion(ComponentStateVersionSubstate),
     PackageCode(PackageCodeSubstate),
     PackageInfo(PackageInfoSubstate),
     PackageCodeType(PackageCodeTypeSubstate),
@@ -297,6 +314,7 @@ impl RuntimeSubstate {
         match self {
             RuntimeSubstate::EpochManager(value) => PersistedSubstate::EpochManager(value.clone()),
             RuntimeSubstate::ValidatorSet(value) => PersistedSubstate::ValidatorSet(value.clone()),
+            RuntimeSubstate::FeeTable(value) => PersistedSubstate::FeeTable(value.clone()),
             RuntimeSubstate::Validator(value) => PersistedSubstate::Validator(value.clone()),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 PersistedSubstate::CurrentTimeRoundedToMinutes(value.clone())
@@ -310,6 +328,9 @@ impl RuntimeSubstate {
             RuntimeSubstate::ComponentState(value) => {
                 PersistedSubstate::ComponentState(value.clone())
             }
+            RuntimeSubstate::ComponentStateVersion(value) => {
+                PersistedSubstate::ComponentStateVersion(value.clone())
+            }
             RuntimeSubstate::PackageInfo(value) => PersistedSubstate::PackageInfo(value.clone()),
             RuntimeSubstate::PackageCodeType(value) => {
                 PersistedSubstate::PackageCodeType(value.clone())
@@ -369,6 +390,7 @@ impl RuntimeSubstate {
         match self {
             RuntimeSubstate::EpochManager(value) => PersistedSubstate::EpochManager(value),
             RuntimeSubstate::ValidatorSet(value) => PersistedSubstate::ValidatorSet(value),
+            RuntimeSubstate::FeeTable(value) => PersistedSubstate::FeeTable(value),
             RuntimeSubstate::Validator(value) => PersistedSubstate::Validator(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 PersistedSubstate::CurrentTimeRoundedToMinutes(value)
@@ -378,6 +400,9 @@ impl RuntimeSubstate {
                 PersistedSubstate::NonFungibleResourceManager(value)
             }
             RuntimeSubstate::ComponentState(value) => PersistedSubstate::ComponentState(value),
+            RuntimeSubstate::ComponentStateVersion(value) => {
+                PersistedSubstate::ComponentStateVersion(value)
+            }
             RuntimeSubstate::PackageInfo(value) => PersistedSubstate::PackageInfo(value),
             RuntimeSubstate::PackageCodeType(value) => PersistedSubstate::PackageCodeType(value),
             RuntimeSubstate::PackageCode(value) => PersistedSubstate::PackageCode(value),
@@ -460,6 +485,7 @@ impl RuntimeSubstate {
         match self {
             RuntimeSubstate::EpochManager(value) => SubstateRefMut::EpochManager(value),
             RuntimeSubstate::ValidatorSet(value) => SubstateRefMut::ValidatorSet(value),
+            RuntimeSubstate::FeeTable(value) => SubstateRefMut::FeeTable(value),
             RuntimeSubstate::Validator(value) => SubstateRefMut::Validator(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRefMut::CurrentTimeRoundedToMinutes(value)
@@ -471,6 +497,9 @@ impl RuntimeSubstate {
             }
             RuntimeSubstate::TypeInfo(value) => SubstateRefMut::TypeInfo(value),
             RuntimeSubstate::ComponentState(value) => SubstateRefMut::ComponentState(value),
+            RuntimeSubstate::ComponentStateVersion(value) => {
+                SubstateRefMut::ComponentStateVersion(value)
+            }
             RuntimeSubstate::ComponentRoyaltyConfig(value) => {
                 SubstateRefMut::ComponentRoyaltyConfig(value)
             }
@@ -526,6 +555,7 @@ impl RuntimeSubstate {
             RuntimeSubstate::TypeInfo(value) => SubstateRef::TypeInfo(value),
             RuntimeSubstate::EpochManager(value) => SubstateRef::EpochManager(value),
             RuntimeSubstate::ValidatorSet(value) => SubstateRef::ValidatorSet(value),
+            RuntimeSubstate::FeeTable(value) => SubstateRef::FeeTable(value),
             RuntimeSubstate::Validator(value) => SubstateRef::Validator(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRef::CurrentTimeRoundedToMinutes(value)
@@ -536,6 +566,9 @@ impl RuntimeSubstate {
                 SubstateRef::NonFungibleResourceManager(value)
             }
             RuntimeSubstate::ComponentState(value) => SubstateRef::ComponentState(value),
+            RuntimeSubstate::ComponentStateVersion(value) => {
+                SubstateRef::ComponentStateVersion(value)
+            }
             RuntimeSubstate::ComponentRoyaltyConfig(value) => {
                 SubstateRef::ComponentRoyaltyConfig(value)
             }
@@ -663,6 +696,12 @@ impl Into<RuntimeSubstate> for ValidatorSetSubstate {
     }
 }
 
+impl Into<RuntimeSubstate> for FeeTable {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::FeeTable(self)
+    }
+}
+
 impl Into<RuntimeSubstate> for ValidatorSubstate {
     fn into(self) -> RuntimeSubstate {
         RuntimeSubstate::Validator(self)
@@ -710,6 +749,12 @@ impl Into<RuntimeSubstate> for ComponentStateSubstate {
     }
 }
 
+impl Into<RuntimeSubstate> for ComponentStateVersionSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::ComponentStateVersion(self)
+    }
+}
+
 impl Into<RuntimeSubstate> for FungibleResourceManagerSubstate {
     fn into(self) -> RuntimeSubstate {
         RuntimeSubstate::ResourceManager(self)
@@ -972,6 +1017,16 @@ impl Into<ValidatorSetSubstate> for RuntimeSubstate {
     }
 }
 
+impl Into<FeeTable> for RuntimeSubstate {
+    fn into(self) -> FeeTable {
+        if let RuntimeSubstate::FeeTable(substate) = self {
+            substate
+        } else {
+            panic!("Not a fee table");
+        }
+    }
+}
+
 impl Into<AuthZoneStackSubstate> for RuntimeSubstate {
     fn into(self) -> AuthZoneStackSubstate {
         if let RuntimeSubstate::AuthZoneStack(substate) = self {
@@ -988,6 +1043,7 @@ pub enum SubstateRef<'a> {
     Worktop(&'a WorktopSubstate),
     ComponentInfo(&'a TypeInfoSubstate),
     ComponentState(&'a ComponentStateSubstate),
+    ComponentStateVersion(&'a ComponentStateVersionSubstate),
     ComponentRoyaltyConfig(&'a ComponentRoyaltyConfigSubstate),
     ComponentRoyaltyAccumulator(&'a ComponentRoyaltyAccumulatorSubstate),
     KeyValueStoreEntry(&'a Option<ScryptoValue>),
@@ -1012,6 +1068,7 @@ pub enum SubstateRef<'a> {
     NonFungibleResourceManager(&'a NonFungibleResourceManagerSubstate),
     EpochManager(&'a EpochManagerSubstate),
     ValidatorSet(&'a ValidatorSetSubstate),
+    FeeTable(&'a FeeTable),
     Validator(&'a ValidatorSubstate),
     CurrentTimeRoundedToMinutes(&'a ClockSubstate),
     MethodAccessRules(&'a MethodAccessRulesSubstate),
@@ -1123,6 +1180,15 @@ impl<'a> From<SubstateRef<'a>> for &'a EpochManagerSubstate {
     }
 }
 
+impl<'a> From<SubstateRef<'a>> for &'a FeeTable {
+    fn from(value: SubstateRef<'a>) -> Self {
+        match value {
+            SubstateRef::FeeTable(value) => value,
+            _ => panic!("Not a fee table"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRef<'a>> for &'a ValidatorSubstate {
     fn from(value: SubstateRef<'a>) -> Self {
         match value {
@@ -1141,6 +1207,15 @@ impl<'a> From<SubstateRef<'a>> for &'a ComponentStateSubstate {
     }
 }
 
+impl<'a> From<SubstateRef<'a>> for &'a ComponentStateVersionSubstate {
+    fn from(value: SubstateRef<'a>) -> Self {
+        match value {
+            SubstateRef::ComponentStateVersion(value) => value,
+            _ => panic!("Not a component state version"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRef<'a>> for &'a ComponentRoyaltyConfigSubstate {
     fn from(value: SubstateRef<'a>) -> Self {
         match value {
@@ -1296,6 +1371,7 @@ impl<'a> SubstateRef<'a> {
             SubstateRef::FungibleResourceManager(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::TypeInfo(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::ComponentState(value) => IndexedScryptoValue::from_typed(*value),
+            SubstateRef::ComponentStateVersion(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::ComponentRoyaltyConfig(value) => IndexedScryptoValue::from_typed(*value),
             SubstateRef::ComponentRoyaltyAccumulator(value) => {
                 IndexedScryptoValue::from_typed(*value)
@@ -1446,6 +1522,7 @@ impl<'a> SubstateRef<'a> {
 pub enum SubstateRefMut<'a> {
     TypeInfo(&'a mut TypeInfoSubstate),
     ComponentState(&'a mut ComponentStateSubstate),
+    ComponentStateVersion(&'a mut ComponentStateVersionSubstate),
     ComponentRoyaltyConfig(&'a mut ComponentRoyaltyConfigSubstate),
     ComponentRoyaltyAccumulator(&'a mut ComponentRoyaltyAccumulatorSubstate),
     PackageInfo(&'a mut PackageInfoSubstate),
@@ -1468,6 +1545,7 @@ pub enum SubstateRefMut<'a> {
     NonFungibleResourceManager(&'a mut NonFungibleResourceManagerSubstate),
     EpochManager(&'a mut EpochManagerSubstate),
     ValidatorSet(&'a mut ValidatorSetSubstate),
+    FeeTable(&'a mut FeeTable),
     Validator(&'a mut ValidatorSubstate),
     CurrentTimeRoundedToMinutes(&'a mut ClockSubstate),
     MethodAccessRules(&'a mut MethodAccessRulesSubstate),
@@ -1535,6 +1613,15 @@ impl<'a> From<SubstateRefMut<'a>> for &'a mut ComponentStateSubstate {
     }
 }
 
+impl<'a> From<SubstateRefMut<'a>> for &'a mut ComponentStateVersionSubstate {
+    fn from(value: SubstateRefMut<'a>) -> Self {
+        match value {
+            SubstateRefMut::ComponentStateVersion(value) => value,
+            _ => panic!("Not a component state version"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRefMut<'a>> for &'a mut ComponentRoyaltyConfigSubstate {
     fn from(value: SubstateRefMut<'a>) -> Self {
         match value {
@@ -1589,6 +1676,15 @@ impl<'a> From<SubstateRefMut<'a>> for &'a mut ValidatorSetSubstate {
     }
 }
 
+impl<'a> From<SubstateRefMut<'a>> for &'a mut FeeTable {
+    fn from(value: SubstateRefMut<'a>) -> Self {
+        match value {
+            SubstateRefMut::FeeTable(value) => value,
+            _ => panic!("Not a fee table"),
+        }
+    }
+}
+
 impl<'a> From<SubstateRefMut<'a>> for &'a mut ClockSubstate {
     fn from(value: SubstateRefMut<'a>) -> Self {
         match value {
@@ -0,0 +1,222 @@
+use crate::kernel::interpreters::ScryptoInterpreter;
+use crate::kernel::track::SubstateFootprint;
+use crate::ledger::{
+    merge_kv_store_keys, OutputValue, ReadableSubstateStore, WriteableSubstateStore,
+};
+use crate::system::node_substates::PersistedSubstate;
+use crate::transaction::*;
+use crate::types::*;
+use crate::wasm::*;
+use transaction::model::*;
+
+pub struct BatchExecutionResult {
+    /// The receipts of the transactions, in the order they were given
+    pub receipts: Vec<TransactionReceipt>,
+    /// Indices of the transactions which conflicted with an earlier transaction of the batch and
+    /// had to be re-executed serially
+    pub reexecuted: Vec<usize>,
+}
+
+/// The base store with the state updates of the already validated transactions of a batch on top.
+struct BatchStagedStore<'s, S: ReadableSubstateStore> {
+    base: &'s S,
+    /// The substates put (or removed, if `None`) by the validated transactions
+    up_substates: BTreeMap<SubstateId, Option<OutputValue>>,
+}
+
+impl<'s, S: ReadableSubstateStore> BatchStagedStore<'s, S> {
+    fn new(base: &'s S) -> Self {
+        Self {
+            base,
+            up_substates: BTreeMap::new(),
+        }
+    }
+
+    fn has_written_any_of(&self, footprint: &SubstateFootprint) -> bool {
+        footprint
+            .reads
+            .iter()
+            .any(|substate_id| self.up_substates.contains_key(substate_id))
+            || self
+                .up_substates
+                .keys()
+                .any(|SubstateId(node_id, ..)| footprint.listed_nodes.contains(node_id))
+    }
+
+    fn apply(&mut self, receipt: &TransactionReceipt) {
+        if let TransactionResult::Commit(commit) = &receipt.result {
+            let state_updates = &commit.state_updates;
+            self.up_substates.extend(
+                state_updates
+                    .up_substates
+                    .iter()
+                    .map(|(substate_id, output)| (substate_id.clone(), Some(output.clone()))),
+            );
+            self.up_substates.extend(
+                state_updates
+                    .removed_substate_ids()
+                    .into_iter()
+                    .map(|substate_id| (substate_id.clone(), None)),
+            );
+        }
+    }
+}
+
+impl<'s, S: ReadableSubstateStore> ReadableSubstateStore for BatchStagedStore<'s, S> {
+    fn get_substate(&self, substate_id: &SubstateId) -> Option<OutputValue> {
+        match self.up_substates.get(substate_id) {
+            Some(output) => output.clone(),
+            None => self.base.get_substate(substate_id),
+        }
+    }
+
+    fn list_kv_store_keys(
+        &self,
+        kv_store_id: &KeyValueStoreId,
+        from: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<Vec<u8>> {
+        let overlay = self
+            .up_substates
+            .iter()
+            .filter_map(|(substate_id, output)| match substate_id {
+                SubstateId(
+                    RENodeId::KeyValueStore(id),
+                    NodeModuleId::SELF,
+                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+                ) if id == kv_store_id => {
+                    let present = matches!(
+                        output,
+                        Some(OutputValue {
+                            substate: PersistedSubstate::KeyValueStoreEntry(Some(..)),
+                            ..
+                        })
+                    );
+                    Some((key.clone(), present))
+                }
+                _ => None,
+            })
+            .collect();
+        merge_kv_store_keys(
+            |from, limit| self.base.list_kv_store_keys(kv_store_id, from, limit),
+            overlay,
+            from,
+            limit,
+        )
+    }
+}
+
+/// Executes the given transactions as if they were executed one after another (each one seeing
+/// the state updates of the previous ones), without committing anything.
+///
+/// All transactions are first executed optimistically in parallel against the given store. The
+/// `SubstateFootprint` of each receipt is then checked (in order) against the substates written
+/// by the earlier transactions of the batch: a transaction which has read any of them (or listed
+/// the entries of a key-value store any of them belongs to) is re-executed on top of these writes.
+/// This makes the receipts equal to executing the transactions one after another, with the same
+/// `ExecutionConfig`.
+///
+/// Unless `ExecutionConfig::skip_unchanged_substates` is turned on, every substate locked by a
+/// transaction counts as written, so that e.g. any two calls to the same package conflict (through
+/// its royalty substate) and most of the batch ends up being re-executed serially.
+pub fn execute_transaction_batch<S: ReadableSubstateStore + Sync, W: WasmEngine + Sync>(
+    substate_store: &S,
+    scrypto_interpreter: &ScryptoInterpreter<W>,
+    fee_reserve_config: &FeeReserveConfig,
+    execution_config: &ExecutionConfig,
+    transactions: &[Executable],
+) -> BatchExecutionResult {
+    let thread_count = std::thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1);
+    let chunk_size = ((transactions.len() + thread_count - 1) / thread_count).max(1);
+
+    // Optimistic phase
+    let optimistic_receipts: Vec<TransactionReceipt> = std::thread::scope(|scope| {
+        let handles: Vec<_> = transactions
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|transaction| {
+                            execute_transaction(
+                                substate_store,
+                                scrypto_interpreter,
+                                fee_reserve_config,
+                                execution_config,
+                                transaction,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    });
+
+    // Validation phase
+    let mut staged_store = BatchStagedStore::new(substate_store);
+    let mut receipts = Vec::with_capacity(transactions.len());
+    let mut reexecuted = Vec::new();
+    for (index, (transaction, receipt)) in transactions
+        .iter()
+        .zip(optimistic_receipts.into_iter())
+        .enumerate()
+    {
+        let receipt =
+            if staged_store.has_written_any_of(&receipt.execution_trace.substate_footprint) {
+                reexecuted.push(index);
+                execute_transaction(
+                    &staged_store,
+                    scrypto_interpreter,
+                    fee_reserve_config,
+                    execution_config,
+                    transaction,
+                )
+            } else {
+                receipt
+            };
+        staged_store.apply(&receipt);
+        receipts.push(receipt);
+    }
+
+    BatchExecutionResult {
+        receipts,
+        reexecuted,
+    }
+}
+
+/// Executes the given transactions using `execute_transaction_batch()` and commits the results,
+/// in order.
+pub fn execute_and_commit_transaction_batch<
+    S: ReadableSubstateStore + WriteableSubstateStore + Sync,
+    W: WasmEngine + Sync,
+>(
+    substate_store: &mut S,
+    scrypto_interpreter: &ScryptoInterpreter<W>,
+    fee_reserve_config: &FeeReserveConfig,
+    execution_config: &ExecutionConfig,
+    transactions: &[Executable],
+) -> BatchExecutionResult {
+    let result = execute_transaction_batch(
+        substate_store,
+        scrypto_interpreter,
+        fee_reserve_config,
+        execution_config,
+        transactions,
+    );
+    for receipt in &result.receipts {
+        if let TransactionResult::Commit(commit) = &receipt.result {
+            commit.state_updates.commit(substate_store);
+        }
+    }
+    result
+}
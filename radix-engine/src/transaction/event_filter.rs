@@ -0,0 +1,262 @@
+use crate::ledger::*;
+use crate::system::node_modules::type_info::TypeInfoSubstate;
+use crate::transaction::CommitResult;
+use crate::types::*;
+use radix_engine_interface::api::node_modules::auth::ACCESS_RULES_BLUEPRINT;
+use radix_engine_interface::api::node_modules::metadata::METADATA_BLUEPRINT;
+use radix_engine_interface::api::node_modules::royalty::COMPONENT_ROYALTY_BLUEPRINT;
+use radix_engine_interface::schema::BlueprintSchema;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventDecodingError {
+    /// The type info of the emitter could not be found, e.g. because the emitter was never
+    /// committed
+    EmitterNotFound(RENodeId),
+    /// The emitter is not an object, and therefore has no blueprint
+    EmitterHasNoBlueprint(RENodeId),
+    PackageNotFound(PackageAddress),
+    BlueprintNotFound {
+        package_address: PackageAddress,
+        blueprint_name: String,
+    },
+    /// The event type is not registered in the schema of the blueprint
+    EventNotFound {
+        package_address: PackageAddress,
+        blueprint_name: String,
+        local_type_index: LocalTypeIndex,
+    },
+    EventSchemaNotMatch(String),
+    DecodeError(DecodeError),
+}
+
+/// The type of an event, resolved against the schema of the blueprint which emitted it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedEventType {
+    pub package_address: PackageAddress,
+    pub blueprint_name: String,
+    pub event_name: String,
+    pub local_type_index: LocalTypeIndex,
+}
+
+/// Selects the application events of a transaction. All the criteria that are set must match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    pub emitter: Option<RENodeId>,
+    pub blueprint: Option<(PackageAddress, String)>,
+    pub event_name: Option<String>,
+}
+
+impl EventFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only selects the events emitted by the given node (the object for method emitters, or
+    /// the package for function emitters).
+    pub fn emitter(mut self, node_id: RENodeId) -> Self {
+        self.emitter = Some(node_id);
+        self
+    }
+
+    /// Only selects the events emitted by the given blueprint.
+    pub fn blueprint(mut self, package_address: PackageAddress, blueprint_name: &str) -> Self {
+        self.blueprint = Some((package_address, blueprint_name.to_string()));
+        self
+    }
+
+    /// Only selects the events with the given name, as registered in the blueprint schema.
+    pub fn event_name(mut self, event_name: &str) -> Self {
+        self.event_name = Some(event_name.to_string());
+        self
+    }
+
+    fn matches(&self, event_type_identifier: &EventTypeIdentifier) -> bool {
+        match &self.emitter {
+            Some(node_id) => event_type_identifier.0.node_id() == node_id,
+            None => true,
+        }
+    }
+
+    fn matches_resolved(&self, resolved: &ResolvedEventType) -> bool {
+        let blueprint_matches = match &self.blueprint {
+            Some((package_address, blueprint_name)) => {
+                resolved.package_address == *package_address
+                    && resolved.blueprint_name == *blueprint_name
+            }
+            None => true,
+        };
+        let event_name_matches = match &self.event_name {
+            Some(event_name) => resolved.event_name == *event_name,
+            None => true,
+        };
+        blueprint_matches && event_name_matches
+    }
+}
+
+/// Returns the blueprint which emitted an event, looking up the type info of method emitters in
+/// the given store.
+pub fn resolve_event_blueprint<S: ReadableSubstateStore>(
+    substate_store: &S,
+    emitter: &Emitter,
+) -> Result<(PackageAddress, String), EventDecodingError> {
+    match emitter {
+        Emitter::Method(node_id, node_module_id) => match node_module_id {
+            NodeModuleId::AccessRules | NodeModuleId::AccessRules1 => {
+                Ok((ACCESS_RULES_PACKAGE, ACCESS_RULES_BLUEPRINT.to_string()))
+            }
+            NodeModuleId::ComponentRoyalty => {
+                Ok((ROYALTY_PACKAGE, COMPONENT_ROYALTY_BLUEPRINT.to_string()))
+            }
+            NodeModuleId::Metadata => Ok((METADATA_PACKAGE, METADATA_BLUEPRINT.to_string())),
+            NodeModuleId::SELF => {
+                let type_info = substate_store
+                    .get_substate(&SubstateId(
+                        *node_id,
+                        NodeModuleId::TypeInfo,
+                        SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
+                    ))
+                    .ok_or(EventDecodingError::EmitterNotFound(*node_id))?
+                    .substate
+                    .type_info()
+                    .clone();
+                match type_info {
+                    TypeInfoSubstate::Object {
+                        package_address,
+                        blueprint_name,
+                        ..
+                    } => Ok((package_address, blueprint_name)),
+                    TypeInfoSubstate::KeyValueStore(..) => {
+                        Err(EventDecodingError::EmitterHasNoBlueprint(*node_id))
+                    }
+                }
+            }
+            NodeModuleId::TypeInfo => Err(EventDecodingError::EmitterHasNoBlueprint(*node_id)),
+        },
+        Emitter::Function(node_id, _, blueprint_name) => match node_id {
+            RENodeId::GlobalObject(Address::Package(package_address)) => {
+                Ok((*package_address, blueprint_name.clone()))
+            }
+            _ => Err(EventDecodingError::EmitterHasNoBlueprint(*node_id)),
+        },
+    }
+}
+
+/// Reads the schema of a blueprint from the package info held by the given store.
+pub fn read_blueprint_schema<S: ReadableSubstateStore>(
+    substate_store: &S,
+    package_address: PackageAddress,
+    blueprint_name: &str,
+) -> Result<BlueprintSchema, EventDecodingError> {
+    let output = substate_store
+        .get_substate(&SubstateId(
+            RENodeId::GlobalObject(Address::Package(package_address)),
+            NodeModuleId::SELF,
+            SubstateOffset::Package(PackageOffset::Info),
+        ))
+        .ok_or(EventDecodingError::PackageNotFound(package_address))?;
+    output
+        .substate
+        .package_info()
+        .schema
+        .blueprints
+        .get(blueprint_name)
+        .cloned()
+        .ok_or_else(|| EventDecodingError::BlueprintNotFound {
+            package_address,
+            blueprint_name: blueprint_name.to_string(),
+        })
+}
+
+/// Resolves the type of an event, returning it along with the schema of the emitting blueprint.
+pub fn resolve_event_type<S: ReadableSubstateStore>(
+    substate_store: &S,
+    event_type_identifier: &EventTypeIdentifier,
+) -> Result<(ResolvedEventType, BlueprintSchema), EventDecodingError> {
+    let EventTypeIdentifier(emitter, local_type_index) = event_type_identifier;
+    let (package_address, blueprint_name) = resolve_event_blueprint(substate_store, emitter)?;
+    let blueprint_schema = read_blueprint_schema(substate_store, package_address, &blueprint_name)?;
+    let event_name = blueprint_schema
+        .event_schema
+        .iter()
+        .find(|(_, index)| *index == local_type_index)
+        .map(|(name, _)| name.clone())
+        .ok_or_else(|| EventDecodingError::EventNotFound {
+            package_address,
+            blueprint_name: blueprint_name.clone(),
+            local_type_index: *local_type_index,
+        })?;
+
+    Ok((
+        ResolvedEventType {
+            package_address,
+            blueprint_name,
+            event_name,
+            local_type_index: *local_type_index,
+        },
+        blueprint_schema,
+    ))
+}
+
+impl CommitResult {
+    /// Returns the application events selected by the filter, in emission order, along with
+    /// their resolved types.
+    ///
+    /// The emitters are resolved against the given store, which must hold the state of the
+    /// ledger after this transaction has been committed.
+    pub fn filter_events<S: ReadableSubstateStore>(
+        &self,
+        substate_store: &S,
+        filter: &EventFilter,
+    ) -> Result<Vec<(EventTypeIdentifier, ResolvedEventType, &[u8])>, EventDecodingError> {
+        let mut events = Vec::new();
+        for (event_type_identifier, event_data) in &self.application_events {
+            if !filter.matches(event_type_identifier) {
+                continue;
+            }
+            let (resolved, _) = resolve_event_type(substate_store, event_type_identifier)?;
+            if filter.matches_resolved(&resolved) {
+                events.push((
+                    event_type_identifier.clone(),
+                    resolved,
+                    event_data.as_slice(),
+                ));
+            }
+        }
+        Ok(events)
+    }
+
+    /// Returns the events of type `T` selected by the filter, decoded after their payload has
+    /// been validated against the schema of the emitting blueprint.
+    ///
+    /// The events are matched by the name `T` is registered under (see [`ScryptoEvent`]).
+    pub fn typed_events<T: ScryptoEvent, S: ReadableSubstateStore>(
+        &self,
+        substate_store: &S,
+        filter: &EventFilter,
+    ) -> Result<Vec<(EventTypeIdentifier, T)>, EventDecodingError> {
+        let filter = filter.clone().event_name(T::event_name());
+
+        let mut events = Vec::new();
+        for (event_type_identifier, event_data) in &self.application_events {
+            if !filter.matches(event_type_identifier) {
+                continue;
+            }
+            let (resolved, blueprint_schema) =
+                resolve_event_type(substate_store, event_type_identifier)?;
+            if !filter.matches_resolved(&resolved) {
+                continue;
+            }
+            validate_payload_against_schema(
+                event_data,
+                &blueprint_schema.schema,
+                resolved.local_type_index,
+            )
+            .map_err(|err| {
+                EventDecodingError::EventSchemaNotMatch(err.error_message(&blueprint_schema.schema))
+            })?;
+            let event = scrypto_decode::<T>(event_data).map_err(EventDecodingError::DecodeError)?;
+            events.push((event_type_identifier.clone(), event));
+        }
+        Ok(events)
+    }
+}
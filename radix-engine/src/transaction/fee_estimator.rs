@@ -0,0 +1,217 @@
+use crate::kernel::interpreters::ScryptoInterpreter;
+use crate::ledger::*;
+use crate::system::kernel_modules::costing::{u128_to_decimal, FeeTable, RoyaltyRecipient};
+use crate::transaction::*;
+use crate::types::*;
+use crate::wasm::WasmEngine;
+use radix_engine_constants::*;
+use radix_engine_interface::network::NetworkDefinition;
+use transaction::bls12381::Bls12381G2Signature;
+use transaction::ecdsa_secp256k1::EcdsaSecp256k1Signature;
+use transaction::eddsa_ed25519::EddsaEd25519Signature;
+use transaction::model::*;
+use transaction::validation::IntentHashManager;
+
+pub struct FeeEstimationConfig {
+    /// The cost unit price the transaction is going to be submitted with
+    pub cost_unit_price: u128,
+    /// The tip percentage the transaction is going to be submitted with
+    pub tip_percentage: u16,
+    /// The percentage added on top of the estimated costs, in case the transaction ends up
+    /// doing more work than when previewed (e.g. because of state changes in the meantime)
+    pub safety_margin_percentage: u16,
+}
+
+impl Default for FeeEstimationConfig {
+    fn default() -> Self {
+        Self {
+            cost_unit_price: DEFAULT_COST_UNIT_PRICE,
+            tip_percentage: 0,
+            safety_margin_percentage: 20,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FeeEstimate {
+    /// The receipt of the previewed transaction
+    pub receipt: TransactionReceipt,
+    /// The number of cost units consumed by the execution, including the base cost and the
+    /// signature verification cost (but not the payload cost, as previews have no payload)
+    pub execution_cost_sum: u32,
+    /// The cost units paid as royalties, by recipient
+    pub royalty_cost_breakdown: BTreeMap<RoyaltyRecipient, u32>,
+    /// The cost units charged for the size of the notarized transaction
+    pub payload_cost: u32,
+    /// The cost units charged for the verification of the signatures (included in
+    /// `execution_cost_sum`)
+    pub signature_cost: u32,
+    /// The storage fee of the net state growth, in XRD (see `FeeSummary::total_storage_cost_xrd`)
+    pub storage_cost_xrd: Decimal,
+    /// The cost unit limit to submit the transaction with
+    pub recommended_cost_unit_limit: u32,
+    /// The amount of XRD to lock for paying the fees
+    pub recommended_lock_fee_xrd: Decimal,
+}
+
+impl FeeEstimate {
+    pub fn royalty_cost_sum(&self) -> u32 {
+        self.royalty_cost_breakdown.values().sum()
+    }
+}
+
+#[derive(Debug)]
+pub enum FeeEstimationError {
+    /// At least one signer (which is used as the notary) is required
+    NoSigners,
+    PreviewError(PreviewError),
+    TransactionNotCommittedSuccessfully(Box<TransactionReceipt>),
+}
+
+/// Estimates the fees of a transaction, by previewing it (without any fee being charged) on top
+/// of the given store.
+///
+/// The first of the signers is assumed to be the notary (and a signatory).
+pub fn estimate_fee<S: ReadableSubstateStore, W: WasmEngine, IHM: IntentHashManager>(
+    substate_store: &S,
+    scrypto_interpreter: &ScryptoInterpreter<W>,
+    intent_hash_manager: &IHM,
+    network: &NetworkDefinition,
+    manifest: TransactionManifest,
+    signer_public_keys: Vec<PublicKey>,
+    config: &FeeEstimationConfig,
+) -> Result<FeeEstimate, FeeEstimationError> {
+    let notary_public_key = *signer_public_keys
+        .first()
+        .ok_or(FeeEstimationError::NoSigners)?;
+    let intent = TransactionIntent {
+        header: TransactionHeader {
+            version: TRANSACTION_VERSION_V1,
+            network_id: network.id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 1,
+            nonce: 0,
+            notary_public_key,
+            notary_as_signatory: true,
+            cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
+            tip_percentage: config.tip_percentage,
+        },
+        manifest,
+        fee_sponsor: None,
+    };
+    let payload_size = estimate_payload_size(&intent, &signer_public_keys);
+
+    let preview_intent = PreviewIntent {
+        intent,
+        signer_public_keys,
+        flags: PreviewFlags {
+            unlimited_loan: true,
+            assume_all_signature_proofs: false,
+            permit_duplicate_intent_hash: true,
+            permit_invalid_header_epoch: true,
+        },
+    };
+    let signature_count = preview_intent.signer_public_keys.len();
+    let receipt = execute_preview(
+        substate_store,
+        scrypto_interpreter,
+        intent_hash_manager,
+        network,
+        preview_intent,
+    )
+    .map_err(FeeEstimationError::PreviewError)?
+    .receipt;
+
+    let fee_summary = match &receipt.result {
+        TransactionResult::Commit(CommitResult {
+            outcome: TransactionOutcome::Success(..),
+            fee_summary,
+            ..
+        }) => fee_summary.clone(),
+        _ => {
+            return Err(FeeEstimationError::TransactionNotCommittedSuccessfully(
+                Box::new(receipt),
+            ))
+        }
+    };
+
+    let fee_table = read_fee_table(substate_store);
+    let payload_cost = fee_table
+        .tx_payload_cost_per_byte()
+        .saturating_mul(payload_size as u32);
+    let signature_cost = fee_table
+        .tx_signature_verification_per_sig()
+        .saturating_mul(signature_count as u32);
+    let royalty_cost_sum: u32 = fee_summary.royalty_cost_unit_breakdown.values().sum();
+    let storage_cost_units =
+        fee_summary.net_storage_bytes.max(0) as u128 * fee_table.storage_cost_per_byte() as u128;
+
+    let with_margin = |cost: u128| cost * (100 + config.safety_margin_percentage as u128) / 100;
+    let execution_cost = fee_summary.execution_cost_sum as u128 + payload_cost as u128;
+    let recommended_cost_unit_limit =
+        u32::try_from(with_margin(execution_cost + royalty_cost_sum as u128)).unwrap_or(u32::MAX);
+    let recommended_lock_fee_xrd = u128_to_decimal(
+        config.cost_unit_price
+            * with_margin(
+                execution_cost * (100 + config.tip_percentage as u128) / 100
+                    + royalty_cost_sum as u128
+                    + storage_cost_units,
+            ),
+    );
+
+    Ok(FeeEstimate {
+        execution_cost_sum: fee_summary.execution_cost_sum,
+        royalty_cost_breakdown: fee_summary.royalty_cost_unit_breakdown,
+        payload_cost,
+        signature_cost,
+        storage_cost_xrd: u128_to_decimal(config.cost_unit_price * storage_cost_units),
+        recommended_cost_unit_limit,
+        recommended_lock_fee_xrd,
+        receipt,
+    })
+}
+
+/// Returns the size of the notarized transaction, with placeholder signatures of the right size.
+fn estimate_payload_size(intent: &TransactionIntent, signer_public_keys: &[PublicKey]) -> usize {
+    let placeholder_signature = |public_key: &PublicKey| match public_key {
+        PublicKey::EcdsaSecp256k1(..) => SignatureWithPublicKey::EcdsaSecp256k1 {
+            signature: EcdsaSecp256k1Signature([0u8; EcdsaSecp256k1Signature::LENGTH]),
+        },
+        PublicKey::EddsaEd25519(public_key) => SignatureWithPublicKey::EddsaEd25519 {
+            public_key: *public_key,
+            signature: EddsaEd25519Signature([0u8; EddsaEd25519Signature::LENGTH]),
+        },
+        PublicKey::Bls12381(public_key) => SignatureWithPublicKey::Bls12381 {
+            public_key: *public_key,
+            signature: Bls12381G2Signature([0u8; Bls12381G2Signature::LENGTH]),
+        },
+    };
+    let notarized_transaction = NotarizedTransaction {
+        signed_intent: SignedTransactionIntent {
+            intent: intent.clone(),
+            intent_signatures: signer_public_keys
+                .iter()
+                .skip(1)
+                .map(placeholder_signature)
+                .collect(),
+            sponsor_signature: None,
+        },
+        notary_signature: placeholder_signature(&intent.header.notary_public_key).signature(),
+    };
+    notarized_transaction
+        .to_bytes()
+        .expect("Failed to encode the transaction")
+        .len()
+}
+
+/// Returns the fee table in effect (see `FeeTable`).
+fn read_fee_table<S: ReadableSubstateStore>(substate_store: &S) -> FeeTable {
+    substate_store
+        .get_substate(&SubstateId(
+            RENodeId::GlobalObject(EPOCH_MANAGER.into()),
+            NodeModuleId::SELF,
+            SubstateOffset::EpochManager(EpochManagerOffset::FeeTable),
+        ))
+        .map(|output| output.substate.fee_table().clone())
+        .unwrap_or_else(FeeTable::new)
+}
@@ -0,0 +1,79 @@
+use crate::kernel::actor::{Actor, ActorIdentifier};
+use crate::system::kernel_modules::logger::LogRecord;
+use crate::types::*;
+use radix_engine_interface::address::AddressDisplayContext;
+use serde_json::{json, Map, Value};
+use std::io::Write;
+use utils::ContextualDisplay;
+
+/// Writes the application logs of transactions as JSON lines: one JSON object per log record,
+/// tagged with the hash of the transaction which emitted it.
+pub struct JsonLinesLogSink<W: Write> {
+    writer: W,
+    encoder: Option<Bech32Encoder>,
+}
+
+impl<W: Write> JsonLinesLogSink<W> {
+    /// Creates a sink which displays addresses as hex.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            encoder: None,
+        }
+    }
+
+    /// Creates a sink which displays addresses in Bech32.
+    pub fn with_encoder(writer: W, encoder: Bech32Encoder) -> Self {
+        Self {
+            writer,
+            encoder: Some(encoder),
+        }
+    }
+
+    pub fn write_logs(
+        &mut self,
+        transaction_hash: &Hash,
+        logs: &[LogRecord],
+    ) -> std::io::Result<()> {
+        for record in logs {
+            let line = self.to_json(transaction_hash, record);
+            writeln!(self.writer, "{}", line)?;
+        }
+        self.writer.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn to_json(&self, transaction_hash: &Hash, record: &LogRecord) -> Value {
+        let mut fields = Map::new();
+        for (key, value) in &record.fields {
+            fields.insert(key.clone(), Value::String(value.clone()));
+        }
+        json!({
+            "transaction_hash": transaction_hash.to_string(),
+            "level": record.level.to_string(),
+            "message": record.message,
+            "fields": fields,
+            "actor": record.actor.as_ref().map(|actor| self.actor_to_json(actor)),
+            "depth": record.depth,
+        })
+    }
+
+    fn actor_to_json(&self, actor: &Actor) -> Value {
+        let context = AddressDisplayContext::from(self.encoder.as_ref());
+        let receiver = match &actor.identifier {
+            ActorIdentifier::Method(MethodIdentifier(node_id, ..)) => {
+                Some(node_id.display(context).to_string())
+            }
+            ActorIdentifier::Function(..) => None,
+        };
+        json!({
+            "package_address": actor.fn_identifier.package_address.display(context).to_string(),
+            "blueprint_name": actor.fn_identifier.blueprint_name,
+            "ident": actor.fn_identifier.ident,
+            "receiver": receiver,
+        })
+    }
+}
@@ -1,8 +1,20 @@
+#[cfg(feature = "std")]
+mod batch_executor;
+mod event_filter;
+mod fee_estimator;
+#[cfg(all(feature = "std", feature = "serde"))]
+mod log_sink;
 mod preview_executor;
 mod reference_extractor; // TODO: merge with TransactionValidator
 mod transaction_executor;
 mod transaction_receipt;
 
+#[cfg(feature = "std")]
+pub use batch_executor::*;
+pub use event_filter::*;
+pub use fee_estimator::*;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub use log_sink::*;
 pub use preview_executor::*;
 pub use reference_extractor::*;
 pub use transaction_executor::*;
@@ -1,4 +1,5 @@
 use crate::kernel::interpreters::ScryptoInterpreter;
+use crate::kernel::track::SubstateFootprintByNode;
 use crate::ledger::*;
 use crate::transaction::TransactionReceipt;
 use crate::transaction::*;
@@ -14,6 +15,8 @@ use transaction::validation::ValidationConfig;
 pub struct PreviewResult {
     pub intent: PreviewIntent,
     pub receipt: TransactionReceipt,
+    /// All the substates read or written by the transaction, labelled read-only or mutable
+    pub substate_footprint: SubstateFootprintByNode,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,8 +49,11 @@ pub fn execute_preview<S: ReadableSubstateStore, W: WasmEngine, IHM: IntentHashM
         )
     };
 
+    let substate_footprint = receipt.execution_trace.substate_footprint.by_node();
+
     Ok(PreviewResult {
         intent: preview_intent,
         receipt,
+        substate_footprint,
     })
 }
@@ -18,6 +18,7 @@ pub fn extract_refs_from_manifest(
     global_references.insert(CLOCK.into());
     global_references.insert(ECDSA_SECP256K1_TOKEN.into());
     global_references.insert(EDDSA_ED25519_TOKEN.into());
+    global_references.insert(BLS12381_TOKEN.into());
 
     (global_references, local_references)
 }
@@ -99,7 +100,10 @@ pub fn extract_refs_from_instruction(
             local_references.insert(InternalRef(vault_id.clone()));
         }
 
-        Instruction::SetPackageRoyaltyConfig {
+        Instruction::UpgradePackage {
+            package_address, ..
+        }
+        | Instruction::SetPackageRoyaltyConfig {
             package_address, ..
         }
         | Instruction::ClaimPackageRoyalty {
@@ -2,8 +2,9 @@ use crate::errors::*;
 use crate::kernel::id_allocator::IdAllocator;
 use crate::kernel::interpreters::ScryptoInterpreter;
 use crate::kernel::kernel::Kernel;
+use crate::kernel::kernel_api::KernelInternalApi;
 use crate::kernel::module_mixer::KernelModuleMixer;
-use crate::kernel::track::{PreExecutionError, Track};
+use crate::kernel::track::{PreExecutionError, Track, TrackError};
 use crate::ledger::{ReadableSubstateStore, WriteableSubstateStore};
 use crate::system::kernel_modules::costing::*;
 use crate::system::kernel_modules::execution_trace::calculate_resource_changes;
@@ -11,7 +12,9 @@ use crate::transaction::*;
 use crate::types::*;
 use crate::wasm::*;
 use radix_engine_constants::*;
+use radix_engine_interface::api::substate_api::LockFlags;
 use radix_engine_interface::api::ClientObjectApi;
+use radix_engine_interface::blueprints::account::{AccountLockFeeInput, ACCOUNT_LOCK_FEE_IDENT};
 use radix_engine_interface::blueprints::transaction_processor::{
     InstructionOutput, TransactionProcessorRunInput,
 };
@@ -41,10 +44,12 @@ impl FeeReserveConfig {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct ExecutionConfig {
     pub genesis: bool,
     pub kernel_trace: bool,
     pub execution_trace: Option<usize>,
+    pub cost_profiling: bool,
     pub max_call_depth: usize,
     pub abort_when_loan_repaid: bool,
     pub max_wasm_mem_per_transaction: usize,
@@ -53,6 +58,13 @@ pub struct ExecutionConfig {
     pub max_substate_writes_per_transaction: usize,
     pub max_substate_size: usize,
     pub max_invoke_input_size: usize,
+    /// The least severe level of the application logs to record (see `LoggerModule`); the more
+    /// verbose logs are dropped without costing anything, e.g. `Level::Info` in production
+    pub min_log_level: Level,
+    /// Whether the existing substates left unchanged by a transaction are omitted from its state
+    /// updates (instead of being re-written with a new version); batch execution should turn this
+    /// on so that transactions which merely lock the same substates don't conflict
+    pub skip_unchanged_substates: bool,
 }
 
 impl Default for ExecutionConfig {
@@ -67,6 +79,7 @@ impl ExecutionConfig {
             genesis: false,
             kernel_trace: false,
             execution_trace: Some(1),
+            cost_profiling: false,
             max_call_depth: DEFAULT_MAX_CALL_DEPTH,
             abort_when_loan_repaid: false,
             max_wasm_mem_per_transaction: DEFAULT_MAX_WASM_MEM_PER_TRANSACTION,
@@ -75,6 +88,8 @@ impl ExecutionConfig {
             max_substate_writes_per_transaction: DEFAULT_MAX_SUBSTATE_WRITES_PER_TRANSACTION,
             max_substate_size: DEFAULT_MAX_SUBSTATE_SIZE,
             max_invoke_input_size: DEFAULT_MAX_INVOKE_INPUT_SIZE,
+            min_log_level: Level::Trace,
+            skip_unchanged_substates: false,
         }
     }
 
@@ -90,6 +105,21 @@ impl ExecutionConfig {
         self
     }
 
+    pub fn with_cost_profiling(mut self, cost_profiling: bool) -> Self {
+        self.cost_profiling = cost_profiling;
+        self
+    }
+
+    pub fn with_min_log_level(mut self, min_log_level: Level) -> Self {
+        self.min_log_level = min_log_level;
+        self
+    }
+
+    pub fn with_skip_unchanged_substates(mut self, skip_unchanged_substates: bool) -> Self {
+        self.skip_unchanged_substates = skip_unchanged_substates;
+        self
+    }
+
     pub fn up_to_loan_repayment() -> Self {
         Self {
             abort_when_loan_repaid: true,
@@ -150,7 +180,27 @@ where
             FeePayment::NoFee => SystemLoanFeeReserve::no_fee(),
         };
 
-        self.execute_with_fee_reserve(transaction, execution_config, fee_reserve, FeeTable::new())
+        self.execute_with_fee_reserve(transaction, execution_config, fee_reserve)
+    }
+
+    /// Reads the fee table in effect from the ledger (or falls back to the built-in one, if the
+    /// epoch manager does not exist yet, i.e. during genesis).
+    fn load_fee_table(track: &mut Track) -> FeeTable {
+        let node_id = RENodeId::GlobalObject(EPOCH_MANAGER.into());
+        let module_id = NodeModuleId::SELF;
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::FeeTable);
+        let substate_id = SubstateId(node_id, module_id, offset.clone());
+
+        match track.acquire_lock(substate_id.clone(), LockFlags::read_only()) {
+            Ok(()) => {
+                let fee_table: &FeeTable = track.get_substate(node_id, module_id, &offset).into();
+                let fee_table = fee_table.clone();
+                track.release_lock(substate_id, false).unwrap();
+                fee_table
+            }
+            Err(TrackError::NotFound(..)) => FeeTable::new(),
+            Err(e) => panic!("Failed to load the fee table: {:?}", e),
+        }
     }
 
     fn apply_pre_execution_costs(
@@ -170,7 +220,8 @@ where
             .and_then(|()| {
                 fee_reserve.consume_deferred(
                     fee_table.tx_signature_verification_per_sig(),
-                    executable.auth_zone_params().initial_proofs.len(),
+                    executable.auth_zone_params().initial_proofs.len()
+                        + executable.fee_sponsor().map_or(0, |_| 1),
                     CostingReason::TxSignatureVerification,
                 )
             });
@@ -189,7 +240,6 @@ where
         executable: &Executable,
         execution_config: &ExecutionConfig,
         mut fee_reserve: SystemLoanFeeReserve,
-        fee_table: FeeTable,
     ) -> TransactionReceipt {
         let transaction_hash = executable.transaction_hash();
 
@@ -211,6 +261,12 @@ where
         let mut resources_tracker =
             crate::kernel::resources_tracker::ResourcesTracker::start_measurement();
 
+        // Load the fee table
+        let mut track = Track::new(self.substate_store)
+            .with_skip_unchanged_substates(execution_config.skip_unchanged_substates);
+        let fee_table = Self::load_fee_table(&mut track);
+        fee_reserve.set_fee_table(&fee_table);
+
         // Apply pre execution costing
         if !execution_config.genesis {
             let pre_execution_result =
@@ -223,6 +279,8 @@ where
                             execution_traces: vec![],
                             resource_changes: index_map_new(),
                             resources_usage: ResourcesUsage::default(),
+                            substate_footprint: track.discard(),
+                            cost_profile: None,
                         },
                         result: TransactionResult::Reject(RejectResult {
                             error: RejectionError::ErrorBeforeFeeLoanRepaid(
@@ -237,8 +295,7 @@ where
         }
 
         // Execute the instructions
-        let mut track = Track::new(self.substate_store);
-        let (transaction_result, execution_traces, vault_ops) = {
+        let (transaction_result, substate_footprint, execution_traces, vault_ops, cost_profile) = {
             let mut id_allocator = IdAllocator::new(
                 transaction_hash.clone(),
                 executable.pre_allocated_ids().clone(),
@@ -262,26 +319,69 @@ where
             // Initialize
             kernel.initialize().expect("Failed to initialize kernel");
 
+            // Lock the fee from the sponsor's account, with only the sponsor's proof
+            let sponsor_result = match executable.fee_sponsor() {
+                Some(fee_sponsor) => {
+                    let instructions = vec![Instruction::CallMethod {
+                        component_address: fee_sponsor.account,
+                        method_name: ACCOUNT_LOCK_FEE_IDENT.to_string(),
+                        args: to_manifest_value(&AccountLockFeeInput {
+                            amount: fee_sponsor.max_fee,
+                        }),
+                    }];
+                    let (global_references, local_references) =
+                        extract_refs_from_manifest(&instructions);
+                    let auth_zone_params = core::mem::replace(
+                        &mut kernel.kernel_get_module_state().auth.params,
+                        AuthZoneParams {
+                            initial_proofs: fee_sponsor.initial_proofs.clone(),
+                            virtual_resources: BTreeSet::new(),
+                        },
+                    );
+                    let result = kernel
+                        .call_function(
+                            TRANSACTION_PROCESSOR_PACKAGE,
+                            TRANSACTION_PROCESSOR_BLUEPRINT,
+                            TRANSACTION_PROCESSOR_RUN_IDENT,
+                            scrypto_encode(&TransactionProcessorRunInput {
+                                transaction_hash: transaction_hash.clone(),
+                                runtime_validations: Cow::Owned(vec![]),
+                                instructions: Cow::Owned(manifest_encode(&instructions).unwrap()),
+                                blobs: Cow::Owned(vec![]),
+                                global_references,
+                                local_references,
+                            })
+                            .unwrap(),
+                        )
+                        .map(|_| ());
+                    kernel.kernel_get_module_state().auth.params = auth_zone_params;
+                    result
+                }
+                None => Ok(()),
+            };
+
             // Call TransactionProcessor::Run()
             let (global_references, local_references) =
                 extract_refs_from_manifest(executable.instructions());
-            let invoke_result = kernel
-                .call_function(
-                    TRANSACTION_PROCESSOR_PACKAGE,
-                    TRANSACTION_PROCESSOR_BLUEPRINT,
-                    TRANSACTION_PROCESSOR_RUN_IDENT,
-                    scrypto_encode(&TransactionProcessorRunInput {
-                        transaction_hash: transaction_hash.clone(),
-                        runtime_validations: Cow::Borrowed(executable.runtime_validations()),
-                        instructions: Cow::Owned(
-                            manifest_encode(executable.instructions()).unwrap(),
-                        ),
-                        blobs: Cow::Borrowed(executable.blobs()),
-                        global_references,
-                        local_references,
-                    })
-                    .unwrap(),
-                )
+            let invoke_result = sponsor_result
+                .and_then(|()| {
+                    kernel.call_function(
+                        TRANSACTION_PROCESSOR_PACKAGE,
+                        TRANSACTION_PROCESSOR_BLUEPRINT,
+                        TRANSACTION_PROCESSOR_RUN_IDENT,
+                        scrypto_encode(&TransactionProcessorRunInput {
+                            transaction_hash: transaction_hash.clone(),
+                            runtime_validations: Cow::Borrowed(executable.runtime_validations()),
+                            instructions: Cow::Owned(
+                                manifest_encode(executable.instructions()).unwrap(),
+                            ),
+                            blobs: Cow::Borrowed(executable.blobs()),
+                            global_references,
+                            local_references,
+                        })
+                        .unwrap(),
+                    )
+                })
                 .map(|x| scrypto_decode::<Vec<InstructionOutput>>(&x).unwrap());
 
             // Teardown
@@ -290,16 +390,27 @@ where
             let application_events = modules.events.events();
             let application_logs = modules.logger.logs();
             let (execution_traces, vault_ops) = modules.execution_trace.collect_traces();
+            let cost_profile = if execution_config.cost_profiling {
+                Some(modules.cost_profiler.collect_profile())
+            } else {
+                None
+            };
 
             // Finalize track
-            let transaction_result = track.finalize(
+            let (transaction_result, substate_footprint) = track.finalize(
                 invoke_result,
                 fee_reserve,
                 application_events,
                 application_logs,
             );
 
-            (transaction_result, execution_traces, vault_ops)
+            (
+                transaction_result,
+                substate_footprint,
+                execution_traces,
+                vault_ops,
+                cost_profile,
+            )
         };
 
         // Calculate resource changes
@@ -327,6 +438,8 @@ where
                 execution_traces,
                 resource_changes,
                 resources_usage,
+                substate_footprint,
+                cost_profile,
             },
         };
 
@@ -350,6 +463,10 @@ where
                         "{:<30}: {:>10}",
                         "Total Cost Units Consumed", commit.fee_summary.execution_cost_sum
                     );
+                    println!(
+                        "{:<30}: {:>10}",
+                        "Total Cost Units Credited", commit.fee_summary.execution_credit_sum
+                    );
                     println!(
                         "{:<30}: {:>10}",
                         "Cost Unit Limit", commit.fee_summary.cost_unit_limit
@@ -365,9 +482,14 @@ where
                         "Royalty XRD",
                         commit.fee_summary.total_royalty_cost_xrd.to_string()
                     );
+                    println!(
+                        "{:<30}: {:>10}",
+                        "Storage XRD",
+                        commit.fee_summary.total_storage_cost_xrd.to_string()
+                    );
                     println!("{:-^80}", "Application Logs");
-                    for (level, message) in &commit.application_logs {
-                        println!("[{}] {}", level, message);
+                    for record in &commit.application_logs {
+                        println!("[{}] {}", record.level, record.message);
                     }
                 }
                 TransactionResult::Reject(e) => {
@@ -1,10 +1,13 @@
 use crate::blueprints::epoch_manager::{EpochChangeEvent, Validator};
 use crate::errors::*;
+use crate::kernel::track::SubstateFootprint;
 use crate::state_manager::StateDiff;
+use crate::system::kernel_modules::cost_profiler::CostProfile;
 use crate::system::kernel_modules::costing::FeeSummary;
 use crate::system::kernel_modules::execution_trace::{
     ExecutionTrace, ResourceChange, WorktopChange,
 };
+use crate::system::kernel_modules::logger::LogRecord;
 use crate::types::*;
 use colored::*;
 use radix_engine_interface::address::AddressDisplayContext;
@@ -30,6 +33,9 @@ pub struct TransactionExecutionTrace {
     pub execution_traces: Vec<ExecutionTrace>,
     pub resource_changes: IndexMap<usize, Vec<ResourceChange>>,
     pub resources_usage: ResourcesUsage,
+    pub substate_footprint: SubstateFootprint,
+    /// The costs attributed to each call stack, if cost profiling is enabled
+    pub cost_profile: Option<CostProfile>,
 }
 
 impl TransactionExecutionTrace {
@@ -67,10 +73,10 @@ pub struct CommitResult {
     pub fee_summary: FeeSummary,
     pub fee_payments: IndexMap<ObjectId, Decimal>,
     pub application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
-    pub application_logs: Vec<(Level, String)>,
+    pub application_logs: Vec<LogRecord>,
 }
 
-#[derive(Debug, Clone, ScryptoSbor)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct StateUpdateSummary {
     pub new_packages: Vec<PackageAddress>,
     pub new_components: Vec<ComponentAddress>,
@@ -465,10 +471,11 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
         if let TransactionResult::Commit(c) = &result {
             write!(
                 f,
-                "\n{} {} XRD used for execution, {} XRD used for royalty, {} XRD in bad debt",
+                "\n{} {} XRD used for execution, {} XRD used for royalty, {} XRD used for storage, {} XRD in bad debt",
                 "Transaction Fee:".bold().green(),
                 c.fee_summary.total_execution_cost_xrd,
                 c.fee_summary.total_royalty_cost_xrd,
+                c.fee_summary.total_storage_cost_xrd,
                 c.fee_summary.total_bad_debt_xrd,
             )?;
 
@@ -488,8 +495,9 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                 "Logs:".bold().green(),
                 c.application_logs.len()
             )?;
-            for (i, (level, msg)) in c.application_logs.iter().enumerate() {
-                let (l, m) = match level {
+            for (i, record) in c.application_logs.iter().enumerate() {
+                let msg = record.display_message();
+                let (l, m) = match record.level {
                     Level::Error => ("ERROR".red(), msg.red()),
                     Level::Warn => ("WARN".yellow(), msg.yellow()),
                     Level::Info => ("INFO".green(), msg.green()),
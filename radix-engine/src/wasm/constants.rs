@@ -30,6 +30,12 @@ pub const WRITE_SUBSTATE_FUNCTION_ID: usize = 0x42;
 pub const WRITE_SUBSTATE_FUNCTION_NAME: &str = "write_substate";
 pub const DROP_LOCK_FUNCTION_ID: usize = 0x43;
 pub const DROP_LOCK_FUNCTION_NAME: &str = "drop_lock";
+pub const REMOVE_KEY_VALUE_STORE_ENTRY_FUNCTION_ID: usize = 0x44;
+pub const REMOVE_KEY_VALUE_STORE_ENTRY_FUNCTION_NAME: &str = "remove_key_value_store_entry";
+pub const LIST_KEY_VALUE_STORE_KEYS_FUNCTION_ID: usize = 0x45;
+pub const LIST_KEY_VALUE_STORE_KEYS_FUNCTION_NAME: &str = "list_key_value_store_keys";
+pub const COUNT_KEY_VALUE_STORE_ENTRIES_FUNCTION_ID: usize = 0x46;
+pub const COUNT_KEY_VALUE_STORE_ENTRIES_FUNCTION_NAME: &str = "count_key_value_store_entries";
 
 pub const EMIT_EVENT_FUNCTION_ID: usize = 0x50;
 pub const EMIT_EVENT_FUNCTION_NAME: &str = "emit_event";
@@ -41,6 +47,23 @@ pub const GENERATE_UUID_FUNCTION_ID: usize = 0x53;
 pub const GENERATE_UUID_FUNCTION_NAME: &str = "generate_uuid";
 pub const GET_ACTOR_FUNCTION_ID: usize = 0x54;
 pub const GET_ACTOR_FUNCTION_NAME: &str = "get_actor";
+pub const LOG_STRUCTURED_FUNCTION_ID: usize = 0x55;
+pub const LOG_STRUCTURED_FUNCTION_NAME: &str = "log_structured_message";
+
+pub const VERIFY_ECDSA_SECP256K1_FUNCTION_ID: usize = 0x60;
+pub const VERIFY_ECDSA_SECP256K1_FUNCTION_NAME: &str = "verify_ecdsa_secp256k1";
+pub const VERIFY_EDDSA_ED25519_FUNCTION_ID: usize = 0x61;
+pub const VERIFY_EDDSA_ED25519_FUNCTION_NAME: &str = "verify_eddsa_ed25519";
+pub const KECCAK256_HASH_FUNCTION_ID: usize = 0x62;
+pub const KECCAK256_HASH_FUNCTION_NAME: &str = "keccak256_hash";
+pub const SHA256_HASH_FUNCTION_ID: usize = 0x63;
+pub const SHA256_HASH_FUNCTION_NAME: &str = "sha256_hash";
+pub const BLAKE2B_256_HASH_FUNCTION_ID: usize = 0x64;
+pub const BLAKE2B_256_HASH_FUNCTION_NAME: &str = "blake2b_256_hash";
+pub const VERIFY_BLS12381_FUNCTION_ID: usize = 0x65;
+pub const VERIFY_BLS12381_FUNCTION_NAME: &str = "verify_bls12381";
+pub const AGGREGATE_VERIFY_BLS12381_FUNCTION_ID: usize = 0x66;
+pub const AGGREGATE_VERIFY_BLS12381_FUNCTION_NAME: &str = "aggregate_verify_bls12381";
 
 pub const MODULE_ENV_NAME: &str = "env";
 pub const EXPORT_MEMORY: &str = "memory";
@@ -129,6 +129,8 @@ pub enum WasmRuntimeError {
     /// Invalid component id
     InvalidComponentId(DecodeError),
     InvalidKeyValueStoreSchema(DecodeError),
+    /// Invalid key-value store key
+    InvalidKeyValueStoreKey(DecodeError),
     InvalidValue(DecodeError),
     // Invalid EventSchema
     InvalidEventSchema(DecodeError),
@@ -136,6 +138,14 @@ pub enum WasmRuntimeError {
     InvalidLockFlags,
     /// Invalid log level
     InvalidLogLevel(DecodeError),
+    /// Invalid log fields
+    InvalidLogFields(DecodeError),
+    /// Invalid hash
+    InvalidHash,
+    /// Invalid public key
+    InvalidPublicKey,
+    /// Invalid public keys
+    InvalidPublicKeys(DecodeError),
 
     //=============
     // No-op Runtime
@@ -32,6 +32,7 @@ pub type DefaultWasmEngine = WasmiEngine;
 #[cfg(not(feature = "wasmer"))]
 pub type DefaultWasmInstance = WasmiInstance;
 
-// TODO: expand if package is upgradable.
-pub type CodeKey = radix_engine_interface::data::scrypto::model::PackageAddress;
+/// Hash of the code, as upgradeable packages replace their code in place (and an upgrade may
+/// be rolled back).
+pub type CodeKey = radix_engine_interface::crypto::Hash;
 pub type MeteredCodeKey = (CodeKey, WasmMeteringConfig);
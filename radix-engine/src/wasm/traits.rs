@@ -75,6 +75,24 @@ pub trait WasmRuntime {
 
     fn drop_lock(&mut self, handle: LockHandle) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn remove_key_value_store_entry(
+        &mut self,
+        node_id: Vec<u8>,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn list_key_value_store_keys(
+        &mut self,
+        node_id: Vec<u8>,
+        from: Vec<u8>,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn count_key_value_store_entries(
+        &mut self,
+        node_id: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
     fn get_actor(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
     fn consume_cost_units(&mut self, n: u32) -> Result<(), InvokeError<WasmRuntimeError>>;
@@ -96,9 +114,50 @@ pub trait WasmRuntime {
         message: Vec<u8>,
     ) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn log_structured_message(
+        &mut self,
+        level: Vec<u8>,
+        message: Vec<u8>,
+        fields: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
     fn generate_uuid(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn verify_bls12381(
+        &mut self,
+        message_hash: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
+    fn aggregate_verify_bls12381(
+        &mut self,
+        message_hash: Vec<u8>,
+        public_keys: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
 }
 
 /// Represents an instantiated, invokable Scrypto module.
@@ -374,6 +374,61 @@ impl WasmerModule {
             Ok(())
         }
 
+        pub fn remove_key_value_store_entry(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+            key_ptr: u32,
+            key_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let node_id = read_memory(&instance, node_id_ptr, node_id_len)?;
+            let key = read_memory(&instance, key_ptr, key_len)?;
+
+            let buffer = runtime
+                .remove_key_value_store_entry(node_id, key)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn list_key_value_store_keys(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+            from_ptr: u32,
+            from_len: u32,
+            limit: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let node_id = read_memory(&instance, node_id_ptr, node_id_len)?;
+            let from = read_memory(&instance, from_ptr, from_len)?;
+
+            let buffer = runtime
+                .list_key_value_store_keys(node_id, from, limit)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn count_key_value_store_entries(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+        ) -> Result<u32, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let node_id = read_memory(&instance, node_id_ptr, node_id_len)?;
+
+            let count = runtime
+                .count_key_value_store_entries(node_id)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(count)
+        }
+
         pub fn get_actor(env: &WasmerInstanceEnv) -> Result<u64, RuntimeError> {
             let (_instance, runtime) = grab_runtime!(env);
 
@@ -421,6 +476,24 @@ impl WasmerModule {
             runtime.log_message(level, message)
         }
 
+        fn log_structured_message(
+            env: &WasmerInstanceEnv,
+            level_ptr: u32,
+            level_len: u32,
+            message_ptr: u32,
+            message_len: u32,
+            fields_ptr: u32,
+            fields_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let level = read_memory(&instance, level_ptr, level_len)?;
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let fields = read_memory(&instance, fields_ptr, fields_len)?;
+
+            runtime.log_structured_message(level, message, fields)
+        }
+
         pub fn get_transaction_hash(env: &WasmerInstanceEnv) -> Result<u64, RuntimeError> {
             let (_instance, runtime) = grab_runtime!(env);
 
@@ -441,6 +514,142 @@ impl WasmerModule {
             Ok(buffer.0)
         }
 
+        pub fn verify_ecdsa_secp256k1(
+            env: &WasmerInstanceEnv,
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            let is_valid = runtime
+                .verify_ecdsa_secp256k1(message, public_key, signature)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(is_valid)
+        }
+
+        pub fn verify_eddsa_ed25519(
+            env: &WasmerInstanceEnv,
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            let is_valid = runtime
+                .verify_eddsa_ed25519(message, public_key, signature)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(is_valid)
+        }
+
+        pub fn keccak256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            let buffer = runtime
+                .keccak256_hash(data)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn sha256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            let buffer = runtime
+                .sha256_hash(data)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn blake2b_256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            let buffer = runtime
+                .blake2b_256_hash(data)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
+        pub fn verify_bls12381(
+            env: &WasmerInstanceEnv,
+            message_hash_ptr: u32,
+            message_hash_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message_hash = read_memory(&instance, message_hash_ptr, message_hash_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            let is_valid = runtime
+                .verify_bls12381(message_hash, public_key, signature)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(is_valid)
+        }
+
+        pub fn aggregate_verify_bls12381(
+            env: &WasmerInstanceEnv,
+            message_hash_ptr: u32,
+            message_hash_len: u32,
+            public_keys_ptr: u32,
+            public_keys_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message_hash = read_memory(&instance, message_hash_ptr, message_hash_len)?;
+            let public_keys = read_memory(&instance, public_keys_ptr, public_keys_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            let is_valid = runtime
+                .aggregate_verify_bls12381(message_hash, public_keys, signature)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(is_valid)
+        }
+
         // native functions ends
 
         // env
@@ -464,12 +673,23 @@ impl WasmerModule {
                 READ_SUBSTATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), read_substate),
                 WRITE_SUBSTATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), write_substate),
                 DROP_LOCK_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), drop_lock),
+                REMOVE_KEY_VALUE_STORE_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), remove_key_value_store_entry),
+                LIST_KEY_VALUE_STORE_KEYS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), list_key_value_store_keys),
+                COUNT_KEY_VALUE_STORE_ENTRIES_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), count_key_value_store_entries),
                 GET_ACTOR_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_actor),
                 CONSUME_COST_UNITS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), consume_cost_units),
                 EMIT_EVENT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), emit_event),
                 LOG_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), log_message),
+                LOG_STRUCTURED_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), log_structured_message),
                 GET_TRANSACTION_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_transaction_hash),
                 GENERATE_UUID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), generate_uuid),
+                VERIFY_ECDSA_SECP256K1_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), verify_ecdsa_secp256k1),
+                VERIFY_EDDSA_ED25519_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), verify_eddsa_ed25519),
+                KECCAK256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), keccak256_hash),
+                SHA256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sha256_hash),
+                BLAKE2B_256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), blake2b_256_hash),
+                VERIFY_BLS12381_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), verify_bls12381),
+                AGGREGATE_VERIFY_BLS12381_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), aggregate_verify_bls12381),
             }
         };
 
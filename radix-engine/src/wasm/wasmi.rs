@@ -324,6 +324,53 @@ fn drop_lock(
     runtime.drop_lock(handle)
 }
 
+fn remove_key_value_store_entry(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    key_ptr: u32,
+    key_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+    let key = read_memory(caller.as_context_mut(), memory, key_ptr, key_len)?;
+
+    runtime
+        .remove_key_value_store_entry(node_id, key)
+        .map(|buffer| buffer.0)
+}
+
+fn list_key_value_store_keys(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    from_ptr: u32,
+    from_len: u32,
+    limit: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+    let from = read_memory(caller.as_context_mut(), memory, from_ptr, from_len)?;
+
+    runtime
+        .list_key_value_store_keys(node_id, from, limit)
+        .map(|buffer| buffer.0)
+}
+
+fn count_key_value_store_entries(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+
+    runtime.count_key_value_store_entries(node_id)
+}
+
 fn get_actor(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmRuntimeError>> {
     let (_memory, runtime) = grab_runtime!(caller);
 
@@ -377,6 +424,164 @@ fn generate_uuid(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmR
     runtime.generate_uuid().map(|buffer| buffer.0)
 }
 
+fn verify_ecdsa_secp256k1(
+    mut caller: Caller<'_, HostState>,
+    message_ptr: u32,
+    message_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
+    let public_key = read_memory(
+        caller.as_context_mut(),
+        memory,
+        public_key_ptr,
+        public_key_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime.verify_ecdsa_secp256k1(message, public_key, signature)
+}
+
+fn verify_eddsa_ed25519(
+    mut caller: Caller<'_, HostState>,
+    message_ptr: u32,
+    message_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
+    let public_key = read_memory(
+        caller.as_context_mut(),
+        memory,
+        public_key_ptr,
+        public_key_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime.verify_eddsa_ed25519(message, public_key, signature)
+}
+
+fn keccak256_hash(
+    mut caller: Caller<'_, HostState>,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
+
+    runtime.keccak256_hash(data).map(|buffer| buffer.0)
+}
+
+fn sha256_hash(
+    mut caller: Caller<'_, HostState>,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
+
+    runtime.sha256_hash(data).map(|buffer| buffer.0)
+}
+
+fn blake2b_256_hash(
+    mut caller: Caller<'_, HostState>,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
+
+    runtime.blake2b_256_hash(data).map(|buffer| buffer.0)
+}
+
+fn verify_bls12381(
+    mut caller: Caller<'_, HostState>,
+    message_hash_ptr: u32,
+    message_hash_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message_hash = read_memory(
+        caller.as_context_mut(),
+        memory,
+        message_hash_ptr,
+        message_hash_len,
+    )?;
+    let public_key = read_memory(
+        caller.as_context_mut(),
+        memory,
+        public_key_ptr,
+        public_key_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime.verify_bls12381(message_hash, public_key, signature)
+}
+
+fn aggregate_verify_bls12381(
+    mut caller: Caller<'_, HostState>,
+    message_hash_ptr: u32,
+    message_hash_len: u32,
+    public_keys_ptr: u32,
+    public_keys_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message_hash = read_memory(
+        caller.as_context_mut(),
+        memory,
+        message_hash_ptr,
+        message_hash_len,
+    )?;
+    let public_keys = read_memory(
+        caller.as_context_mut(),
+        memory,
+        public_keys_ptr,
+        public_keys_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime.aggregate_verify_bls12381(message_hash, public_keys, signature)
+}
+
 fn log_message(
     mut caller: Caller<'_, HostState>,
     level_ptr: u32,
@@ -391,6 +596,24 @@ fn log_message(
 
     runtime.log_message(level, message)
 }
+
+fn log_structured_message(
+    mut caller: Caller<'_, HostState>,
+    level_ptr: u32,
+    level_len: u32,
+    message_ptr: u32,
+    message_len: u32,
+    fields_ptr: u32,
+    fields_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let level = read_memory(caller.as_context_mut(), memory, level_ptr, level_len)?;
+    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
+    let fields = read_memory(caller.as_context_mut(), memory, fields_ptr, fields_len)?;
+
+    runtime.log_structured_message(level, message, fields)
+}
 // native functions ends
 
 macro_rules! linker_define {
@@ -599,6 +822,51 @@ impl WasmiModule {
             },
         );
 
+        let host_remove_key_value_store_entry = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32,
+             key_ptr: u32,
+             key_len: u32|
+             -> Result<u64, Trap> {
+                remove_key_value_store_entry(caller, node_id_ptr, node_id_len, key_ptr, key_len)
+                    .map_err(|e| e.into())
+            },
+        );
+
+        let host_list_key_value_store_keys = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32,
+             from_ptr: u32,
+             from_len: u32,
+             limit: u32|
+             -> Result<u64, Trap> {
+                list_key_value_store_keys(
+                    caller,
+                    node_id_ptr,
+                    node_id_len,
+                    from_ptr,
+                    from_len,
+                    limit,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_count_key_value_store_entries = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32|
+             -> Result<u32, Trap> {
+                count_key_value_store_entries(caller, node_id_ptr, node_id_len)
+                    .map_err(|e| e.into())
+            },
+        );
+
         let host_get_actor = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>| -> Result<u64, Trap> {
@@ -645,6 +913,29 @@ impl WasmiModule {
             },
         );
 
+        let host_log_structured = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             level_ptr: u32,
+             level_len: u32,
+             message_ptr: u32,
+             message_len: u32,
+             fields_ptr: u32,
+             fields_len: u32|
+             -> Result<(), Trap> {
+                log_structured_message(
+                    caller,
+                    level_ptr,
+                    level_len,
+                    message_ptr,
+                    message_len,
+                    fields_ptr,
+                    fields_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let host_get_transaction_hash = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>| -> Result<u64, Trap> {
@@ -659,6 +950,119 @@ impl WasmiModule {
             },
         );
 
+        let host_verify_ecdsa_secp256k1 = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_ptr: u32,
+             message_len: u32,
+             public_key_ptr: u32,
+             public_key_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                verify_ecdsa_secp256k1(
+                    caller,
+                    message_ptr,
+                    message_len,
+                    public_key_ptr,
+                    public_key_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_verify_eddsa_ed25519 = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_ptr: u32,
+             message_len: u32,
+             public_key_ptr: u32,
+             public_key_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                verify_eddsa_ed25519(
+                    caller,
+                    message_ptr,
+                    message_len,
+                    public_key_ptr,
+                    public_key_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_keccak256_hash = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, data_ptr: u32, data_len: u32| -> Result<u64, Trap> {
+                keccak256_hash(caller, data_ptr, data_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_sha256_hash = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, data_ptr: u32, data_len: u32| -> Result<u64, Trap> {
+                sha256_hash(caller, data_ptr, data_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_blake2b_256_hash = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, data_ptr: u32, data_len: u32| -> Result<u64, Trap> {
+                blake2b_256_hash(caller, data_ptr, data_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_verify_bls12381 = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_hash_ptr: u32,
+             message_hash_len: u32,
+             public_key_ptr: u32,
+             public_key_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                verify_bls12381(
+                    caller,
+                    message_hash_ptr,
+                    message_hash_len,
+                    public_key_ptr,
+                    public_key_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_aggregate_verify_bls12381 = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_hash_ptr: u32,
+             message_hash_len: u32,
+             public_keys_ptr: u32,
+             public_keys_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                aggregate_verify_bls12381(
+                    caller,
+                    message_hash_ptr,
+                    message_hash_len,
+                    public_keys_ptr,
+                    public_keys_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let mut linker = <Linker<HostState>>::new();
         linker_define!(linker, CONSUME_BUFFER_FUNCTION_NAME, host_consume_buffer);
         linker_define!(linker, CALL_METHOD_FUNCTION_NAME, host_call_method);
@@ -684,6 +1088,21 @@ impl WasmiModule {
         linker_define!(linker, READ_SUBSTATE_FUNCTION_NAME, host_read_substate);
         linker_define!(linker, WRITE_SUBSTATE_FUNCTION_NAME, host_write_substate);
         linker_define!(linker, DROP_LOCK_FUNCTION_NAME, host_drop_lock);
+        linker_define!(
+            linker,
+            REMOVE_KEY_VALUE_STORE_ENTRY_FUNCTION_NAME,
+            host_remove_key_value_store_entry
+        );
+        linker_define!(
+            linker,
+            LIST_KEY_VALUE_STORE_KEYS_FUNCTION_NAME,
+            host_list_key_value_store_keys
+        );
+        linker_define!(
+            linker,
+            COUNT_KEY_VALUE_STORE_ENTRIES_FUNCTION_NAME,
+            host_count_key_value_store_entries
+        );
         linker_define!(linker, GET_ACTOR_FUNCTION_NAME, host_get_actor);
         linker_define!(
             linker,
@@ -692,12 +1111,36 @@ impl WasmiModule {
         );
         linker_define!(linker, EMIT_EVENT_FUNCTION_NAME, host_emit_event);
         linker_define!(linker, LOG_FUNCTION_NAME, host_log);
+        linker_define!(linker, LOG_STRUCTURED_FUNCTION_NAME, host_log_structured);
         linker_define!(
             linker,
             GET_TRANSACTION_HASH_FUNCTION_NAME,
             host_get_transaction_hash
         );
         linker_define!(linker, GENERATE_UUID_FUNCTION_NAME, host_generate_uuid);
+        linker_define!(
+            linker,
+            VERIFY_ECDSA_SECP256K1_FUNCTION_NAME,
+            host_verify_ecdsa_secp256k1
+        );
+        linker_define!(
+            linker,
+            VERIFY_EDDSA_ED25519_FUNCTION_NAME,
+            host_verify_eddsa_ed25519
+        );
+        linker_define!(linker, KECCAK256_HASH_FUNCTION_NAME, host_keccak256_hash);
+        linker_define!(linker, SHA256_HASH_FUNCTION_NAME, host_sha256_hash);
+        linker_define!(
+            linker,
+            BLAKE2B_256_HASH_FUNCTION_NAME,
+            host_blake2b_256_hash
+        );
+        linker_define!(linker, VERIFY_BLS12381_FUNCTION_NAME, host_verify_bls12381);
+        linker_define!(
+            linker,
+            AGGREGATE_VERIFY_BLS12381_FUNCTION_NAME,
+            host_aggregate_verify_bls12381
+        );
 
         linker.instantiate(store.as_context_mut(), &module)
     }
@@ -0,0 +1,1024 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use radix_engine_common::data::scrypto::model::NonFungibleIdType;
+use radix_engine_common::data::scrypto::{
+    scrypto_decode, ScryptoCustomTypeExtension, ScryptoCustomTypeKind, ScryptoDescribe,
+    ScryptoSchema,
+};
+use radix_engine_common::time::{Instant, UtcDateTime};
+use radix_engine_interface::api::types::RoyaltyConfig;
+use radix_engine_interface::blueprints::resource::{
+    AccessRule, AccessRuleEntry, AccessRuleNode, AccessRulesConfig, MethodKey, NonFungibleGlobalId,
+    ProofRule, ResourceMethodAuthKey, ResourceType, SoftCount, SoftDecimal, SoftResource,
+    SoftResourceOrNonFungible, SoftResourceOrNonFungibleList,
+};
+use sbor::{
+    generate_full_schema_from_single_type, ChildNames, LengthValidation, LocalTypeIndex,
+    SchemaTypeKind, TypeKind, TypeMetadata, TypeValidation,
+};
+use scrypto_schema::{BlueprintSchema, PackageSchema, Receiver};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use syn::parse::{Parse, ParseStream};
+use syn::*;
+
+macro_rules! trace {
+    ($($arg:expr),*) => {{
+        #[cfg(feature = "trace")]
+        println!($($arg),*);
+    }};
+}
+
+/// Named types which are already defined by the Scrypto prelude, together with their schemas.
+///
+/// These are referred to instead of being generated again, provided the imported type is
+/// structurally the same as the prelude one (see `TypeGenerator::is_same_type`).
+fn prelude_types() -> BTreeMap<&'static str, (LocalTypeIndex, ScryptoSchema)> {
+    fn schema_of<T: ScryptoDescribe>() -> (LocalTypeIndex, ScryptoSchema) {
+        generate_full_schema_from_single_type::<T, ScryptoCustomTypeExtension>()
+    }
+
+    BTreeMap::from([
+        ("NonFungibleGlobalId", schema_of::<NonFungibleGlobalId>()),
+        ("AccessRule", schema_of::<AccessRule>()),
+        ("AccessRuleNode", schema_of::<AccessRuleNode>()),
+        ("ProofRule", schema_of::<ProofRule>()),
+        ("SoftResource", schema_of::<SoftResource>()),
+        (
+            "SoftResourceOrNonFungible",
+            schema_of::<SoftResourceOrNonFungible>(),
+        ),
+        (
+            "SoftResourceOrNonFungibleList",
+            schema_of::<SoftResourceOrNonFungibleList>(),
+        ),
+        ("SoftDecimal", schema_of::<SoftDecimal>()),
+        ("SoftCount", schema_of::<SoftCount>()),
+        ("AccessRulesConfig", schema_of::<AccessRulesConfig>()),
+        ("AccessRuleEntry", schema_of::<AccessRuleEntry>()),
+        ("MethodKey", schema_of::<MethodKey>()),
+        ("RoyaltyConfig", schema_of::<RoyaltyConfig>()),
+        (
+            "ResourceMethodAuthKey",
+            schema_of::<ResourceMethodAuthKey>(),
+        ),
+        ("ResourceType", schema_of::<ResourceType>()),
+        ("NonFungibleIdType", schema_of::<NonFungibleIdType>()),
+        ("Instant", schema_of::<Instant>()),
+        ("UtcDateTime", schema_of::<UtcDateTime>()),
+    ])
+}
+
+pub struct ImportBlueprint {
+    pub schema_path: LitStr,
+    pub blueprint_name: LitStr,
+}
+
+impl Parse for ImportBlueprint {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let schema_path = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let blueprint_name = input.parse()?;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self {
+            schema_path,
+            blueprint_name,
+        })
+    }
+}
+
+pub fn handle_import_blueprint(input: TokenStream) -> Result<TokenStream> {
+    trace!("handle_import_blueprint() starts");
+
+    let ImportBlueprint {
+        schema_path,
+        blueprint_name,
+    } = parse2::<ImportBlueprint>(input)?;
+
+    // Relative paths are resolved against the crate being compiled
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let path = Path::new(&manifest_dir).join(schema_path.value());
+    let bytes = std::fs::read(&path).map_err(|err| {
+        Error::new(
+            schema_path.span(),
+            format!("Failed to read schema file {}: {}", path.display(), err),
+        )
+    })?;
+    let schema: PackageSchema = scrypto_decode(&bytes).map_err(|err| {
+        Error::new(
+            schema_path.span(),
+            format!("Failed to decode schema file {}: {:?}", path.display(), err),
+        )
+    })?;
+    let blueprint_schema = schema
+        .blueprints
+        .get(&blueprint_name.value())
+        .ok_or_else(|| {
+            Error::new(
+                blueprint_name.span(),
+                format!(
+                    "Blueprint {} not found in schema, available blueprints: {:?}",
+                    blueprint_name.value(),
+                    schema.blueprints.keys().collect::<Vec<_>>()
+                ),
+            )
+        })?;
+
+    let stubs = generate_stubs(&blueprint_name.value(), blueprint_schema)
+        .map_err(|err| Error::new(blueprint_name.span(), err))?;
+    let path = path.display().to_string();
+
+    let output = quote! {
+        // Makes the stubs get regenerated whenever the schema file changes
+        const _: &[u8] = include_bytes!(#path);
+
+        #stubs
+    };
+
+    #[cfg(feature = "trace")]
+    crate::utils::print_generated_code("import_blueprint!", &output);
+
+    trace!("handle_import_blueprint() finishes");
+    Ok(output)
+}
+
+/// Generates a `<Blueprint>Blueprint` stub for the functions and a `<Blueprint>GlobalComponentRef`
+/// stub for the methods of a blueprint, together with the types used by their signatures.
+pub fn generate_stubs(
+    blueprint_name: &str,
+    schema: &BlueprintSchema,
+) -> std::result::Result<TokenStream, String> {
+    if !is_valid_ident(blueprint_name) {
+        return Err(format!("Invalid blueprint name: {}", blueprint_name));
+    }
+    let blueprint_ident = format_ident!("{}Blueprint", blueprint_name);
+    let component_ident = format_ident!("{}GlobalComponentRef", blueprint_name);
+
+    let mut types = TypeGenerator::new(
+        &schema.schema,
+        [blueprint_ident.to_string(), component_ident.to_string()],
+    );
+    let mut functions = Vec::<TokenStream>::new();
+    let mut methods = Vec::<TokenStream>::new();
+
+    for (name, function_schema) in &schema.functions {
+        trace!("Processing function: {}", name);
+        let ident = to_ident(name);
+        let (input_args, input_types) = types.input_args(function_schema.input)?;
+        let output = types.rust_type(function_schema.output)?;
+
+        match function_schema.receiver {
+            None => functions.push(quote! {
+                pub fn #ident(&self #(, #input_args: #input_types)*) -> #output {
+                    ::scrypto::runtime::Runtime::call_function(
+                        self.package_address,
+                        #blueprint_name,
+                        #name,
+                        ::scrypto::prelude::scrypto_args!(#(#input_args),*)
+                    )
+                }
+            }),
+            Some(ref receiver) => {
+                let receiver = match receiver {
+                    Receiver::SelfRef => quote! { &self },
+                    Receiver::SelfRefMut => quote! { &mut self },
+                };
+                methods.push(quote! {
+                    pub fn #ident(#receiver #(, #input_args: #input_types)*) -> #output {
+                        ::scrypto::runtime::Runtime::call_method(
+                            self.component_address,
+                            #name,
+                            ::scrypto::prelude::scrypto_args!(#(#input_args),*)
+                        )
+                    }
+                });
+            }
+        }
+    }
+
+    let definitions = types.definitions;
+
+    Ok(quote! {
+        #(#definitions)*
+
+        #[derive(::scrypto::prelude::ScryptoSbor)]
+        pub struct #blueprint_ident {
+            pub package_address: ::scrypto::prelude::PackageAddress,
+        }
+
+        // We allow dead code because not all the interface might be used
+        #[allow(dead_code)]
+        impl #blueprint_ident {
+            pub fn at(package_address: ::scrypto::prelude::PackageAddress) -> Self {
+                Self { package_address }
+            }
+
+            #(#functions)*
+        }
+
+        impl From<#blueprint_ident> for ::scrypto::prelude::PackageAddress {
+            fn from(a: #blueprint_ident) -> ::scrypto::prelude::PackageAddress {
+                a.package_address
+            }
+        }
+
+        #[derive(::scrypto::prelude::ScryptoSbor)]
+        pub struct #component_ident {
+            pub component_address: ::scrypto::prelude::ComponentAddress,
+        }
+
+        #[allow(dead_code)]
+        impl #component_ident {
+            pub fn at(component_address: ::scrypto::prelude::ComponentAddress) -> Self {
+                Self { component_address }
+            }
+
+            #(#methods)*
+        }
+
+        impl From<::scrypto::prelude::ComponentAddress> for #component_ident {
+            fn from(component_address: ::scrypto::prelude::ComponentAddress) -> Self {
+                Self { component_address }
+            }
+        }
+
+        impl From<#component_ident> for ::scrypto::prelude::ComponentAddress {
+            fn from(a: #component_ident) -> ::scrypto::prelude::ComponentAddress {
+                a.component_address
+            }
+        }
+    })
+}
+
+/// Maps schema types to Rust types, generating a definition for each named struct and enum.
+///
+/// The generated types only need to be encoded the same way as the original ones, so collections
+/// are mapped to `Vec` and `BTreeMap`, and transparent wrappers to their inner types.
+struct TypeGenerator<'s> {
+    schema: &'s ScryptoSchema,
+    prelude_types: BTreeMap<&'static str, (LocalTypeIndex, ScryptoSchema)>,
+    idents: BTreeMap<usize, Ident>,
+    used_names: BTreeSet<String>,
+    in_progress: BTreeSet<usize>,
+    definitions: Vec<TokenStream>,
+}
+
+impl<'s> TypeGenerator<'s> {
+    fn new<const N: usize>(schema: &'s ScryptoSchema, reserved_names: [String; N]) -> Self {
+        Self {
+            schema,
+            prelude_types: prelude_types(),
+            idents: BTreeMap::new(),
+            used_names: reserved_names.into_iter().collect(),
+            in_progress: BTreeSet::new(),
+            definitions: Vec::new(),
+        }
+    }
+
+    fn input_args(
+        &mut self,
+        index: LocalTypeIndex,
+    ) -> std::result::Result<(Vec<Ident>, Vec<TokenStream>), String> {
+        let field_types = match self.resolve_type_kind(index)? {
+            TypeKind::Tuple { field_types } => field_types,
+            _ => return Err(format!("Function input {:?} is not a tuple", index)),
+        };
+        let field_names = self.field_names(index, field_types.len())?;
+
+        let mut input_args = Vec::new();
+        let mut input_types = Vec::new();
+        for (i, field_type) in field_types.iter().enumerate() {
+            input_args.push(match &field_names {
+                Some(names) => to_ident(&names[i]),
+                None => format_ident!("arg{}", i),
+            });
+            input_types.push(self.rust_type(*field_type)?);
+        }
+        Ok((input_args, input_types))
+    }
+
+    fn rust_type(&mut self, index: LocalTypeIndex) -> std::result::Result<TokenStream, String> {
+        let type_kind = self.resolve_type_kind(index)?;
+        let type_name = self.resolve_type_metadata(index)?.get_name();
+
+        let rust_type = match type_kind {
+            TypeKind::Any => quote! { ::scrypto::prelude::ScryptoValue },
+            TypeKind::Bool => quote! { bool },
+            TypeKind::I8 => quote! { i8 },
+            TypeKind::I16 => quote! { i16 },
+            TypeKind::I32 => quote! { i32 },
+            TypeKind::I64 => quote! { i64 },
+            TypeKind::I128 => quote! { i128 },
+            TypeKind::U8 => quote! { u8 },
+            TypeKind::U16 => quote! { u16 },
+            TypeKind::U32 => quote! { u32 },
+            TypeKind::U64 => quote! { u64 },
+            TypeKind::U128 => quote! { u128 },
+            TypeKind::String => quote! { ::scrypto::prelude::String },
+            TypeKind::Array { element_type } => {
+                let element_type = self.rust_type(*element_type)?;
+                match self.schema.resolve_type_validation(index) {
+                    Some(TypeValidation::Array(LengthValidation {
+                        min: Some(min),
+                        max: Some(max),
+                    })) if min == max => {
+                        let length = *min as usize;
+                        quote! { [#element_type; #length] }
+                    }
+                    _ => quote! { ::scrypto::prelude::Vec<#element_type> },
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let key_type = self.rust_type(*key_type)?;
+                let value_type = self.rust_type(*value_type)?;
+                quote! { ::scrypto::prelude::BTreeMap<#key_type, #value_type> }
+            }
+            TypeKind::Tuple { field_types } => match type_name {
+                Some(name) => self.named_type(index, name)?,
+                None => {
+                    let field_types = field_types
+                        .iter()
+                        .map(|field_type| self.rust_type(*field_type))
+                        .collect::<std::result::Result<Vec<_>, _>>()?;
+                    quote! { (#(#field_types,)*) }
+                }
+            },
+            TypeKind::Enum { variants } => {
+                let variant = |discriminator: u8| {
+                    variants.get(&discriminator).filter(|_| variants.len() == 2)
+                };
+                match (type_name, variant(0), variant(1)) {
+                    (Some("Option"), Some(none), Some(some))
+                        if none.is_empty() && some.len() == 1 =>
+                    {
+                        let some_type = self.rust_type(some[0])?;
+                        quote! { Option<#some_type> }
+                    }
+                    (Some("Result"), Some(ok), Some(err)) if ok.len() == 1 && err.len() == 1 => {
+                        let ok_type = self.rust_type(ok[0])?;
+                        let err_type = self.rust_type(err[0])?;
+                        quote! { Result<#ok_type, #err_type> }
+                    }
+                    _ => self.named_type(index, type_name.unwrap_or("Enum"))?,
+                }
+            }
+            TypeKind::Custom(custom_type_kind) => match custom_type_kind {
+                ScryptoCustomTypeKind::Address => quote! { ::scrypto::prelude::Address },
+                ScryptoCustomTypeKind::PackageAddress => {
+                    quote! { ::scrypto::prelude::PackageAddress }
+                }
+                ScryptoCustomTypeKind::ComponentAddress => {
+                    quote! { ::scrypto::prelude::ComponentAddress }
+                }
+                ScryptoCustomTypeKind::ResourceAddress => {
+                    quote! { ::scrypto::prelude::ResourceAddress }
+                }
+                ScryptoCustomTypeKind::Own | ScryptoCustomTypeKind::KeyValueStore => {
+                    quote! { ::scrypto::prelude::Own }
+                }
+                ScryptoCustomTypeKind::Bucket => quote! { ::scrypto::prelude::Bucket },
+                ScryptoCustomTypeKind::Proof => quote! { ::scrypto::prelude::Proof },
+                ScryptoCustomTypeKind::Vault => quote! { ::scrypto::prelude::Vault },
+                ScryptoCustomTypeKind::Decimal => quote! { ::scrypto::prelude::Decimal },
+                ScryptoCustomTypeKind::PreciseDecimal => {
+                    quote! { ::scrypto::prelude::PreciseDecimal }
+                }
+                ScryptoCustomTypeKind::NonFungibleLocalId => {
+                    quote! { ::scrypto::prelude::NonFungibleLocalId }
+                }
+                ScryptoCustomTypeKind::Reference => quote! { ::scrypto::prelude::Reference },
+            },
+        };
+
+        Ok(rust_type)
+    }
+
+    fn named_type(
+        &mut self,
+        index: LocalTypeIndex,
+        name: &str,
+    ) -> std::result::Result<TokenStream, String> {
+        // A type which only shares its name with a prelude type is generated instead
+        if let Some((prelude_index, prelude_schema)) = self.prelude_types.get(name) {
+            if self.is_same_type(index, prelude_schema, *prelude_index, &mut BTreeSet::new()) {
+                let ident = format_ident!("{}", name);
+                return Ok(quote! { ::scrypto::prelude::#ident });
+            }
+        }
+
+        let local_index = match index {
+            LocalTypeIndex::SchemaLocalIndex(local_index) => local_index,
+            LocalTypeIndex::WellKnown(_) => {
+                return Err(format!("Unexpected well-known type {:?}", index))
+            }
+        };
+        if let Some(ident) = self.idents.get(&local_index) {
+            // Boxing breaks the cycle of recursive types, and is encoded the same
+            return Ok(if self.in_progress.contains(&local_index) {
+                quote! { ::scrypto::prelude::Box<#ident> }
+            } else {
+                quote! { #ident }
+            });
+        }
+
+        let ident = self.unique_ident(name);
+        self.idents.insert(local_index, ident.clone());
+        self.in_progress.insert(local_index);
+        let definition = match self.resolve_type_kind(index)? {
+            TypeKind::Tuple { field_types } => {
+                let fields = self.fields(index, field_types)?;
+                quote! {
+                    #[derive(Debug, PartialEq, Eq, ::scrypto::prelude::ScryptoSbor)]
+                    pub struct #ident #fields
+                }
+            }
+            TypeKind::Enum { variants } => {
+                let variant_names = match &self.resolve_type_metadata(index)?.child_names {
+                    Some(ChildNames::EnumVariants(variant_names)) => Some(variant_names),
+                    _ => None,
+                };
+                let mut definitions = Vec::new();
+                for (i, (discriminator, field_types)) in variants.iter().enumerate() {
+                    // Derived enums are encoded with the position of the variant
+                    if *discriminator as usize != i {
+                        return Err(format!(
+                            "Enum {} has a non-sequential variant discriminator {}",
+                            name, discriminator
+                        ));
+                    }
+                    let variant_metadata = variant_names.and_then(|names| names.get(discriminator));
+                    let variant_ident = variant_metadata
+                        .and_then(|metadata| metadata.get_name())
+                        .map(to_ident)
+                        .unwrap_or_else(|| format_ident!("Variant{}", discriminator));
+                    let fields = self.variant_fields(variant_metadata, field_types)?;
+                    definitions.push(quote! { #variant_ident #fields });
+                }
+                quote! {
+                    #[derive(Debug, PartialEq, Eq, ::scrypto::prelude::ScryptoSbor)]
+                    pub enum #ident {
+                        #(#definitions),*
+                    }
+                }
+            }
+            _ => return Err(format!("Type {} is neither a struct nor an enum", name)),
+        };
+        self.in_progress.remove(&local_index);
+        self.definitions.push(definition);
+
+        Ok(quote! { #ident })
+    }
+
+    fn fields(
+        &mut self,
+        index: LocalTypeIndex,
+        field_types: &[LocalTypeIndex],
+    ) -> std::result::Result<TokenStream, String> {
+        let field_names = self.field_names(index, field_types.len())?;
+        let field_types = field_types
+            .iter()
+            .map(|field_type| self.rust_type(*field_type))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(match field_names {
+            Some(field_names) => {
+                let field_idents = field_names.iter().map(|name| to_ident(name));
+                quote! { { #(pub #field_idents: #field_types),* } }
+            }
+            None if field_types.is_empty() => quote! { {} },
+            None => quote! { (#(pub #field_types),*); },
+        })
+    }
+
+    fn variant_fields(
+        &mut self,
+        metadata: Option<&TypeMetadata>,
+        field_types: &[LocalTypeIndex],
+    ) -> std::result::Result<TokenStream, String> {
+        let field_types = field_types
+            .iter()
+            .map(|field_type| self.rust_type(*field_type))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(
+            match metadata.and_then(|metadata| metadata.child_names.as_ref()) {
+                Some(ChildNames::NamedFields(field_names)) => {
+                    let field_idents = field_names.iter().map(|name| to_ident(name));
+                    quote! { { #(#field_idents: #field_types),* } }
+                }
+                _ if field_types.is_empty() => quote! {},
+                _ => quote! { (#(#field_types),*) },
+            },
+        )
+    }
+
+    fn field_names(
+        &self,
+        index: LocalTypeIndex,
+        field_count: usize,
+    ) -> std::result::Result<Option<Vec<String>>, String> {
+        match &self.resolve_type_metadata(index)?.child_names {
+            Some(ChildNames::NamedFields(names)) if names.len() == field_count => {
+                Ok(Some(names.iter().map(|name| name.to_string()).collect()))
+            }
+            Some(ChildNames::NamedFields(_)) => Err(format!(
+                "Field names of type {:?} do not match its fields",
+                index
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    /// Whether a type of the imported schema is encoded the same as a type of the other schema,
+    /// with the same names and validations, down to the types of its fields.
+    fn is_same_type(
+        &self,
+        index: LocalTypeIndex,
+        other_schema: &ScryptoSchema,
+        other_index: LocalTypeIndex,
+        visited: &mut BTreeSet<(usize, usize)>,
+    ) -> bool {
+        let local_indices = match (index, other_index) {
+            (LocalTypeIndex::WellKnown(a), LocalTypeIndex::WellKnown(b)) => return a == b,
+            (LocalTypeIndex::SchemaLocalIndex(a), LocalTypeIndex::SchemaLocalIndex(b)) => (a, b),
+            _ => return false,
+        };
+        // Recursive types are compared once, and match unless their other parts don't
+        if !visited.insert(local_indices) {
+            return true;
+        }
+        if self.schema.resolve_type_metadata(index)
+            != other_schema.resolve_type_metadata(other_index)
+            || self.schema.resolve_type_validation(index)
+                != other_schema.resolve_type_validation(other_index)
+        {
+            return false;
+        }
+
+        match (
+            self.schema.resolve_type_kind(index),
+            other_schema.resolve_type_kind(other_index),
+        ) {
+            (
+                Some(TypeKind::Array { element_type }),
+                Some(TypeKind::Array {
+                    element_type: other_element_type,
+                }),
+            ) => self.is_same_type(*element_type, other_schema, *other_element_type, visited),
+            (
+                Some(TypeKind::Tuple { field_types }),
+                Some(TypeKind::Tuple {
+                    field_types: other_field_types,
+                }),
+            ) => self.are_same_types(field_types, other_schema, other_field_types, visited),
+            (
+                Some(TypeKind::Enum { variants }),
+                Some(TypeKind::Enum {
+                    variants: other_variants,
+                }),
+            ) => {
+                variants.len() == other_variants.len()
+                    && variants.iter().zip(other_variants).all(
+                        |(
+                            (discriminator, field_types),
+                            (other_discriminator, other_field_types),
+                        )| {
+                            discriminator == other_discriminator
+                                && self.are_same_types(
+                                    field_types,
+                                    other_schema,
+                                    other_field_types,
+                                    visited,
+                                )
+                        },
+                    )
+            }
+            (
+                Some(TypeKind::Map {
+                    key_type,
+                    value_type,
+                }),
+                Some(TypeKind::Map {
+                    key_type: other_key_type,
+                    value_type: other_value_type,
+                }),
+            ) => {
+                self.is_same_type(*key_type, other_schema, *other_key_type, visited)
+                    && self.is_same_type(*value_type, other_schema, *other_value_type, visited)
+            }
+            (Some(type_kind), Some(other_type_kind)) => type_kind == other_type_kind,
+            _ => false,
+        }
+    }
+
+    fn are_same_types(
+        &self,
+        types: &[LocalTypeIndex],
+        other_schema: &ScryptoSchema,
+        other_types: &[LocalTypeIndex],
+        visited: &mut BTreeSet<(usize, usize)>,
+    ) -> bool {
+        types.len() == other_types.len()
+            && types.iter().zip(other_types).all(|(index, other_index)| {
+                self.is_same_type(*index, other_schema, *other_index, visited)
+            })
+    }
+
+    fn unique_ident(&mut self, name: &str) -> Ident {
+        let base = if is_valid_ident(name) { name } else { "Type" };
+        let mut name = base.to_string();
+        let mut suffix = 1;
+        while !self.used_names.insert(name.clone()) {
+            name = format!("{}{}", base, suffix);
+            suffix += 1;
+        }
+        format_ident!("{}", name)
+    }
+
+    fn resolve_type_kind(
+        &self,
+        index: LocalTypeIndex,
+    ) -> std::result::Result<&'s SchemaTypeKind<ScryptoCustomTypeExtension>, String> {
+        self.schema
+            .resolve_type_kind(index)
+            .ok_or_else(|| format!("Type {:?} not found in schema", index))
+    }
+
+    fn resolve_type_metadata(
+        &self,
+        index: LocalTypeIndex,
+    ) -> std::result::Result<&'s TypeMetadata, String> {
+        self.schema
+            .resolve_type_metadata(index)
+            .ok_or_else(|| format!("Type {:?} not found in schema", index))
+    }
+}
+
+fn is_valid_ident(name: &str) -> bool {
+    parse_str::<Ident>(name).is_ok()
+}
+
+/// Converts a name from the schema into an identifier, escaping keywords.
+fn to_ident(name: &str) -> Ident {
+    if is_valid_ident(name) {
+        Ident::new(name, Span::call_site())
+    } else {
+        Ident::new_raw(name, Span::call_site())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_common::data::scrypto::well_known_scrypto_custom_types::*;
+    use sbor::basic_well_known_types::*;
+    use scrypto_schema::FunctionSchema;
+    use std::borrow::Cow;
+
+    fn assert_code_eq(a: TokenStream, b: TokenStream) {
+        assert_eq!(a.to_string(), b.to_string());
+    }
+
+    fn named_fields(name: &'static str, field_names: &[&'static str]) -> TypeMetadata {
+        TypeMetadata {
+            type_name: Some(Cow::Borrowed(name)),
+            child_names: Some(ChildNames::NamedFields(
+                field_names
+                    .iter()
+                    .map(|name| Cow::Borrowed(*name))
+                    .collect(),
+            )),
+        }
+    }
+
+    fn blueprint_schema(
+        types: Vec<(SchemaTypeKind<ScryptoCustomTypeExtension>, TypeMetadata)>,
+        functions: Vec<(&str, Option<Receiver>, usize, LocalTypeIndex)>,
+    ) -> BlueprintSchema {
+        let type_validations = types.iter().map(|_| TypeValidation::None).collect();
+        let (type_kinds, type_metadata) = types.into_iter().unzip();
+        BlueprintSchema {
+            schema: ScryptoSchema {
+                type_kinds,
+                type_metadata,
+                type_validations,
+            },
+            substates: vec![],
+            functions: functions
+                .into_iter()
+                .map(|(name, receiver, input, output)| {
+                    (
+                        name.to_string(),
+                        FunctionSchema {
+                            receiver,
+                            input: LocalTypeIndex::SchemaLocalIndex(input),
+                            output,
+                            export_name: format!("Test_{}", name),
+                        },
+                    )
+                })
+                .collect(),
+            event_schema: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_import_blueprint() {
+        let schema = blueprint_schema(
+            vec![
+                // 0: Test_new_Input
+                (
+                    TypeKind::Tuple {
+                        field_types: vec![],
+                    },
+                    named_fields("Test_new_Input", &[]),
+                ),
+                // 1: Test_deposit_Input
+                (
+                    TypeKind::Tuple {
+                        field_types: vec![LocalTypeIndex::WellKnown(OWN_BUCKET_ID)],
+                    },
+                    named_fields("Test_deposit_Input", &["arg0"]),
+                ),
+                // 2: DepositResult
+                (
+                    TypeKind::Enum {
+                        variants: BTreeMap::from([
+                            (0, vec![]),
+                            (1, vec![LocalTypeIndex::WellKnown(STRING_ID)]),
+                        ]),
+                    },
+                    TypeMetadata {
+                        type_name: Some(Cow::Borrowed("DepositResult")),
+                        child_names: Some(ChildNames::EnumVariants(BTreeMap::from([
+                            (0, TypeMetadata::no_child_names("Success")),
+                            (1, TypeMetadata::no_child_names("Failure")),
+                        ]))),
+                    },
+                ),
+                // 3: Test_balance_Input
+                (
+                    TypeKind::Tuple {
+                        field_types: vec![],
+                    },
+                    named_fields("Test_balance_Input", &[]),
+                ),
+            ],
+            vec![
+                (
+                    "new",
+                    None,
+                    0,
+                    LocalTypeIndex::WellKnown(COMPONENT_ADDRESS_ID),
+                ),
+                (
+                    "deposit",
+                    Some(Receiver::SelfRefMut),
+                    1,
+                    LocalTypeIndex::SchemaLocalIndex(2),
+                ),
+                (
+                    "balance",
+                    Some(Receiver::SelfRef),
+                    3,
+                    LocalTypeIndex::WellKnown(DECIMAL_ID),
+                ),
+            ],
+        );
+
+        let output = generate_stubs("Test", &schema).unwrap();
+
+        assert_code_eq(
+            output,
+            quote! {
+                #[derive(Debug, PartialEq, Eq, ::scrypto::prelude::ScryptoSbor)]
+                pub enum DepositResult {
+                    Success,
+                    Failure(::scrypto::prelude::String)
+                }
+
+                #[derive(::scrypto::prelude::ScryptoSbor)]
+                pub struct TestBlueprint {
+                    pub package_address: ::scrypto::prelude::PackageAddress,
+                }
+
+                #[allow(dead_code)]
+                impl TestBlueprint {
+                    pub fn at(package_address: ::scrypto::prelude::PackageAddress) -> Self {
+                        Self { package_address }
+                    }
+
+                    pub fn new(&self) -> ::scrypto::prelude::ComponentAddress {
+                        ::scrypto::runtime::Runtime::call_function(
+                            self.package_address,
+                            "Test",
+                            "new",
+                            ::scrypto::prelude::scrypto_args!()
+                        )
+                    }
+                }
+
+                impl From<TestBlueprint> for ::scrypto::prelude::PackageAddress {
+                    fn from(a: TestBlueprint) -> ::scrypto::prelude::PackageAddress {
+                        a.package_address
+                    }
+                }
+
+                #[derive(::scrypto::prelude::ScryptoSbor)]
+                pub struct TestGlobalComponentRef {
+                    pub component_address: ::scrypto::prelude::ComponentAddress,
+                }
+
+                #[allow(dead_code)]
+                impl TestGlobalComponentRef {
+                    pub fn at(component_address: ::scrypto::prelude::ComponentAddress) -> Self {
+                        Self { component_address }
+                    }
+
+                    pub fn balance(&self) -> ::scrypto::prelude::Decimal {
+                        ::scrypto::runtime::Runtime::call_method(
+                            self.component_address,
+                            "balance",
+                            ::scrypto::prelude::scrypto_args!()
+                        )
+                    }
+
+                    pub fn deposit(&mut self, arg0: ::scrypto::prelude::Bucket) -> DepositResult {
+                        ::scrypto::runtime::Runtime::call_method(
+                            self.component_address,
+                            "deposit",
+                            ::scrypto::prelude::scrypto_args!(arg0)
+                        )
+                    }
+                }
+
+                impl From<::scrypto::prelude::ComponentAddress> for TestGlobalComponentRef {
+                    fn from(component_address: ::scrypto::prelude::ComponentAddress) -> Self {
+                        Self { component_address }
+                    }
+                }
+
+                impl From<TestGlobalComponentRef> for ::scrypto::prelude::ComponentAddress {
+                    fn from(a: TestGlobalComponentRef) -> ::scrypto::prelude::ComponentAddress {
+                        a.component_address
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_import_blueprint_types() {
+        let types = vec![
+            // 0: Node { value: u32, next: Option<Node> }
+            (
+                TypeKind::Tuple {
+                    field_types: vec![
+                        LocalTypeIndex::WellKnown(U32_ID),
+                        LocalTypeIndex::SchemaLocalIndex(1),
+                    ],
+                },
+                named_fields("Node", &["value", "next"]),
+            ),
+            // 1: Option<Node>
+            (
+                TypeKind::Enum {
+                    variants: BTreeMap::from([
+                        (0, vec![]),
+                        (1, vec![LocalTypeIndex::SchemaLocalIndex(0)]),
+                    ]),
+                },
+                TypeMetadata::no_child_names("Option"),
+            ),
+            // 2: [u8; 32]
+            (
+                TypeKind::Array {
+                    element_type: LocalTypeIndex::WellKnown(U8_ID),
+                },
+                TypeMetadata::no_child_names("Bytes"),
+            ),
+            // 3: Test_insert_Input
+            (
+                TypeKind::Tuple {
+                    field_types: vec![
+                        LocalTypeIndex::SchemaLocalIndex(0),
+                        LocalTypeIndex::SchemaLocalIndex(2),
+                    ],
+                },
+                named_fields("Test_insert_Input", &["arg0", "arg1"]),
+            ),
+            // 4: (NonFungibleGlobalId, Vec<u8>)
+            (
+                TypeKind::Tuple {
+                    field_types: vec![
+                        LocalTypeIndex::SchemaLocalIndex(5),
+                        LocalTypeIndex::WellKnown(BYTES_ID),
+                    ],
+                },
+                TypeMetadata::unnamed(),
+            ),
+            // 5: NonFungibleGlobalId
+            (
+                TypeKind::Tuple {
+                    field_types: vec![
+                        LocalTypeIndex::WellKnown(RESOURCE_ADDRESS_ID),
+                        LocalTypeIndex::WellKnown(NON_FUNGIBLE_LOCAL_ID_ID),
+                    ],
+                },
+                TypeMetadata::no_child_names("NonFungibleGlobalId"),
+            ),
+        ];
+        let mut schema = blueprint_schema(
+            types,
+            vec![("insert", None, 3, LocalTypeIndex::SchemaLocalIndex(4))],
+        );
+        schema.schema.type_validations[2] = TypeValidation::Array(LengthValidation {
+            min: Some(32),
+            max: Some(32),
+        });
+
+        let mut types = TypeGenerator::new(&schema.schema, []);
+        let (input_args, input_types) = types
+            .input_args(LocalTypeIndex::SchemaLocalIndex(3))
+            .unwrap();
+        let output = types
+            .rust_type(LocalTypeIndex::SchemaLocalIndex(4))
+            .unwrap();
+        let definitions = types.definitions;
+
+        assert_code_eq(
+            quote! {
+                #(#definitions)*
+                fn insert(#(#input_args: #input_types),*) -> #output;
+            },
+            quote! {
+                #[derive(Debug, PartialEq, Eq, ::scrypto::prelude::ScryptoSbor)]
+                pub struct Node {
+                    pub value: u32,
+                    pub next: Option<::scrypto::prelude::Box<Node> >
+                }
+                fn insert(arg0: Node, arg1: [u8; 32usize]) -> (
+                    ::scrypto::prelude::NonFungibleGlobalId,
+                    ::scrypto::prelude::Vec<u8>,
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_import_blueprint_type_named_like_prelude_type() {
+        let types = vec![
+            // 0: Instant { seconds_since_unix_epoch: i64 }, as in the prelude
+            (
+                TypeKind::Tuple {
+                    field_types: vec![LocalTypeIndex::WellKnown(I64_ID)],
+                },
+                named_fields("Instant", &["seconds_since_unix_epoch"]),
+            ),
+            // 1: ResourceType { decimals: u8 }, unlike the prelude enum
+            (
+                TypeKind::Tuple {
+                    field_types: vec![LocalTypeIndex::WellKnown(U8_ID)],
+                },
+                named_fields("ResourceType", &["decimals"]),
+            ),
+            // 2: Test_convert_Input
+            (
+                TypeKind::Tuple {
+                    field_types: vec![
+                        LocalTypeIndex::SchemaLocalIndex(0),
+                        LocalTypeIndex::SchemaLocalIndex(1),
+                    ],
+                },
+                named_fields("Test_convert_Input", &["arg0", "arg1"]),
+            ),
+        ];
+        let schema = blueprint_schema(
+            types,
+            vec![("convert", None, 2, LocalTypeIndex::WellKnown(UNIT_ID))],
+        );
+
+        let mut types = TypeGenerator::new(&schema.schema, []);
+        let (input_args, input_types) = types
+            .input_args(LocalTypeIndex::SchemaLocalIndex(2))
+            .unwrap();
+        let definitions = types.definitions;
+
+        assert_code_eq(
+            quote! {
+                #(#definitions)*
+                fn convert(#(#input_args: #input_types),*);
+            },
+            quote! {
+                #[derive(Debug, PartialEq, Eq, ::scrypto::prelude::ScryptoSbor)]
+                pub struct ResourceType {
+                    pub decimals: u8
+                }
+                fn convert(arg0: ::scrypto::prelude::Instant, arg1: ResourceType);
+            },
+        );
+    }
+}
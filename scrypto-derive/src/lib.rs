@@ -1,5 +1,6 @@
 mod ast;
 mod blueprint;
+mod import_blueprint;
 mod non_fungible_data;
 mod utils;
 
@@ -67,3 +68,37 @@ pub fn non_fungible_data(input: TokenStream) -> TokenStream {
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+/// Imports a blueprint of another package from its schema, generating typed stubs to call it.
+///
+/// The schema file is the SBOR-encoded `PackageSchema` written next to the WASM by
+/// `scrypto build`, or exported from a published package with `resim export-schema`.
+/// Relative paths are resolved against the directory of the crate's `Cargo.toml`.
+///
+/// The macro generates:
+/// - A `<Blueprint>Blueprint` struct, with the functions of the blueprint;
+/// - A `<Blueprint>GlobalComponentRef` struct, with the methods of the blueprint;
+/// - A struct or enum for each named type used by their arguments and outputs, except for the
+///   types of the Scrypto prelude (e.g. `NonFungibleGlobalId`) which the schema describes
+///   exactly the same way.
+///
+/// Types are generated next to the stubs, so blueprints sharing types should be imported
+/// in separate modules.
+///
+/// # Example
+/// ```ignore
+/// use scrypto::prelude::*;
+///
+/// import_blueprint!("schemas/radiswap.schema", "Radiswap");
+///
+/// fn swap(package_address: PackageAddress, a: Bucket, b: Bucket, input: Bucket) -> Bucket {
+///     let component_address = RadiswapBlueprint::at(package_address).new(a, b);
+///     RadiswapGlobalComponentRef::at(component_address).swap(input)
+/// }
+/// ```
+#[proc_macro]
+pub fn import_blueprint(input: TokenStream) -> TokenStream {
+    import_blueprint::handle_import_blueprint(proc_macro2::TokenStream::from(input))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -12,19 +12,20 @@ use radix_engine::kernel::kernel::Kernel;
 use radix_engine::kernel::module_mixer::KernelModuleMixer;
 use radix_engine::kernel::track::Track;
 use radix_engine::ledger::*;
+use radix_engine::state_manager::{CommitFeed, CommitSubscriber, CommittedTransaction};
 use radix_engine::system::kernel_modules::costing::FeeTable;
 use radix_engine::system::kernel_modules::costing::SystemLoanFeeReserve;
-use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
 use radix_engine::transaction::{
-    execute_preview, execute_transaction, ExecutionConfig, FeeReserveConfig, PreviewError,
-    PreviewResult, TransactionReceipt, TransactionResult,
+    estimate_fee, execute_preview, execute_transaction, execute_transaction_batch,
+    BatchExecutionResult, ExecutionConfig, FeeEstimate, FeeEstimationConfig, FeeEstimationError,
+    FeeReserveConfig, JsonLinesLogSink, PreviewError, PreviewResult, TransactionReceipt,
+    TransactionResult,
 };
 use radix_engine::types::*;
 use radix_engine::utils::*;
 use radix_engine::wasm::{DefaultWasmEngine, WasmInstrumenter, WasmMeteringConfig};
 use radix_engine_interface::api::node_modules::auth::*;
 use radix_engine_interface::api::node_modules::metadata::*;
-use radix_engine_interface::api::node_modules::royalty::*;
 use radix_engine_interface::api::types::{RENodeId, VaultOffset};
 use radix_engine_interface::api::ClientObjectApi;
 use radix_engine_interface::blueprints::clock::{
@@ -44,8 +45,11 @@ use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_interface::schema::{BlueprintSchema, FunctionSchema, PackageSchema};
 use radix_engine_interface::time::Instant;
 use radix_engine_interface::{dec, rule};
+use radix_engine_stores::event_store::InMemoryEventStore;
 use radix_engine_stores::hash_tree::tree_store::{TypedInMemoryTreeStore, Version};
-use radix_engine_stores::hash_tree::{put_at_next_version, SubstateHashChange};
+use radix_engine_stores::hash_tree::{
+    prune_stale_nodes, put_at_next_version, to_substate_hash_changes,
+};
 use sbor::basic_well_known_types::{ANY_ID, UNIT_ID};
 use scrypto::modules::Mutability::*;
 use scrypto::prelude::*;
@@ -123,6 +127,7 @@ pub struct TestRunnerBuilder {
     custom_genesis: Option<SystemTransaction>,
     trace: bool,
     state_hashing: bool,
+    event_store: bool,
 }
 
 impl TestRunnerBuilder {
@@ -136,6 +141,13 @@ impl TestRunnerBuilder {
         self
     }
 
+    /// Makes the test runner keep the events of all the committed transactions (see
+    /// `TestRunner::event_store()`).
+    pub fn with_event_store(mut self) -> Self {
+        self.event_store = true;
+        self
+    }
+
     pub fn with_custom_genesis(mut self, genesis: SystemTransaction) -> Self {
         self.custom_genesis = Some(genesis);
         self
@@ -148,7 +160,9 @@ impl TestRunnerBuilder {
                 wasm_engine: DefaultWasmEngine::default(),
                 wasm_instrumenter: WasmInstrumenter::default(),
             },
-            substate_store: TypedInMemorySubstateStore::new(),
+            substate_store: TypedInMemorySubstateStore::new()
+                .with_history()
+                .with_commit_log(),
             state_hash_support: Some(self.state_hashing)
                 .filter(|x| *x)
                 .map(|_| StateHashSupport::new()),
@@ -156,6 +170,11 @@ impl TestRunnerBuilder {
             next_private_key: 1, // 0 is invalid
             next_transaction_nonce: 0,
             trace: self.trace,
+            commit_feed: CommitFeed::new(),
+            event_store: Some(self.event_store)
+                .filter(|x| *x)
+                .map(|_| InMemoryEventStore::new()),
+            log_sink: None,
         };
         let genesis = self
             .custom_genesis
@@ -178,6 +197,9 @@ pub struct TestRunner {
     next_transaction_nonce: u64,
     trace: bool,
     state_hash_support: Option<StateHashSupport>,
+    commit_feed: CommitFeed,
+    event_store: Option<InMemoryEventStore>,
+    log_sink: Option<JsonLinesLogSink<Box<dyn std::io::Write>>>,
 }
 
 impl TestRunner {
@@ -186,6 +208,7 @@ impl TestRunner {
             custom_genesis: None,
             trace: true,
             state_hashing: false,
+            event_store: false,
         }
     }
 
@@ -197,6 +220,23 @@ impl TestRunner {
         &mut self.substate_store
     }
 
+    /// Registers a subscriber notified about every transaction committed from now on (the ones
+    /// committed before can be replayed via `CommitFeed::replay()`).
+    pub fn subscribe_to_commits(&mut self, subscriber: Box<dyn CommitSubscriber>) {
+        self.commit_feed.subscribe(subscriber);
+    }
+
+    /// Returns the store of the events emitted by all the transactions committed so far.
+    pub fn event_store(&self) -> &InMemoryEventStore {
+        self.event_store.as_ref().expect("event store not enabled")
+    }
+
+    /// Writes the application logs of all the transactions committed from now on to the given
+    /// sink, as JSON lines.
+    pub fn set_log_sink(&mut self, log_sink: JsonLinesLogSink<Box<dyn std::io::Write>>) {
+        self.log_sink = Some(log_sink);
+    }
+
     pub fn next_private_key(&mut self) -> u64 {
         self.next_private_key += 1;
         self.next_private_key - 1
@@ -417,6 +457,23 @@ impl TestRunner {
         accounter.into_map()
     }
 
+    /// Returns the resources owned by the component at the given (past) state version.
+    pub fn get_component_resources_at(
+        &mut self,
+        component_address: ComponentAddress,
+        state_version: u64,
+    ) -> HashMap<ResourceAddress, Decimal> {
+        let node_id = RENodeId::GlobalObject(component_address.into());
+        let historical_substate_store = self.substate_store.at_version(state_version).unwrap();
+        let mut accounter = ResourceAccounter::new(&historical_substate_store);
+        accounter.add_resources(node_id).unwrap();
+        accounter.into_map()
+    }
+
+    pub fn get_state_version(&self) -> u64 {
+        self.substate_store.get_state_version()
+    }
+
     pub fn load_account_from_faucet(&mut self, account_address: ComponentAddress) {
         let manifest = ManifestBuilder::new()
             .lock_fee(FAUCET_COMPONENT, 100u32.into())
@@ -459,9 +516,7 @@ impl TestRunner {
         ComponentAddress,
     ) {
         let (pub_key, priv_key) = self.new_key_pair();
-        let account = ComponentAddress::virtual_account_from_public_key(
-            &PublicKey::EcdsaSecp256k1(pub_key.clone()),
-        );
+        let account = ComponentAddress::virtual_account_from_public_key(&pub_key);
         self.load_account_from_faucet(account);
         (pub_key, priv_key, account)
     }
@@ -649,6 +704,27 @@ impl TestRunner {
         self.execute_transaction_with_config(executable, &fee_reserve_config, &execution_config)
     }
 
+    /// Executes the manifest with cost profiling enabled (see `TransactionExecutionTrace::cost_profile`).
+    pub fn execute_manifest_with_cost_profiling(
+        &mut self,
+        manifest: TransactionManifest,
+        initial_proofs: Vec<NonFungibleGlobalId>,
+    ) -> TransactionReceipt {
+        let transactions = TestTransaction::new(
+            manifest,
+            self.next_transaction_nonce(),
+            DEFAULT_COST_UNIT_LIMIT,
+        );
+        let executable = transactions.get_executable(initial_proofs);
+
+        let fee_reserve_config = FeeReserveConfig::default();
+        let execution_config = ExecutionConfig::default()
+            .with_trace(self.trace)
+            .with_cost_profiling(true);
+
+        self.execute_transaction_with_config(executable, &fee_reserve_config, &execution_config)
+    }
+
     pub fn execute_transaction(&mut self, executable: Executable) -> TransactionReceipt {
         let fee_config = FeeReserveConfig::default();
         let execution_config = ExecutionConfig::default().with_trace(self.trace);
@@ -670,14 +746,78 @@ impl TestRunner {
             &executable,
         );
         if let TransactionResult::Commit(commit) = &transaction_receipt.result {
-            let commit_receipt = commit.state_updates.commit(&mut self.substate_store);
+            let committed_transaction = self.commit_feed.commit(
+                &mut self.substate_store,
+                *executable.transaction_hash(),
+                commit,
+            );
+            if let Some(event_store) = &mut self.event_store {
+                event_store.on_commit(&committed_transaction);
+            }
+            if let Some(log_sink) = &mut self.log_sink {
+                log_sink
+                    .write_logs(executable.transaction_hash(), &commit.application_logs)
+                    .expect("Failed to write logs");
+            }
             if let Some(state_hash_support) = &mut self.state_hash_support {
-                state_hash_support.update_with(commit_receipt.outputs);
+                state_hash_support.update_with(&committed_transaction);
             }
         }
         transaction_receipt
     }
 
+    pub fn execute_transaction_batch(
+        &mut self,
+        executables: &[Executable],
+    ) -> BatchExecutionResult {
+        let fee_reserve_config = FeeReserveConfig::default();
+        let execution_config = ExecutionConfig::default()
+            .with_trace(self.trace)
+            .with_skip_unchanged_substates(true);
+
+        self.execute_transaction_batch_with_config(
+            executables,
+            &fee_reserve_config,
+            &execution_config,
+        )
+    }
+
+    pub fn execute_transaction_batch_with_config(
+        &mut self,
+        executables: &[Executable],
+        fee_reserve_config: &FeeReserveConfig,
+        execution_config: &ExecutionConfig,
+    ) -> BatchExecutionResult {
+        let result = execute_transaction_batch(
+            &self.substate_store,
+            &self.scrypto_interpreter,
+            fee_reserve_config,
+            execution_config,
+            executables,
+        );
+        for (executable, receipt) in executables.iter().zip(&result.receipts) {
+            if let TransactionResult::Commit(commit) = &receipt.result {
+                let committed_transaction = self.commit_feed.commit(
+                    &mut self.substate_store,
+                    *executable.transaction_hash(),
+                    commit,
+                );
+                if let Some(event_store) = &mut self.event_store {
+                    event_store.on_commit(&committed_transaction);
+                }
+                if let Some(log_sink) = &mut self.log_sink {
+                    log_sink
+                        .write_logs(executable.transaction_hash(), &commit.application_logs)
+                        .expect("Failed to write logs");
+                }
+                if let Some(state_hash_support) = &mut self.state_hash_support {
+                    state_hash_support.update_with(&committed_transaction);
+                }
+            }
+        }
+        result
+    }
+
     pub fn preview(
         &mut self,
         preview_intent: PreviewIntent,
@@ -692,6 +832,24 @@ impl TestRunner {
         )
     }
 
+    pub fn estimate_fee(
+        &mut self,
+        manifest: TransactionManifest,
+        signer_public_keys: Vec<PublicKey>,
+        network: &NetworkDefinition,
+        config: &FeeEstimationConfig,
+    ) -> Result<FeeEstimate, FeeEstimationError> {
+        estimate_fee(
+            &self.substate_store,
+            &mut self.scrypto_interpreter,
+            &self.intent_hash_manager,
+            network,
+            manifest,
+            signer_public_keys,
+            config,
+        )
+    }
+
     pub fn lock_resource_auth(
         &mut self,
         function: &str,
@@ -1107,85 +1265,10 @@ impl TestRunner {
         &self,
         event_type_identifier: &EventTypeIdentifier,
     ) -> (LocalTypeIndex, ScryptoSchema) {
-        let (package_address, blueprint_name, local_type_index) = match event_type_identifier {
-            EventTypeIdentifier(Emitter::Method(node_id, node_module), local_type_index) => {
-                match node_module {
-                    NodeModuleId::AccessRules | NodeModuleId::AccessRules1 => (
-                        ACCESS_RULES_PACKAGE,
-                        ACCESS_RULES_BLUEPRINT.into(),
-                        local_type_index.clone(),
-                    ),
-                    NodeModuleId::ComponentRoyalty => (
-                        ROYALTY_PACKAGE,
-                        COMPONENT_ROYALTY_BLUEPRINT.into(),
-                        local_type_index.clone(),
-                    ),
-                    NodeModuleId::Metadata => (
-                        METADATA_PACKAGE,
-                        METADATA_BLUEPRINT.into(),
-                        local_type_index.clone(),
-                    ),
-                    NodeModuleId::SELF => {
-                        let type_info = self
-                            .substate_store()
-                            .get_substate(&SubstateId(
-                                *node_id,
-                                NodeModuleId::TypeInfo,
-                                SubstateOffset::TypeInfo(TypeInfoOffset::TypeInfo),
-                            ))
-                            .unwrap()
-                            .substate
-                            .type_info()
-                            .clone();
-
-                        match type_info {
-                            TypeInfoSubstate::Object {
-                                package_address,
-                                blueprint_name,
-                                ..
-                            } => (package_address, blueprint_name, *local_type_index),
-                            TypeInfoSubstate::KeyValueStore(..) => panic!("No event schema."),
-                        }
-                    }
-                    NodeModuleId::TypeInfo => {
-                        panic!("No event schema.")
-                    }
-                }
-            }
-            EventTypeIdentifier(
-                Emitter::Function(node_id, _, blueprint_name),
-                local_type_index,
-            ) => {
-                let RENodeId::GlobalObject(Address::Package(package_address)) = node_id else {
-                    panic!("must be a package address")
-                };
-                (
-                    *package_address,
-                    blueprint_name.to_owned(),
-                    local_type_index.clone(),
-                )
-            }
-        };
-
-        let substate_id = SubstateId(
-            RENodeId::GlobalObject(Address::Package(package_address)),
-            NodeModuleId::SELF,
-            SubstateOffset::Package(PackageOffset::Info),
-        );
-        (
-            local_type_index,
-            self.substate_store()
-                .get_substate(&substate_id)
-                .unwrap()
-                .substate
-                .package_info()
-                .schema
-                .blueprints
-                .get(&blueprint_name)
-                .unwrap()
-                .schema
-                .clone(),
-        )
+        let (resolved, blueprint_schema) =
+            resolve_event_type(self.substate_store(), event_type_identifier)
+                .expect("Failed to resolve the event type");
+        (resolved.local_type_index, blueprint_schema.schema)
     }
 
     pub fn event_name(&self, event_type_identifier: &EventTypeIdentifier) -> String {
@@ -1230,22 +1313,21 @@ impl StateHashSupport {
         }
     }
 
-    pub fn update_with(&mut self, transaction_outputs: Vec<OutputId>) {
-        let hash_changes = transaction_outputs
-            .iter()
-            .map(|output_id| {
-                SubstateHashChange::new(
-                    output_id.substate_id.clone(),
-                    Some(output_id.substate_hash),
-                )
-            })
-            .collect::<Vec<_>>();
+    /// Applies the substates brought up and down by the given transaction (the latter being
+    /// deleted from the tree, unless brought back up).
+    pub fn update_with(&mut self, committed_transaction: &CommittedTransaction) {
+        let hash_changes = to_substate_hash_changes(
+            &committed_transaction.inputs,
+            &committed_transaction.outputs,
+        );
         self.current_hash = put_at_next_version(
             &mut self.tree_store,
             Some(self.current_version).filter(|version| *version > 0),
             hash_changes,
         );
         self.current_version += 1;
+        // only the current state's hash is ever needed, so the older versions can be pruned
+        prune_stale_nodes(&mut self.tree_store, self.current_version);
     }
 
     pub fn get_current(&self) -> Hash {
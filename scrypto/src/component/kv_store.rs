@@ -7,6 +7,7 @@ use radix_engine_interface::data::scrypto::model::*;
 use radix_engine_interface::data::scrypto::well_known_scrypto_custom_types::OWN_KEY_VALUE_STORE_ID;
 use radix_engine_interface::data::scrypto::*;
 use sbor::rust::marker::PhantomData;
+use sbor::rust::vec::Vec;
 use sbor::*;
 use scrypto_schema::KeyValueStoreSchema;
 
@@ -122,6 +123,127 @@ impl<
             .unwrap();
         env.sys_drop_lock(handle).unwrap();
     }
+
+    /// Removes the entry with the given key, returning its value if there was one.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        let mut env = ScryptoEnv;
+        let key_payload = scrypto_encode(key).unwrap();
+        let raw_bytes = env
+            .sys_remove_key_value_store_entry(RENodeId::KeyValueStore(self.id), key_payload)
+            .unwrap();
+
+        let substate: Option<ScryptoValue> = scrypto_decode(&raw_bytes).unwrap();
+        substate.map(|value| scrypto_decode(&scrypto_encode(&value).unwrap()).unwrap())
+    }
+
+    /// Returns the number of entries in this map.
+    ///
+    /// The cost of this grows with the number of entries.
+    pub fn len(&self) -> usize {
+        let mut env = ScryptoEnv;
+        env.sys_count_key_value_store_entries(RENodeId::KeyValueStore(self.id))
+            .unwrap() as usize
+    }
+
+    /// Returns whether this map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.keys(None, 1).is_empty()
+    }
+
+    /// Returns up to `limit` keys, in ascending order of their encoding, starting from the given
+    /// key (inclusive) or from the first key.
+    pub fn keys(&self, from: Option<&K>, limit: u32) -> Vec<K> {
+        let from = from.map(|key| scrypto_encode(key).unwrap());
+        self.list_keys(from, limit)
+            .iter()
+            .map(|key_payload| scrypto_decode(key_payload).unwrap())
+            .collect()
+    }
+
+    /// Returns an iterator over the entries of this map, in ascending order of the encoding of
+    /// their keys.
+    ///
+    /// Keys are loaded one page at a time, so the iteration is only charged for as far as it goes.
+    pub fn iter(&self) -> KeyValueStoreIter<K, V> {
+        KeyValueStoreIter {
+            store: self,
+            page: Vec::new(),
+            next_from: Some(Vec::new()),
+        }
+    }
+
+    fn list_keys(&self, from: Option<Vec<u8>>, limit: u32) -> Vec<Vec<u8>> {
+        let mut env = ScryptoEnv;
+        env.sys_list_key_value_store_keys(RENodeId::KeyValueStore(self.id), from, limit)
+            .unwrap()
+    }
+
+    fn read(&self, key_payload: Vec<u8>) -> Option<V> {
+        let mut env = ScryptoEnv;
+        let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key_payload));
+        let handle = env
+            .sys_lock_substate(
+                RENodeId::KeyValueStore(self.id),
+                offset,
+                LockFlags::read_only(),
+            )
+            .unwrap();
+        let raw_bytes = env.sys_read_substate(handle).unwrap();
+        env.sys_drop_lock(handle).unwrap();
+
+        let substate: Option<ScryptoValue> = scrypto_decode(&raw_bytes).unwrap();
+        substate.map(|value| scrypto_decode(&scrypto_encode(&value).unwrap()).unwrap())
+    }
+}
+
+/// The number of keys loaded at once by a [`KeyValueStoreIter`].
+pub const KEY_VALUE_STORE_ITER_PAGE_SIZE: u32 = 32;
+
+/// An iterator over the entries of a [`KeyValueStore`], created by [`KeyValueStore::iter`].
+pub struct KeyValueStoreIter<
+    'a,
+    K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+> {
+    store: &'a KeyValueStore<K, V>,
+    /// The loaded keys not yet returned, in reverse order.
+    page: Vec<Vec<u8>>,
+    /// Where the next page starts, or `None` once the last page has been loaded.
+    next_from: Option<Vec<u8>>,
+}
+
+impl<
+        'a,
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    > Iterator for KeyValueStoreIter<'a, K, V>
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(key_payload) = self.page.pop() {
+                // Entries removed while iterating are skipped
+                if let Some(value) = self.store.read(key_payload.clone()) {
+                    return Some((scrypto_decode(&key_payload).unwrap(), value));
+                }
+                continue;
+            }
+
+            let from = self.next_from.take()?;
+            let mut page = self
+                .store
+                .list_keys(Some(from), KEY_VALUE_STORE_ITER_PAGE_SIZE);
+            if page.len() == KEY_VALUE_STORE_ITER_PAGE_SIZE as usize {
+                // The smallest encoding greater than the last key
+                let mut next_from = page.last().unwrap().clone();
+                next_from.push(0);
+                self.next_from = Some(next_from);
+            }
+            page.reverse();
+            self.page = page;
+        }
+    }
 }
 
 //========
@@ -0,0 +1,84 @@
+use radix_engine_interface::api::ClientCryptoUtilsApi;
+use radix_engine_interface::crypto::{
+    Bls12381G1PublicKey, EcdsaSecp256k1PublicKey, EddsaEd25519PublicKey, Hash,
+};
+use sbor::rust::prelude::*;
+use scrypto::engine::scrypto_env::ScryptoEnv;
+
+/// Cryptographic utilities, run natively by the engine.
+///
+/// These are much cheaper than verifying signatures or hashing within the blueprint itself.
+#[derive(Debug)]
+pub struct CryptoUtils {}
+
+impl CryptoUtils {
+    /// Verifies an ECDSA secp256k1 signature of the given message, which is hashed with `hash()`
+    /// first, just like a transaction is before being signed.
+    ///
+    /// The signature is the 65 bytes Radix signs transactions with: the recovery id first,
+    /// followed by the 64-byte compact `(r, s)`. Note this differs from the Ethereum layout,
+    /// which puts the recovery id (`v`) last. Malformed signatures are reported as invalid.
+    pub fn verify_ecdsa_secp256k1(
+        message: &[u8],
+        public_key: &EcdsaSecp256k1PublicKey,
+        signature: &[u8],
+    ) -> bool {
+        ScryptoEnv
+            .verify_ecdsa_secp256k1(message.to_vec(), *public_key, signature.to_vec())
+            .unwrap()
+    }
+
+    /// Verifies a 64-byte EdDSA Ed25519 signature of the given message, which is hashed with
+    /// `hash()` first, just like a transaction is before being signed. Malformed signatures are
+    /// reported as invalid.
+    pub fn verify_eddsa_ed25519(
+        message: &[u8],
+        public_key: &EddsaEd25519PublicKey,
+        signature: &[u8],
+    ) -> bool {
+        ScryptoEnv
+            .verify_eddsa_ed25519(message.to_vec(), *public_key, signature.to_vec())
+            .unwrap()
+    }
+
+    /// Verifies a BLS12-381 G2 signature of the given message hash. Malformed signatures are
+    /// reported as invalid.
+    pub fn verify_bls12381(
+        message_hash: &Hash,
+        public_key: &Bls12381G1PublicKey,
+        signature: &[u8],
+    ) -> bool {
+        ScryptoEnv
+            .verify_bls12381(*message_hash, *public_key, signature.to_vec())
+            .unwrap()
+    }
+
+    /// Verifies an aggregate BLS12-381 G2 signature of the same message hash by each of the given
+    /// public keys, each paired with a proof of its possession (which rules out forging the
+    /// aggregate with a rogue key). Invalid proofs, as well as malformed signatures or keys, are
+    /// reported as invalid.
+    pub fn aggregate_verify_bls12381(
+        message_hash: &Hash,
+        public_keys: &[(Bls12381G1PublicKey, Vec<u8>)],
+        signature: &[u8],
+    ) -> bool {
+        ScryptoEnv
+            .aggregate_verify_bls12381(*message_hash, public_keys.to_vec(), signature.to_vec())
+            .unwrap()
+    }
+
+    /// Computes the Keccak-256 hash of the given data, as used by Ethereum.
+    pub fn keccak256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoEnv.keccak256_hash(data.as_ref().to_vec()).unwrap()
+    }
+
+    /// Computes the SHA-256 hash of the given data.
+    pub fn sha256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoEnv.sha256_hash(data.as_ref().to_vec()).unwrap()
+    }
+
+    /// Computes the Blake2b-256 hash of the given data, as used by Radix Engine.
+    pub fn blake2b_256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoEnv.blake2b_256_hash(data.as_ref().to_vec()).unwrap()
+    }
+}
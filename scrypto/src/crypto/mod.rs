@@ -0,0 +1,4 @@
+mod crypto_utils;
+
+pub use crypto_utils::*;
+pub use radix_engine_interface::crypto::*;
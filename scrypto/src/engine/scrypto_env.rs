@@ -1,8 +1,12 @@
 use crate::engine::wasm_api::*;
 use radix_engine_interface::api::{types::*, ClientTransactionRuntimeApi};
 use radix_engine_interface::api::{ClientActorApi, ClientObjectApi, ClientSubstateApi};
-use radix_engine_interface::api::{ClientEventApi, ClientLoggerApi, LockFlags};
-use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::api::{
+    ClientCryptoUtilsApi, ClientEventApi, ClientLoggerApi, LockFlags,
+};
+use radix_engine_interface::crypto::{
+    Bls12381G1PublicKey, EcdsaSecp256k1PublicKey, EddsaEd25519PublicKey, Hash,
+};
 use radix_engine_interface::data::scrypto::model::{Address, PackageAddress};
 use radix_engine_interface::data::scrypto::*;
 use radix_engine_interface::*;
@@ -221,6 +225,53 @@ impl ClientSubstateApi<ClientApiError> for ScryptoEnv {
 
         Ok(())
     }
+
+    fn sys_remove_key_value_store_entry(
+        &mut self,
+        node_id: RENodeId,
+        key: Vec<u8>,
+    ) -> Result<Vec<u8>, ClientApiError> {
+        let node_id = scrypto_encode(&node_id).unwrap();
+
+        let entry = copy_buffer(unsafe {
+            remove_key_value_store_entry(node_id.as_ptr(), node_id.len(), key.as_ptr(), key.len())
+        });
+
+        Ok(entry)
+    }
+
+    fn sys_list_key_value_store_keys(
+        &mut self,
+        node_id: RENodeId,
+        from: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<Vec<u8>>, ClientApiError> {
+        let node_id = scrypto_encode(&node_id).unwrap();
+        let from = scrypto_encode(&from).unwrap();
+
+        let bytes = copy_buffer(unsafe {
+            list_key_value_store_keys(
+                node_id.as_ptr(),
+                node_id.len(),
+                from.as_ptr(),
+                from.len(),
+                limit,
+            )
+        });
+
+        scrypto_decode(&bytes).map_err(ClientApiError::DecodeError)
+    }
+
+    fn sys_count_key_value_store_entries(
+        &mut self,
+        node_id: RENodeId,
+    ) -> Result<u32, ClientApiError> {
+        let node_id = scrypto_encode(&node_id).unwrap();
+
+        let count = unsafe { count_key_value_store_entries(node_id.as_ptr(), node_id.len()) };
+
+        Ok(count)
+    }
 }
 
 impl ClientActorApi<ClientApiError> for ScryptoEnv {
@@ -255,6 +306,27 @@ impl ClientLoggerApi<ClientApiError> for ScryptoEnv {
         unsafe { log_message(level.as_ptr(), level.len(), message.as_ptr(), message.len()) }
         Ok(())
     }
+
+    fn log_structured_message(
+        &mut self,
+        level: Level,
+        message: String,
+        fields: Vec<(String, String)>,
+    ) -> Result<(), ClientApiError> {
+        let level = scrypto_encode(&level).unwrap();
+        let fields = scrypto_encode(&fields).unwrap();
+        unsafe {
+            log_structured_message(
+                level.as_ptr(),
+                level.len(),
+                message.as_ptr(),
+                message.len(),
+                fields.as_ptr(),
+                fields.len(),
+            )
+        }
+        Ok(())
+    }
 }
 
 impl ClientTransactionRuntimeApi<ClientApiError> for ScryptoEnv {
@@ -271,6 +343,108 @@ impl ClientTransactionRuntimeApi<ClientApiError> for ScryptoEnv {
     }
 }
 
+impl ClientCryptoUtilsApi<ClientApiError> for ScryptoEnv {
+    fn verify_ecdsa_secp256k1(
+        &mut self,
+        message: Vec<u8>,
+        public_key: EcdsaSecp256k1PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, ClientApiError> {
+        let is_valid = unsafe {
+            verify_ecdsa_secp256k1(
+                message.as_ptr(),
+                message.len(),
+                public_key.0.as_ptr(),
+                public_key.0.len(),
+                signature.as_ptr(),
+                signature.len(),
+            )
+        };
+
+        Ok(is_valid != 0)
+    }
+
+    fn verify_eddsa_ed25519(
+        &mut self,
+        message: Vec<u8>,
+        public_key: EddsaEd25519PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, ClientApiError> {
+        let is_valid = unsafe {
+            verify_eddsa_ed25519(
+                message.as_ptr(),
+                message.len(),
+                public_key.0.as_ptr(),
+                public_key.0.len(),
+                signature.as_ptr(),
+                signature.len(),
+            )
+        };
+
+        Ok(is_valid != 0)
+    }
+
+    fn verify_bls12381(
+        &mut self,
+        message_hash: Hash,
+        public_key: Bls12381G1PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, ClientApiError> {
+        let is_valid = unsafe {
+            verify_bls12381(
+                message_hash.0.as_ptr(),
+                message_hash.0.len(),
+                public_key.0.as_ptr(),
+                public_key.0.len(),
+                signature.as_ptr(),
+                signature.len(),
+            )
+        };
+
+        Ok(is_valid != 0)
+    }
+
+    fn aggregate_verify_bls12381(
+        &mut self,
+        message_hash: Hash,
+        public_keys: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+        signature: Vec<u8>,
+    ) -> Result<bool, ClientApiError> {
+        let public_keys = scrypto_encode(&public_keys).unwrap();
+
+        let is_valid = unsafe {
+            aggregate_verify_bls12381(
+                message_hash.0.as_ptr(),
+                message_hash.0.len(),
+                public_keys.as_ptr(),
+                public_keys.len(),
+                signature.as_ptr(),
+                signature.len(),
+            )
+        };
+
+        Ok(is_valid != 0)
+    }
+
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Hash, ClientApiError> {
+        let hash = copy_buffer(unsafe { keccak256_hash(data.as_ptr(), data.len()) });
+
+        scrypto_decode(&hash).map_err(ClientApiError::DecodeError)
+    }
+
+    fn sha256_hash(&mut self, data: Vec<u8>) -> Result<Hash, ClientApiError> {
+        let hash = copy_buffer(unsafe { sha256_hash(data.as_ptr(), data.len()) });
+
+        scrypto_decode(&hash).map_err(ClientApiError::DecodeError)
+    }
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Hash, ClientApiError> {
+        let hash = copy_buffer(unsafe { blake2b_256_hash(data.as_ptr(), data.len()) });
+
+        scrypto_decode(&hash).map_err(ClientApiError::DecodeError)
+    }
+}
+
 #[macro_export]
 macro_rules! scrypto_env_native_fn {
     ($($vis:vis $fn:ident $fn_name:ident ($($args:tt)*) -> $rtn:ty { $arg:expr })*) => {
@@ -132,6 +132,26 @@ extern "C" {
     // Releases a lock
     pub fn drop_lock(handle: u32);
 
+    // Removes an entry from a key-value store, returning its former value
+    pub fn remove_key_value_store_entry(
+        node_id_ptr: *const u8,
+        node_id_len: usize,
+        key_ptr: *const u8,
+        key_len: usize,
+    ) -> Buffer;
+
+    // Lists the keys of a key-value store's entries, in ascending order of their encoding
+    pub fn list_key_value_store_keys(
+        node_id_ptr: *const u8,
+        node_id_len: usize,
+        from_ptr: *const u8,
+        from_len: usize,
+        limit: u32,
+    ) -> Buffer;
+
+    // Counts the entries of a key-value store
+    pub fn count_key_value_store_entries(node_id_ptr: *const u8, node_id_len: usize) -> u32;
+
     //===============
     // Actor API
     //===============
@@ -161,9 +181,68 @@ extern "C" {
         message_len: usize,
     );
 
+    pub fn log_structured_message(
+        level_ptr: *const u8,
+        level_len: usize,
+        message_ptr: *const u8,
+        message_len: usize,
+        fields_ptr: *const u8,
+        fields_len: usize,
+    );
+
     pub fn get_transaction_hash() -> Buffer;
 
     pub fn generate_uuid() -> Buffer;
+
+    // Verifies an ECDSA secp256k1 signature
+    pub fn verify_ecdsa_secp256k1(
+        message_ptr: *const u8,
+        message_len: usize,
+        public_key_ptr: *const u8,
+        public_key_len: usize,
+        signature_ptr: *const u8,
+        signature_len: usize,
+    ) -> u32;
+
+    // Verifies an EdDSA Ed25519 signature
+    pub fn verify_eddsa_ed25519(
+        message_ptr: *const u8,
+        message_len: usize,
+        public_key_ptr: *const u8,
+        public_key_len: usize,
+        signature_ptr: *const u8,
+        signature_len: usize,
+    ) -> u32;
+
+    // Computes the Keccak-256 hash of data
+    pub fn keccak256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+
+    // Computes the SHA-256 hash of data
+    pub fn sha256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+
+    // Computes the Blake2b-256 hash of data
+    pub fn blake2b_256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+
+    // Verifies a BLS12-381 G2 signature
+    pub fn verify_bls12381(
+        message_hash_ptr: *const u8,
+        message_hash_len: usize,
+        public_key_ptr: *const u8,
+        public_key_len: usize,
+        signature_ptr: *const u8,
+        signature_len: usize,
+    ) -> u32;
+
+    // Verifies an aggregate BLS12-381 G2 signature, given scrypto-encoded public keys paired with
+    // their proofs of possession
+    pub fn aggregate_verify_bls12381(
+        message_hash_ptr: *const u8,
+        message_hash_len: usize,
+        public_keys_ptr: *const u8,
+        public_keys_len: usize,
+        signature_ptr: *const u8,
+        signature_len: usize,
+    ) -> u32;
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -296,6 +375,32 @@ pub unsafe fn drop_lock(_handle: u32) {
     unreachable!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn remove_key_value_store_entry(
+    _node_id_ptr: *const u8,
+    _node_id_len: usize,
+    _key_ptr: *const u8,
+    _key_len: usize,
+) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn list_key_value_store_keys(
+    _node_id_ptr: *const u8,
+    _node_id_len: usize,
+    _from_ptr: *const u8,
+    _from_len: usize,
+    _limit: u32,
+) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn count_key_value_store_entries(_node_id_ptr: *const u8, _node_id_len: usize) -> u32 {
+    unreachable!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn get_actor() -> Buffer {
     unreachable!()
@@ -321,6 +426,18 @@ pub unsafe fn log_message(
     unreachable!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn log_structured_message(
+    _level_ptr: *const u8,
+    _level_len: usize,
+    _message_ptr: *const u8,
+    _message_len: usize,
+    _fields_ptr: *const u8,
+    _fields_len: usize,
+) {
+    unreachable!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn get_transaction_hash() -> Buffer {
     unreachable!()
@@ -330,3 +447,66 @@ pub unsafe fn get_transaction_hash() -> Buffer {
 pub unsafe fn generate_uuid() -> Buffer {
     unreachable!()
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn verify_ecdsa_secp256k1(
+    _message_ptr: *const u8,
+    _message_len: usize,
+    _public_key_ptr: *const u8,
+    _public_key_len: usize,
+    _signature_ptr: *const u8,
+    _signature_len: usize,
+) -> u32 {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn verify_eddsa_ed25519(
+    _message_ptr: *const u8,
+    _message_len: usize,
+    _public_key_ptr: *const u8,
+    _public_key_len: usize,
+    _signature_ptr: *const u8,
+    _signature_len: usize,
+) -> u32 {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn keccak256_hash(_data_ptr: *const u8, _data_len: usize) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn sha256_hash(_data_ptr: *const u8, _data_len: usize) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn blake2b_256_hash(_data_ptr: *const u8, _data_len: usize) -> Buffer {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn verify_bls12381(
+    _message_hash_ptr: *const u8,
+    _message_hash_len: usize,
+    _public_key_ptr: *const u8,
+    _public_key_len: usize,
+    _signature_ptr: *const u8,
+    _signature_len: usize,
+) -> u32 {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn aggregate_verify_bls12381(
+    _message_hash_ptr: *const u8,
+    _message_hash_len: usize,
+    _public_keys_ptr: *const u8,
+    _public_keys_len: usize,
+    _signature_ptr: *const u8,
+    _signature_len: usize,
+) -> u32 {
+    unreachable!()
+}
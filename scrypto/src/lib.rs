@@ -18,6 +18,8 @@ compile_error!("Feature `std` and `alloc` can't be enabled at the same time.");
 
 /// Scrypto component abstraction.
 pub mod component;
+/// Scrypto cryptography.
+pub mod crypto;
 /// Scrypto engine abstraction.
 pub mod engine;
 /// Scrypto module abstraction.
@@ -35,12 +37,12 @@ pub use macros::*;
 
 // Re-export Scrypto derive.
 extern crate scrypto_derive;
-pub use scrypto_derive::{blueprint, NonFungibleData};
+pub use scrypto_derive::{blueprint, import_blueprint, NonFungibleData};
 
 // Re-export Radix Engine Interface modules.
 extern crate radix_engine_interface;
 pub use radix_engine_interface::{
-    address, api, blueprints, constants, crypto, data, math, network, schema, time,
+    address, api, blueprints, constants, data, math, network, schema, time,
 };
 
 // This is to make derives work within this crate.
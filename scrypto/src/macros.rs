@@ -73,6 +73,28 @@ macro_rules! trace {
     }};
 }
 
+/// Logs a message at the given level, along with key/value fields.
+///
+/// # Example
+/// ```no_run
+/// use scrypto::prelude::*;
+///
+/// log!(Level::Info, "Swapped", amount = 100, pool = "XRD/USD");
+/// ```
+#[macro_export]
+macro_rules! log {
+    ($level: expr, $message: expr $(, $key: ident = $value: expr)* $(,)?) => {{
+        $crate::runtime::Logger::log_structured_message(
+            $level,
+            ::sbor::rust::string::ToString::to_string(&$message),
+            ::sbor::rust::vec![$((
+                ::sbor::rust::string::ToString::to_string(::core::stringify!($key)),
+                ::sbor::rust::format!("{}", $value),
+            )),*],
+        );
+    }};
+}
+
 #[macro_export]
 macro_rules! this_package {
     () => {
@@ -195,6 +217,7 @@ macro_rules! include_schema {
 ///
 /// - Replaces the import! macro for importing an schema, using a more concise, readable syntax.
 /// - Similar to the `external_component` macro, which is used for making cross-component calls to an already-instantiated component.
+/// - The `import_blueprint` macro generates the same kind of stubs from a package schema, with argument and output types checked against it.
 #[macro_export]
 macro_rules! external_blueprint {
     (
@@ -336,6 +359,7 @@ macro_rules! external_blueprint_members {
 /// # Related
 ///
 /// - Similar to the [external_blueprint] macro, but the external_component can be used without knowing the package and blueprint addresses.
+/// - The `import_blueprint` macro generates the same kind of stubs from a package schema, with argument and output types checked against it.
 #[macro_export]
 macro_rules! external_component {
     (
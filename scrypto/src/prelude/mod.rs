@@ -3,14 +3,15 @@
 //=============
 
 pub use crate::component::*;
+pub use crate::crypto::CryptoUtils;
 pub use crate::engine::*;
 pub use crate::modules::*;
 pub use crate::resource::*;
 pub use crate::runtime::*;
 pub use crate::{
     blueprint, borrow_component, borrow_package, borrow_resource_manager, debug, error,
-    external_blueprint, external_component, include_code, include_schema, info, resource_list,
-    this_package, trace, warn, NonFungibleData,
+    external_blueprint, external_component, import_blueprint, include_code, include_schema, info,
+    log, resource_list, this_package, trace, warn, NonFungibleData,
 };
 
 //=========================
@@ -1,5 +1,6 @@
 use radix_engine_interface::{api::types::Level, api::ClientLoggerApi};
 use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
 
 use crate::engine::scrypto_env::ScryptoEnv;
 
@@ -13,6 +14,13 @@ impl Logger {
         ScryptoEnv.log_message(level, message).unwrap();
     }
 
+    /// Emits a log with key/value fields to console.
+    pub fn log_structured_message(level: Level, message: String, fields: Vec<(String, String)>) {
+        ScryptoEnv
+            .log_structured_message(level, message, fields)
+            .unwrap();
+    }
+
     /// Emits a trace message.
     pub fn trace(message: String) {
         Self::log_message(Level::Trace, message);
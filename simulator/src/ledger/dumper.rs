@@ -3,7 +3,7 @@ use colored::*;
 use radix_engine::blueprints::resource::*;
 use radix_engine::ledger::*;
 use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
-use radix_engine::system::node_substates::RuntimeSubstate;
+use radix_engine::system::node_substates::{PersistedSubstate, RuntimeSubstate};
 use radix_engine::types::*;
 use radix_engine_interface::address::AddressDisplayContext;
 use radix_engine_interface::api::component::*;
@@ -333,7 +333,21 @@ fn dump_kv_store<T: ReadableSubstateStore + QueryableSubstateStore, O: std::io::
     let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
     let mut owned_kv_stores = Vec::new();
     let mut owned_vaults = Vec::new();
-    let map = substate_store.get_kv_store_entries(kv_store_id);
+    let entries: Vec<(Vec<u8>, PersistedSubstate)> = substate_store
+        .list_substates(
+            &RENodeId::KeyValueStore(*kv_store_id),
+            Some(NodeModuleId::SELF),
+            None,
+            usize::MAX,
+        )
+        .into_iter()
+        .filter_map(|(SubstateId(_, _, offset), output_value)| match offset {
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)) => {
+                Some((key, output_value.substate))
+            }
+            _ => None,
+        })
+        .collect();
     writeln!(
         output,
         "{}: {}, {}",
@@ -341,7 +355,7 @@ fn dump_kv_store<T: ReadableSubstateStore + QueryableSubstateStore, O: std::io::
         component_address.to_string(AddressDisplayContext::with_encoder(&bech32_encoder)),
         hex::encode(kv_store_id)
     );
-    for (last, (key, substate)) in map.iter().identify_last() {
+    for (last, (key, substate)) in entries.iter().identify_last() {
         let substate = substate.clone().to_runtime();
         if let Option::Some(value) = &substate.kv_store_entry() {
             let key: ScryptoValue = scrypto_decode(&key).unwrap();
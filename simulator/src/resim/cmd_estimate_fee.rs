@@ -0,0 +1,151 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::transaction::{
+    estimate_fee, CommitResult, FeeEstimationConfig, FeeEstimationError, PreviewError,
+};
+use transaction::validation::TestIntentHashManager;
+
+use crate::resim::*;
+
+/// Estimates the cost unit limit and the amount of XRD to lock for a transaction manifest
+#[derive(Parser, Debug)]
+pub struct EstimateFee {
+    /// The path to a transaction manifest file
+    pub path: PathBuf,
+
+    /// The network to use, [simulator | adapanet | nebunet | mainnet]
+    #[clap(short, long)]
+    pub network: Option<String>,
+
+    /// The paths to blobs
+    #[clap(short, long, multiple = true)]
+    pub blobs: Option<Vec<String>>,
+
+    /// The private keys used for signing, separated by comma (the first one being the notary)
+    #[clap(short, long)]
+    pub signing_keys: Option<String>,
+
+    /// The tip percentage the transaction is going to be submitted with
+    #[clap(long, default_value = "0")]
+    pub tip_percentage: u16,
+
+    /// The percentage added on top of the estimated costs
+    #[clap(long, default_value = "20")]
+    pub safety_margin: u16,
+}
+
+impl EstimateFee {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let manifest = std::fs::read_to_string(&self.path).map_err(Error::IOError)?;
+        let pre_processed_manifest = Run::pre_process_manifest(&manifest);
+        let network = match &self.network {
+            Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
+            None => NetworkDefinition::simulator(),
+        };
+        let mut blobs = Vec::new();
+        if let Some(paths) = &self.blobs {
+            for path in paths {
+                blobs.push(std::fs::read(path).map_err(Error::IOError)?);
+            }
+        }
+        let compiled_manifest =
+            transaction::manifest::compile(&pre_processed_manifest, &network, blobs)
+                .map_err(Error::CompileError)?;
+        let signer_public_keys = get_signing_keys(&self.signing_keys)?
+            .into_iter()
+            .map(|private_key| private_key.public_key().into())
+            .collect();
+
+        let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+        let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter)
+            .map_err(Error::LedgerOpenError)?;
+        let estimate = estimate_fee(
+            &substate_store,
+            &scrypto_interpreter,
+            &TestIntentHashManager::new(),
+            &network,
+            compiled_manifest,
+            signer_public_keys,
+            &FeeEstimationConfig {
+                tip_percentage: self.tip_percentage,
+                safety_margin_percentage: self.safety_margin,
+                ..Default::default()
+            },
+        )
+        .map_err(|error| match error {
+            FeeEstimationError::NoSigners => Error::NoDefaultPrivateKey,
+            FeeEstimationError::PreviewError(PreviewError::TransactionValidationError(e)) => {
+                Error::TransactionValidationError(e)
+            }
+            FeeEstimationError::TransactionNotCommittedSuccessfully(receipt) => {
+                match &receipt.result {
+                    TransactionResult::Commit(CommitResult {
+                        outcome: TransactionOutcome::Failure(error),
+                        ..
+                    }) => Error::TransactionFailed(error.clone()),
+                    TransactionResult::Reject(rejection) => {
+                        Error::TransactionRejected(rejection.error.clone())
+                    }
+                    TransactionResult::Abort(result) => {
+                        Error::TransactionAborted(result.reason.clone())
+                    }
+                    // A successful transaction is never reported, but is passed on if it ever is
+                    TransactionResult::Commit(..) => Error::FeeEstimationError(
+                        FeeEstimationError::TransactionNotCommittedSuccessfully(receipt),
+                    ),
+                }
+            }
+        })?;
+
+        writeln!(
+            out,
+            "{}: {}",
+            "Execution Cost Units".green().bold(),
+            estimate.execution_cost_sum
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{}: {}",
+            "Signature Verification Cost Units".green().bold(),
+            estimate.signature_cost
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{}: {}",
+            "Payload Cost Units".green().bold(),
+            estimate.payload_cost
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{}: {}",
+            "Royalty Cost Units".green().bold(),
+            estimate.royalty_cost_sum()
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{}: {}",
+            "Storage XRD".green().bold(),
+            estimate.storage_cost_xrd
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{}: {}",
+            "Recommended Cost Unit Limit".green().bold(),
+            estimate.recommended_cost_unit_limit
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{}: {}",
+            "Recommended Lock Fee XRD".green().bold(),
+            estimate.recommended_lock_fee_xrd
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
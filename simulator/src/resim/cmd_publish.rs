@@ -1,6 +1,7 @@
 use clap::Parser;
 use colored::*;
-use radix_engine::ledger::{OutputValue, ReadableSubstateStore, WriteableSubstateStore};
+use radix_engine::ledger::{OutputValue, ReadableSubstateStore};
+use radix_engine::state_manager::StateDiff;
 use radix_engine::system::node_substates::PersistedSubstate;
 use radix_engine::types::*;
 use radix_engine_interface::api::types::RENodeId;
@@ -63,7 +64,8 @@ impl Publish {
         if let Some(package_address) = self.package_address.clone() {
             let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
             let mut substate_store =
-                RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
+                RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter)
+                    .map_err(Error::LedgerOpenError)?;
 
             let substate_id = SubstateId(
                 RENodeId::GlobalObject(package_address.0.into()),
@@ -83,7 +85,8 @@ impl Publish {
 
             // Overwrite package
             // TODO: implement real package overwrite
-            substate_store.put_substate(
+            let mut state_diff = StateDiff::new();
+            state_diff.up_substates.insert(
                 SubstateId(
                     RENodeId::GlobalObject(package_address.0.into()),
                     NodeModuleId::SELF,
@@ -92,10 +95,20 @@ impl Publish {
                 output_value,
             );
 
+            let info_substate_id = SubstateId(
+                RENodeId::GlobalObject(package_address.0.into()),
+                NodeModuleId::SELF,
+                SubstateOffset::Package(PackageOffset::Info),
+            );
+            let upgrade_info = substate_store
+                .get_substate(&info_substate_id)
+                .and_then(|output| output.substate.package_info().upgrade_info.clone());
+
             let package_info = PackageInfoSubstate {
                 schema,
                 dependent_resources: BTreeSet::new(),
                 dependent_components: BTreeSet::new(),
+                upgrade_info,
             };
 
             let output_value = OutputValue {
@@ -103,14 +116,12 @@ impl Publish {
                 version: previous_version.unwrap_or(0),
             };
 
-            substate_store.put_substate(
-                SubstateId(
-                    RENodeId::GlobalObject(package_address.0.into()),
-                    NodeModuleId::SELF,
-                    SubstateOffset::Package(PackageOffset::Info),
-                ),
-                output_value,
-            );
+            state_diff
+                .up_substates
+                .insert(info_substate_id, output_value);
+
+            // Commit both substates at once, so that the ledger's state hash is maintained
+            state_diff.commit(&mut substate_store);
 
             writeln!(out, "Package updated!").map_err(Error::IOError)?;
         } else {
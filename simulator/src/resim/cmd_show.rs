@@ -15,7 +15,8 @@ pub struct Show {
 impl Show {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
         let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
-        let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
+        let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter)
+            .map_err(Error::LedgerOpenError)?;
         let bech32_decoder = Bech32Decoder::new(&NetworkDefinition::simulator());
 
         if let Ok(package_address) =
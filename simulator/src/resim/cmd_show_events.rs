@@ -0,0 +1,69 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::transaction::resolve_event_type;
+use radix_engine::types::*;
+use radix_engine_interface::api::types::IndexedScryptoValue;
+use radix_engine_interface::data::scrypto::ScryptoValueDisplayContext;
+use radix_engine_stores::event_store::{EventQuery, EventStore};
+use radix_engine_stores::rocks_db::RadixEngineDB;
+use utils::ContextualDisplay;
+
+use crate::resim::*;
+
+/// Show the events emitted by the committed transactions
+#[derive(Parser, Debug)]
+pub struct ShowEvents {
+    /// Only show the events emitted by this component
+    #[clap(long)]
+    pub component: Option<SimulatorComponentAddress>,
+
+    /// Only show the events committed at or after this state version
+    #[clap(long, default_value = "0")]
+    pub since: u64,
+}
+
+impl ShowEvents {
+    /// The number of events read at once.
+    const PAGE_SIZE: usize = 100;
+
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+        let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter)
+            .map_err(Error::LedgerOpenError)?;
+        let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
+
+        let mut query = EventQuery::new(Self::PAGE_SIZE).since(self.since);
+        if let Some(component) = &self.component {
+            query = query.emitter(RENodeId::GlobalObject(component.0.into()));
+        }
+        loop {
+            let page = substate_store.query_events(&query);
+            for event in &page.events {
+                let event_name =
+                    match resolve_event_type(&substate_store, &event.event_type_identifier) {
+                        Ok((resolved, _)) => resolved.event_name,
+                        Err(_) => format!("{:?}", event.event_type_identifier.1),
+                    };
+                let event_data = IndexedScryptoValue::from_slice(&event.event_data)
+                    .expect("Event must be decodable!");
+                writeln!(
+                    out,
+                    "{} {} {}: {}",
+                    format!("[{}#{}]", event.cursor.state_version, event.cursor.index)
+                        .green()
+                        .bold(),
+                    event.event_type_identifier.0.display(&bech32_encoder),
+                    event_name.bold(),
+                    event_data.display(ScryptoValueDisplayContext::with_optional_bench32(Some(
+                        &bech32_encoder
+                    )))
+                )
+                .map_err(Error::IOError)?;
+            }
+            match page.next_cursor {
+                Some(cursor) => query = query.from_cursor(cursor),
+                None => return Ok(()),
+            }
+        }
+    }
+}
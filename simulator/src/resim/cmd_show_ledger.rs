@@ -5,7 +5,7 @@ use radix_engine_interface::blueprints::clock::*;
 use radix_engine_interface::blueprints::epoch_manager::*;
 use radix_engine_interface::time::Instant;
 use radix_engine_interface::time::UtcDateTime;
-use radix_engine_stores::rocks_db::RadixEngineDB;
+use radix_engine_stores::rocks_db::{LastCommittedTransaction, RadixEngineDB};
 use transaction::model::Instruction;
 use utils::ContextualDisplay;
 
@@ -19,7 +19,8 @@ pub struct ShowLedger {}
 impl ShowLedger {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
         let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
-        let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
+        let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter)
+            .map_err(Error::LedgerOpenError)?;
         let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
 
         writeln!(out, "{}:", "Packages".green().bold()).map_err(Error::IOError)?;
@@ -59,9 +60,32 @@ impl ShowLedger {
             .map_err(Error::IOError)?;
         }
 
+        let state_hash = substate_store.get_state_hash();
+        let last_committed_transaction = substate_store.get_last_committed_transaction();
+
         // Close the database
         drop(substate_store);
 
+        if let Some((state_version, state_root_hash)) = state_hash {
+            writeln!(out, "{}: {}", "State Version".green().bold(), state_version)
+                .map_err(Error::IOError)?;
+            writeln!(out, "{}: {}", "State Hash".green().bold(), state_root_hash)
+                .map_err(Error::IOError)?;
+        }
+        if let Some(LastCommittedTransaction {
+            transaction_hash: Some(transaction_hash),
+            ..
+        }) = last_committed_transaction
+        {
+            writeln!(
+                out,
+                "{}: {}",
+                "Last Committed Transaction".green().bold(),
+                transaction_hash
+            )
+            .map_err(Error::IOError)?;
+        }
+
         let current_epoch = Self::get_current_epoch(out)?;
         writeln!(out, "{}: {}", "Current Epoch".green().bold(), current_epoch)
             .map_err(Error::IOError)?;
@@ -2,12 +2,13 @@ use std::io;
 use std::path::PathBuf;
 
 use radix_engine::errors::{RejectionError, RuntimeError};
-use radix_engine::transaction::AbortReason;
+use radix_engine::transaction::{AbortReason, FeeEstimationError};
 use radix_engine::types::{AddressError, ComponentAddress, PackageAddress};
 use radix_engine::utils::ExtractSchemaError;
 use radix_engine::wasm::PrepareError;
 use radix_engine_interface::blueprints::resource::ParseNonFungibleGlobalIdError;
 use radix_engine_interface::network::ParseNetworkError;
+use radix_engine_stores::rocks_db::RadixEngineDBError;
 use sbor::*;
 use transaction::errors::*;
 
@@ -51,6 +52,10 @@ pub enum Error {
 
     TransactionAborted(AbortReason),
 
+    FeeEstimationError(FeeEstimationError),
+
+    LedgerOpenError(RadixEngineDBError),
+
     LedgerDumpError(DisplayError),
 
     CompileError(transaction::manifest::CompileError),
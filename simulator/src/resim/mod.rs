@@ -1,6 +1,7 @@
 mod addressing;
 mod cmd_call_function;
 mod cmd_call_method;
+mod cmd_estimate_fee;
 mod cmd_export_schema;
 mod cmd_generate_key_pair;
 mod cmd_mint;
@@ -18,6 +19,7 @@ mod cmd_set_current_time;
 mod cmd_set_default_account;
 mod cmd_show;
 mod cmd_show_configs;
+mod cmd_show_events;
 mod cmd_show_ledger;
 mod cmd_transfer;
 mod config;
@@ -26,6 +28,7 @@ mod error;
 pub use addressing::*;
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
+pub use cmd_estimate_fee::*;
 pub use cmd_export_schema::*;
 pub use cmd_generate_key_pair::*;
 pub use cmd_mint::*;
@@ -43,6 +46,7 @@ pub use cmd_set_current_time::*;
 pub use cmd_set_default_account::*;
 pub use cmd_show::*;
 pub use cmd_show_configs::*;
+pub use cmd_show_events::*;
 pub use cmd_show_ledger::*;
 pub use cmd_transfer::*;
 pub use config::*;
@@ -51,12 +55,16 @@ pub use error::*;
 pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
 pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
+pub const ENV_LOG_FILE: &'static str = "RESIM_LOG_FILE";
 
 use clap::{Parser, Subcommand};
 use radix_engine::kernel::interpreters::ScryptoInterpreter;
 use radix_engine::ledger::ReadableSubstateStore;
+use radix_engine::state_manager::CommitLogStore;
+use radix_engine::system::kernel_modules::logger::LogRecord;
 use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
-use radix_engine::transaction::execute_and_commit_transaction;
+use radix_engine::transaction::execute_transaction;
+use radix_engine::transaction::JsonLinesLogSink;
 use radix_engine::transaction::TransactionOutcome;
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::transaction::TransactionReceiptDisplayContextBuilder;
@@ -79,6 +87,7 @@ use std::path::PathBuf;
 use transaction::builder::ManifestBuilder;
 use transaction::ecdsa_secp256k1::EcdsaSecp256k1PrivateKey;
 use transaction::manifest::decompile;
+use transaction::model::Executable;
 use transaction::model::Instruction;
 use transaction::model::SystemTransaction;
 use transaction::model::TestTransaction;
@@ -103,6 +112,7 @@ impl ResimCli {
 pub enum Command {
     CallFunction(CallFunction),
     CallMethod(CallMethod),
+    EstimateFee(EstimateFee),
     ExportSchema(ExportSchema),
     GenerateKeyPair(GenerateKeyPair),
     Mint(crate::resim::cmd_mint::Mint),
@@ -119,6 +129,7 @@ pub enum Command {
     SetCurrentTime(SetCurrentTime),
     SetDefaultAccount(SetDefaultAccount),
     ShowConfigs(ShowConfigs),
+    ShowEvents(ShowEvents),
     ShowLedger(ShowLedger),
     Show(Show),
     Transfer(Transfer),
@@ -132,6 +143,7 @@ pub fn run() -> Result<(), Error> {
     match cli.command {
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
+        Command::EstimateFee(cmd) => cmd.run(&mut out),
         Command::ExportSchema(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
@@ -148,6 +160,7 @@ pub fn run() -> Result<(), Error> {
         Command::SetCurrentTime(cmd) => cmd.run(&mut out),
         Command::SetDefaultAccount(cmd) => cmd.run(&mut out),
         Command::ShowConfigs(cmd) => cmd.run(&mut out),
+        Command::ShowEvents(cmd) => cmd.run(&mut out),
         Command::ShowLedger(cmd) => cmd.run(&mut out),
         Command::Show(cmd) => cmd.run(&mut out),
         Command::Transfer(cmd) => cmd.run(&mut out),
@@ -163,7 +176,8 @@ pub fn handle_system_transaction<O: std::io::Write>(
     out: &mut O,
 ) -> Result<TransactionReceipt, Error> {
     let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
-    let mut substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
+    let mut substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter)
+        .map_err(Error::LedgerOpenError)?;
 
     let nonce = get_nonce()?;
     let transaction = SystemTransaction {
@@ -173,13 +187,12 @@ pub fn handle_system_transaction<O: std::io::Write>(
         pre_allocated_ids: BTreeSet::new(),
     };
 
-    let receipt = execute_and_commit_transaction(
+    let receipt = execute_and_commit_to_ledger(
         &mut substate_store,
         &scrypto_interpreter,
-        &FeeReserveConfig::default(),
         &ExecutionConfig::standard().with_trace(trace),
         &transaction.get_executable(initial_proofs),
-    );
+    )?;
 
     if print_receipt {
         let encoder = Bech32Encoder::for_simulator();
@@ -230,7 +243,8 @@ pub fn handle_manifest<O: std::io::Write>(
         None => {
             let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
             let mut substate_store =
-                RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
+                RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter)
+                    .map_err(Error::LedgerOpenError)?;
 
             let sks = get_signing_keys(signing_keys)?;
             let initial_proofs = sks
@@ -240,13 +254,12 @@ pub fn handle_manifest<O: std::io::Write>(
             let nonce = get_nonce()?;
             let transaction = TestTransaction::new(manifest, nonce, DEFAULT_COST_UNIT_LIMIT);
 
-            let receipt = execute_and_commit_transaction(
+            let receipt = execute_and_commit_to_ledger(
                 &mut substate_store,
                 &scrypto_interpreter,
-                &FeeReserveConfig::default(),
                 &ExecutionConfig::standard().with_trace(trace),
                 &transaction.get_executable(initial_proofs),
-            );
+            )?;
 
             if print_receipt {
                 let encoder = Bech32Encoder::for_simulator();
@@ -265,6 +278,47 @@ pub fn handle_manifest<O: std::io::Write>(
     }
 }
 
+/// Executes the given transaction and, if it is committable, atomically commits its state
+/// updates and appends it to the ledger's commit log (see `CommitLogStore`).
+/// Returns an error if the application logs of the committed transaction could not be appended
+/// to the log file (if configured).
+fn execute_and_commit_to_ledger<W: WasmEngine>(
+    substate_store: &mut RadixEngineDB,
+    scrypto_interpreter: &ScryptoInterpreter<W>,
+    execution_config: &ExecutionConfig,
+    executable: &Executable,
+) -> Result<TransactionReceipt, Error> {
+    let receipt = execute_transaction(
+        substate_store,
+        scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        execution_config,
+        executable,
+    );
+    if let TransactionResult::Commit(commit) = &receipt.result {
+        substate_store.commit_and_log(*executable.transaction_hash(), commit);
+        if let Ok(log_file) = env::var(ENV_LOG_FILE) {
+            append_logs(
+                &log_file,
+                executable.transaction_hash(),
+                &commit.application_logs,
+            )
+            .map_err(Error::IOError)?;
+        }
+    }
+    Ok(receipt)
+}
+
+/// Appends the given application logs to the file, as JSON lines.
+fn append_logs(log_file: &str, transaction_hash: &Hash, logs: &[LogRecord]) -> std::io::Result<()> {
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_file)?;
+    JsonLinesLogSink::with_encoder(file, Bech32Encoder::for_simulator())
+        .write_logs(transaction_hash, logs)
+}
+
 pub fn process_receipt(receipt: TransactionReceipt) -> Result<TransactionReceipt, Error> {
     match &receipt.result {
         TransactionResult::Commit(commit) => {
@@ -309,7 +363,8 @@ pub fn get_signing_keys(
 
 pub fn export_package_schema(package_address: PackageAddress) -> Result<PackageSchema, Error> {
     let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
-    let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
+    let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter)
+        .map_err(Error::LedgerOpenError)?;
 
     let output = substate_store
         .get_substate(&SubstateId(
@@ -342,7 +397,8 @@ pub fn get_blueprint(
     component_address: ComponentAddress,
 ) -> Result<(PackageAddress, String), Error> {
     let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
-    let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter);
+    let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?, &scrypto_interpreter)
+        .map_err(Error::LedgerOpenError)?;
 
     let output = substate_store
         .get_substate(&SubstateId(
@@ -411,7 +467,7 @@ pub fn get_event_schema<S: ReadableSubstateStore>(
         }
         EventTypeIdentifier(Emitter::Function(node_id, _, blueprint_name), local_type_index) => {
             let RENodeId::GlobalObject(Address::Package(package_address)) = node_id else {
-                return None
+                return None;
             };
             (
                 *package_address,
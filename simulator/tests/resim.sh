@@ -59,6 +59,13 @@ $resim call-method $account2 deposit "$non_fungible_global_id"
 $resim mint 777 $token_address --proofs $minter_badge:1
 $resim transfer 111 $token_address $account2
 
+# Test - show the events of an account
+account2_events=`$resim show-events --component $account2`
+if [[ ${account2_events} != *"DepositEvent"* ]];then
+    echo "Deposit event not present!"
+    exit 1
+fi
+
 # Test - publish, call-function and call-method and non-fungibles
 owner_badge=`$resim new-simple-badge --name 'OwnerBadge' | awk '/NonFungibleGlobalId:/ {print $NF}'`
 package=`$resim publish ../examples/hello-world --owner-badge $owner_badge | awk '/Package:/ {print $NF}'`
@@ -84,6 +91,7 @@ files=`ls target/*.blob`
 blobs=`echo $files | sed 's/ / --blobs /g'`
 $resim run ./target/temp2.rtm --blobs $blobs
 $resim new-account --manifest ./target/temp3.rtm
+$resim estimate-fee ./target/temp3.rtm --safety-margin 50
 $resim run ./target/temp3.rtm
 
 # Test - run manifest with a given set of signing keys
@@ -0,0 +1,13 @@
+mod private_key;
+mod signature;
+
+pub use private_key::*;
+pub use signature::*;
+
+/// The domain separation tag of BLS12-381 signatures, which follow the proof-of-possession scheme
+/// with public keys on G1 and signatures on G2.
+pub const BLS12381_CIPHERSUITE: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// The domain separation tag of BLS12-381 proofs of possession (i.e. signatures of the signer's
+/// own public key), which are required from every key taking part in an aggregate verification.
+pub const BLS12381_POP_CIPHERSUITE: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
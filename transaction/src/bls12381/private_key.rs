@@ -0,0 +1,145 @@
+use super::{Bls12381G2Signature, BLS12381_CIPHERSUITE, BLS12381_POP_CIPHERSUITE};
+use blst::min_pk::SecretKey;
+use radix_engine_interface::crypto::{Bls12381G1PublicKey, Hash};
+use sbor::rust::vec::Vec;
+
+pub struct Bls12381PrivateKey(SecretKey);
+
+impl Bls12381PrivateKey {
+    pub const LENGTH: usize = 32;
+
+    pub fn public_key(&self) -> Bls12381G1PublicKey {
+        Bls12381G1PublicKey(self.0.sk_to_pk().to_bytes())
+    }
+
+    pub fn sign(&self, msg_hash: &Hash) -> Bls12381G2Signature {
+        Bls12381G2Signature(
+            self.0
+                .sign(&msg_hash.0, BLS12381_CIPHERSUITE, &[])
+                .to_bytes(),
+        )
+    }
+
+    /// Proves the possession of this key, by signing its public key.
+    pub fn prove_possession(&self) -> Bls12381G2Signature {
+        Bls12381G2Signature(
+            self.0
+                .sign(&self.public_key().0, BLS12381_POP_CIPHERSUITE, &[])
+                .to_bytes(),
+        )
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().to_vec()
+    }
+
+    pub fn from_bytes(slice: &[u8]) -> Result<Self, ()> {
+        if slice.len() != Bls12381PrivateKey::LENGTH {
+            return Err(());
+        }
+        Ok(Self(SecretKey::from_bytes(slice).map_err(|_| ())?))
+    }
+
+    pub fn from_u64(n: u64) -> Result<Self, ()> {
+        let mut bytes = [0u8; Bls12381PrivateKey::LENGTH];
+        (&mut bytes[Bls12381PrivateKey::LENGTH - 8..Bls12381PrivateKey::LENGTH])
+            .copy_from_slice(&n.to_be_bytes());
+
+        Ok(Self(SecretKey::from_bytes(&bytes).map_err(|_| ())?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::{
+        aggregate_verify_bls12381, verify_bls12381, verify_bls12381_proof_of_possession,
+    };
+    use radix_engine_interface::crypto::hash;
+
+    #[test]
+    fn sign_and_verify() {
+        let sk = Bls12381PrivateKey::from_u64(1).unwrap();
+        let pk = sk.public_key();
+        let test_message_hash = hash("Test");
+        let sig = sk.sign(&test_message_hash);
+
+        assert_eq!(
+            Bls12381PrivateKey::from_bytes(&sk.to_bytes())
+                .unwrap()
+                .public_key(),
+            pk
+        );
+        assert!(verify_bls12381(&test_message_hash, &pk, &sig));
+        assert!(!verify_bls12381(&hash("Other"), &pk, &sig));
+    }
+
+    #[test]
+    fn aggregate_sign_and_verify() {
+        let sks: Vec<Bls12381PrivateKey> = (1..4)
+            .map(|n| Bls12381PrivateKey::from_u64(n).unwrap())
+            .collect();
+        let pks: Vec<(Bls12381G1PublicKey, Bls12381G2Signature)> = sks
+            .iter()
+            .map(|sk| (sk.public_key(), sk.prove_possession()))
+            .collect();
+        let test_message_hash = hash("Test");
+        let sigs: Vec<Bls12381G2Signature> =
+            sks.iter().map(|sk| sk.sign(&test_message_hash)).collect();
+        let agg_sig = Bls12381G2Signature::aggregate(&sigs).unwrap();
+
+        assert!(aggregate_verify_bls12381(
+            &test_message_hash,
+            &pks,
+            &agg_sig
+        ));
+        assert!(!aggregate_verify_bls12381(
+            &test_message_hash,
+            &pks[..2],
+            &agg_sig
+        ));
+        assert!(!aggregate_verify_bls12381(
+            &test_message_hash,
+            &[],
+            &agg_sig
+        ));
+        assert_eq!(Bls12381G2Signature::aggregate(&[]), None);
+    }
+
+    #[test]
+    fn prove_possession_and_verify() {
+        let sk = Bls12381PrivateKey::from_u64(1).unwrap();
+        let other_sk = Bls12381PrivateKey::from_u64(2).unwrap();
+
+        assert!(verify_bls12381_proof_of_possession(
+            &sk.public_key(),
+            &sk.prove_possession()
+        ));
+        assert!(!verify_bls12381_proof_of_possession(
+            &sk.public_key(),
+            &other_sk.prove_possession()
+        ));
+    }
+
+    #[test]
+    fn aggregate_verify_requires_proofs_of_possession() {
+        let sks: Vec<Bls12381PrivateKey> = (1..4)
+            .map(|n| Bls12381PrivateKey::from_u64(n).unwrap())
+            .collect();
+        let test_message_hash = hash("Test");
+        let sigs: Vec<Bls12381G2Signature> =
+            sks.iter().map(|sk| sk.sign(&test_message_hash)).collect();
+        let agg_sig = Bls12381G2Signature::aggregate(&sigs).unwrap();
+        let mut pks: Vec<(Bls12381G1PublicKey, Bls12381G2Signature)> = sks
+            .iter()
+            .map(|sk| (sk.public_key(), sk.prove_possession()))
+            .collect();
+        pks[2].1 = sks[0].prove_possession();
+
+        assert!(!aggregate_verify_bls12381(
+            &test_message_hash,
+            &pks,
+            &agg_sig
+        ));
+    }
+}
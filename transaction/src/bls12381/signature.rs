@@ -0,0 +1,95 @@
+use sbor::rust::borrow::ToOwned;
+use sbor::rust::fmt;
+use sbor::rust::str::FromStr;
+use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
+use sbor::*;
+use utils::copy_u8_array;
+
+/// Represents a BLS12-381 signature, a compressed point on the G2 curve.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Sbor)]
+pub struct Bls12381G2Signature(
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))] pub [u8; Self::LENGTH],
+);
+
+impl Bls12381G2Signature {
+    pub const LENGTH: usize = 96;
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+
+    /// Aggregates the given signatures into one, or returns `None` if there are none or any of
+    /// them is malformed.
+    pub fn aggregate(signatures: &[Bls12381G2Signature]) -> Option<Self> {
+        let signatures = signatures
+            .iter()
+            .map(|signature| blst::min_pk::Signature::from_bytes(&signature.0))
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        let signatures: Vec<&blst::min_pk::Signature> = signatures.iter().collect();
+        let aggregate = blst::min_pk::AggregateSignature::aggregate(&signatures, true).ok()?;
+
+        Some(Bls12381G2Signature(aggregate.to_signature().to_bytes()))
+    }
+}
+
+impl TryFrom<&[u8]> for Bls12381G2Signature {
+    type Error = ParseBls12381G2SignatureError;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        if slice.len() != Bls12381G2Signature::LENGTH {
+            return Err(ParseBls12381G2SignatureError::InvalidLength(slice.len()));
+        }
+
+        Ok(Bls12381G2Signature(copy_u8_array(slice)))
+    }
+}
+
+//======
+// error
+//======
+
+/// Represents an error when parsing BLS12-381 signature from hex.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBls12381G2SignatureError {
+    InvalidHex(String),
+    InvalidLength(usize),
+}
+
+#[cfg(not(feature = "alloc"))]
+impl std::error::Error for ParseBls12381G2SignatureError {}
+
+#[cfg(not(feature = "alloc"))]
+impl fmt::Display for ParseBls12381G2SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+//======
+// text
+//======
+
+impl FromStr for Bls12381G2Signature {
+    type Err = ParseBls12381G2SignatureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes =
+            hex::decode(s).map_err(|_| ParseBls12381G2SignatureError::InvalidHex(s.to_owned()))?;
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl fmt::Display for Bls12381G2Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", hex::encode(self.to_vec()))
+    }
+}
+
+impl fmt::Debug for Bls12381G2Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self)
+    }
+}
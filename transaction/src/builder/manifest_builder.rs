@@ -12,10 +12,13 @@ use radix_engine_interface::blueprints::epoch_manager::{
 use radix_engine_interface::blueprints::identity::{
     IdentityCreateInput, IDENTITY_BLUEPRINT, IDENTITY_CREATE_IDENT,
 };
+use radix_engine_interface::blueprints::package::{
+    PACKAGE_BLUEPRINT, PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT,
+};
 use radix_engine_interface::blueprints::resource::ResourceMethodAuthKey::{Burn, Mint};
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::constants::{
-    ACCESS_CONTROLLER_PACKAGE, ACCOUNT_PACKAGE, EPOCH_MANAGER, IDENTITY_PACKAGE,
+    ACCESS_CONTROLLER_PACKAGE, ACCOUNT_PACKAGE, EPOCH_MANAGER, IDENTITY_PACKAGE, PACKAGE_PACKAGE,
     RESOURCE_MANAGER_PACKAGE,
 };
 use radix_engine_interface::crypto::{hash, EcdsaSecp256k1PublicKey, Hash};
@@ -601,6 +604,57 @@ impl ManifestBuilder {
         self
     }
 
+    /// Publishes a package with an owner badge, who can later upgrade its code.
+    pub fn publish_upgradeable_package_with_owner(
+        &mut self,
+        code: Vec<u8>,
+        schema: PackageSchema,
+        owner_badge: NonFungibleGlobalId,
+    ) -> &mut Self {
+        let code_hash = hash(&code);
+        self.blobs.insert(code_hash, code);
+
+        self.add_instruction(Instruction::CallFunction {
+            package_address: PACKAGE_PACKAGE,
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT.to_string(),
+            args: manifest_args!(
+                None::<[u8; 26]>,
+                ManifestBlobRef(code_hash.0),
+                schema,
+                BTreeMap::<String, RoyaltyConfig>::new(),
+                BTreeMap::<String, String>::new(),
+                package_access_rules_from_owner_badge(&owner_badge)
+            ),
+        });
+        self
+    }
+
+    /// Upgrades the code of an upgradeable package, with the functions of the new code which
+    /// migrate the state of existing components, per blueprint.
+    pub fn upgrade_package(
+        &mut self,
+        package_address: PackageAddress,
+        code: Vec<u8>,
+        schema: PackageSchema,
+        migrations: BTreeMap<String, String>,
+    ) -> &mut Self {
+        let code_hash = hash(&code);
+        self.blobs.insert(code_hash, code);
+
+        let schema = scrypto_encode(&schema).unwrap();
+        let schema_hash = hash(&schema);
+        self.blobs.insert(schema_hash, schema);
+
+        self.add_instruction(Instruction::UpgradePackage {
+            package_address,
+            code: ManifestBlobRef(code_hash.0),
+            schema: ManifestBlobRef(schema_hash.0),
+            migrations,
+        });
+        self
+    }
+
     /// Builds a transaction manifest.
     /// TODO: consider using self
     pub fn build(&self) -> TransactionManifest {
@@ -6,7 +6,9 @@ use radix_engine_interface::data::manifest::manifest_encode;
 pub struct TransactionBuilder {
     manifest: Option<TransactionManifest>,
     header: Option<TransactionHeader>,
+    fee_sponsor: Option<FeeSponsor>,
     intent_signatures: Vec<SignatureWithPublicKey>,
+    sponsor_signature: Option<SignatureWithPublicKey>,
     notary_signature: Option<Signature>,
 }
 
@@ -15,7 +17,9 @@ impl TransactionBuilder {
         Self {
             manifest: None,
             header: None,
+            fee_sponsor: None,
             intent_signatures: vec![],
+            sponsor_signature: None,
             notary_signature: None,
         }
     }
@@ -30,6 +34,11 @@ impl TransactionBuilder {
         self
     }
 
+    pub fn fee_sponsor(mut self, fee_sponsor: FeeSponsor) -> Self {
+        self.fee_sponsor = Some(fee_sponsor);
+        self
+    }
+
     pub fn sign<S: Signer>(mut self, signer: &S) -> Self {
         let intent = self.transaction_intent();
         let intent_payload = manifest_encode(&intent).unwrap();
@@ -44,6 +53,14 @@ impl TransactionBuilder {
         self
     }
 
+    pub fn sponsor_sign<S: Signer>(mut self, signer: &S) -> Self {
+        let intent = self.transaction_intent();
+        let intent_payload = manifest_encode(&intent).unwrap();
+        let intent_payload_hash = hash(intent_payload);
+        self.sponsor_signature = Some(signer.sign(&intent_payload_hash));
+        self
+    }
+
     pub fn notarize<S: Signer>(mut self, signer: &S) -> Self {
         let signed_intent = self.signed_transaction_intent();
         let signed_intent_payload = manifest_encode(&signed_intent).unwrap();
@@ -68,6 +85,7 @@ impl TransactionBuilder {
         TransactionIntent {
             manifest: self.manifest.clone().expect("Manifest not specified"),
             header: self.header.clone().expect("Header not specified"),
+            fee_sponsor: self.fee_sponsor.clone(),
         }
     }
 
@@ -76,6 +94,7 @@ impl TransactionBuilder {
         SignedTransactionIntent {
             intent,
             intent_signatures: self.intent_signatures.clone(),
+            sponsor_signature: self.sponsor_signature.clone(),
         }
     }
 }
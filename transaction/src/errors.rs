@@ -9,6 +9,7 @@ pub enum HeaderValidationError {
     InvalidNetwork,
     InvalidCostUnitLimit,
     InvalidTipBps,
+    InvalidSponsorMaxFee,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -16,6 +17,9 @@ pub enum SignatureValidationError {
     TooManySignatures,
     InvalidIntentSignature,
     InvalidNotarySignature,
+    MissingSponsorSignature,
+    UnexpectedSponsorSignature,
+    InvalidSponsorSignature,
     DuplicateSigner,
     SerializationError(EncodeError),
 }
@@ -1,3 +1,4 @@
+pub mod bls12381;
 pub mod builder;
 pub mod data;
 pub mod ecdsa_secp256k1;
@@ -103,6 +103,13 @@ pub enum Instruction {
         access_rules: Value,
     },
 
+    UpgradePackage {
+        package_address: Value,
+        code: Value,
+        schema: Value,
+        migrations: Value,
+    },
+
     BurnResource {
         bucket: Value,
     },
@@ -441,6 +441,19 @@ pub fn decompile_instruction<F: fmt::Write>(
             format_typed_value(f, context, access_rules)?;
             f.write_str(";")?;
         }
+        Instruction::UpgradePackage {
+            package_address,
+            code,
+            schema,
+            migrations,
+        } => {
+            f.write_str("UPGRADE_PACKAGE")?;
+            format_typed_value(f, context, package_address)?;
+            format_typed_value(f, context, code)?;
+            format_typed_value(f, context, schema)?;
+            format_typed_value(f, context, migrations)?;
+            f.write_str(";")?;
+        }
         Instruction::BurnResource { bucket_id } => {
             write!(
                 f,
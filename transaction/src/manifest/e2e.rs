@@ -38,6 +38,32 @@ PUBLISH_PACKAGE
         );
     }
 
+    #[test]
+    fn test_upgrade_package() {
+        compile_and_decompile_with_inversion_test(
+            "upgrade_package",
+            &apply_replacements_to_manifest(
+                include_str!("../../examples/package/upgrade.rtm").to_string(),
+            ),
+            &NetworkDefinition::simulator(),
+            vec![
+                include_bytes!("../../examples/package/code.blob").to_vec(),
+                include_bytes!("../../examples/package/schema.blob").to_vec(),
+            ],
+            r##"
+CALL_METHOD
+    Address("account_sim1qjy5fakwygc45fkyhyxxulsf5zfae0ycez0x05et9hqs7d0gtn")
+    "lock_fee"
+    Decimal("10");
+UPGRADE_PACKAGE
+    Address("package_sim1qr46xrzzzlgvqccwqptp9ujlqncamd6kexux05essnuqc933em")
+    Blob("a710f0959d8e139b3c1ca74ac4fcb9a95ada2c82e7f563304c5487e0117095c0")
+    Blob("554d6e3a49e90d3be279e7ff394a01d9603cc13aa701c11c1f291f6264aa5791")
+    Map<String, String>("Blueprint", "migrate");
+"##,
+        );
+    }
+
     #[test]
     fn test_resource_worktop() {
         compile_and_decompile_with_inversion_test(
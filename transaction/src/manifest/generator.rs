@@ -412,6 +412,17 @@ pub fn generate_instruction(
             metadata: generate_typed_value(metadata, resolver, bech32_decoder, blobs)?,
             access_rules: generate_typed_value(access_rules, resolver, bech32_decoder, blobs)?,
         },
+        ast::Instruction::UpgradePackage {
+            package_address,
+            code,
+            schema,
+            migrations,
+        } => Instruction::UpgradePackage {
+            package_address: generate_package_address(package_address, bech32_decoder)?,
+            code: generate_blob(code, blobs)?,
+            schema: generate_blob(schema, blobs)?,
+            migrations: generate_typed_value(migrations, resolver, bech32_decoder, blobs)?,
+        },
         ast::Instruction::BurnResource { bucket } => {
             let bucket_id = generate_bucket(bucket, resolver)?;
             id_validator
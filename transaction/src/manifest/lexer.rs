@@ -100,6 +100,7 @@ pub enum TokenKind {
     CallFunction,
     CallMethod,
     PublishPackage,
+    UpgradePackage,
     BurnResource,
     RecallResource,
     SetMetadata,
@@ -434,6 +435,7 @@ impl Lexer {
             "CALL_FUNCTION" => Ok(TokenKind::CallFunction),
             "CALL_METHOD" => Ok(TokenKind::CallMethod),
             "PUBLISH_PACKAGE" => Ok(TokenKind::PublishPackage),
+            "UPGRADE_PACKAGE" => Ok(TokenKind::UpgradePackage),
             "BURN_RESOURCE" => Ok(TokenKind::BurnResource),
             "RECALL_RESOURCE" => Ok(TokenKind::RecallResource),
             "SET_METADATA" => Ok(TokenKind::SetMetadata),
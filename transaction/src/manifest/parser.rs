@@ -169,6 +169,12 @@ impl Parser {
                 metadata: self.parse_value()?,
                 access_rules: self.parse_value()?,
             },
+            TokenKind::UpgradePackage => Instruction::UpgradePackage {
+                package_address: self.parse_value()?,
+                code: self.parse_value()?,
+                schema: self.parse_value()?,
+                migrations: self.parse_value()?,
+            },
             TokenKind::BurnResource => Instruction::BurnResource {
                 bucket: self.parse_value()?,
             },
@@ -1,8 +1,9 @@
-use radix_engine_common::data::scrypto::model::ResourceAddress;
+use radix_engine_common::data::scrypto::model::{ComponentAddress, ResourceAddress};
 use radix_engine_interface::api::types::*;
 use radix_engine_interface::blueprints::resource::NonFungibleGlobalId;
 use radix_engine_interface::blueprints::transaction_processor::RuntimeValidationRequest;
 use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::math::Decimal;
 use radix_engine_interface::*;
 use sbor::rust::collections::BTreeSet;
 use sbor::rust::vec::Vec;
@@ -22,6 +23,7 @@ pub struct ExecutionContext {
     pub payload_size: usize,
     pub auth_zone_params: AuthZoneParams,
     pub fee_payment: FeePayment,
+    pub fee_sponsor: Option<FeeSponsorship>,
     pub runtime_validations: Vec<RuntimeValidationRequest>,
 }
 
@@ -34,6 +36,16 @@ pub enum FeePayment {
     NoFee,
 }
 
+/// A verified fee sponsor (see `FeeSponsor`).
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct FeeSponsorship {
+    pub account: ComponentAddress,
+    pub max_fee: Decimal,
+    /// The proofs of the sponsor's signature, which are only used for locking the fee (and are
+    /// not available to the manifest)
+    pub initial_proofs: Vec<NonFungibleGlobalId>,
+}
+
 #[derive(Debug)]
 pub struct Executable<'a> {
     instructions: Vec<Instruction>,
@@ -70,6 +82,10 @@ impl<'a> Executable<'a> {
         &self.context.fee_payment
     }
 
+    pub fn fee_sponsor(&self) -> Option<&FeeSponsorship> {
+        self.context.fee_sponsor.as_ref()
+    }
+
     pub fn instructions(&self) -> &[Instruction] {
         &self.instructions
     }
@@ -108,6 +108,14 @@ pub enum Instruction {
         access_rules: AccessRulesConfig,
     },
 
+    /// Upgrade the code of an upgradeable package.
+    UpgradePackage {
+        package_address: PackageAddress,
+        code: ManifestBlobRef,
+        schema: ManifestBlobRef,
+        migrations: BTreeMap<String, String>,
+    },
+
     BurnResource {
         bucket_id: ManifestBucket,
     },
@@ -1,9 +1,12 @@
+use crate::bls12381::Bls12381G2Signature;
 use crate::ecdsa_secp256k1::EcdsaSecp256k1Signature;
 use crate::eddsa_ed25519::EddsaEd25519Signature;
 use crate::manifest::{compile, CompileError};
 use crate::model::TransactionManifest;
 use radix_engine_interface::crypto::*;
 use radix_engine_interface::data::manifest::*;
+use radix_engine_interface::data::scrypto::model::ComponentAddress;
+use radix_engine_interface::math::Decimal;
 use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_interface::*;
 use sbor::*;
@@ -24,16 +27,31 @@ pub struct TransactionHeader {
     pub tip_percentage: u16,
 }
 
+/// A third party paying for the fees of a transaction (up to a limit), so that the signers of the
+/// intent don't need to hold any XRD.
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct FeeSponsor {
+    /// The account to lock the fee from, before the manifest runs
+    pub account: ComponentAddress,
+    /// The key authorizing the fee payment, by signing the intent hash
+    pub public_key: PublicKey,
+    /// The amount of XRD locked from the account (any unused fee is refunded)
+    pub max_fee: Decimal,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
 pub struct TransactionIntent {
     pub header: TransactionHeader,
     pub manifest: TransactionManifest,
+    pub fee_sponsor: Option<FeeSponsor>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
 pub struct SignedTransactionIntent {
     pub intent: TransactionIntent,
     pub intent_signatures: Vec<SignatureWithPublicKey>,
+    /// The signature of the intent hash by the fee sponsor (if any)
+    pub sponsor_signature: Option<SignatureWithPublicKey>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
@@ -52,6 +70,7 @@ pub struct NotarizedTransaction {
 pub enum Signature {
     EcdsaSecp256k1(EcdsaSecp256k1Signature),
     EddsaEd25519(EddsaEd25519Signature),
+    Bls12381(Bls12381G2Signature),
 }
 
 /// Represents any natively supported signature, including public key.
@@ -69,6 +88,10 @@ pub enum SignatureWithPublicKey {
         public_key: EddsaEd25519PublicKey,
         signature: EddsaEd25519Signature,
     },
+    Bls12381 {
+        public_key: Bls12381G1PublicKey,
+        signature: Bls12381G2Signature,
+    },
 }
 
 impl SignatureWithPublicKey {
@@ -76,6 +99,7 @@ impl SignatureWithPublicKey {
         match &self {
             SignatureWithPublicKey::EcdsaSecp256k1 { signature } => signature.clone().into(),
             SignatureWithPublicKey::EddsaEd25519 { signature, .. } => signature.clone().into(),
+            SignatureWithPublicKey::Bls12381 { signature, .. } => signature.clone().into(),
         }
     }
 }
@@ -92,6 +116,12 @@ impl From<EddsaEd25519Signature> for Signature {
     }
 }
 
+impl From<Bls12381G2Signature> for Signature {
+    fn from(signature: Bls12381G2Signature) -> Self {
+        Self::Bls12381(signature)
+    }
+}
+
 impl From<EcdsaSecp256k1Signature> for SignatureWithPublicKey {
     fn from(signature: EcdsaSecp256k1Signature) -> Self {
         Self::EcdsaSecp256k1 { signature }
@@ -107,6 +137,15 @@ impl From<(EddsaEd25519PublicKey, EddsaEd25519Signature)> for SignatureWithPubli
     }
 }
 
+impl From<(Bls12381G1PublicKey, Bls12381G2Signature)> for SignatureWithPublicKey {
+    fn from((public_key, signature): (Bls12381G1PublicKey, Bls12381G2Signature)) -> Self {
+        Self::Bls12381 {
+            public_key,
+            signature,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum IntentCreationError {
     CompileErr(CompileError),
@@ -142,6 +181,7 @@ impl TransactionIntent {
         Ok(Self {
             header,
             manifest: compile(manifest, &network, blobs)?,
+            fee_sponsor: None,
         })
     }
 
@@ -225,6 +265,7 @@ mod tests {
         let signed_intent = SignedTransactionIntent {
             intent,
             intent_signatures: vec![signature1.into(), signature2.into()],
+            sponsor_signature: None,
         };
 
         let signed_intent_hash = signed_intent.hash().unwrap();
@@ -238,17 +279,17 @@ mod tests {
 
         assert_eq!(
             transaction.signed_intent.intent.hash().unwrap().to_string(),
-            "81b06974e2e359ed9ceb16ebbcaae7d256f19d6b13bbef6d44698c203e3d14e7"
+            "bd88e66f565045cdb6454484e289a16badd530d822aa45755e111a1de2e19059"
         );
         assert_eq!(
             transaction.signed_intent.hash().unwrap().to_string(),
-            "b00287b46fc52651e5db2913b8886051d4ca6fd851852697fe4e43fd85608737"
+            "2d3159a32465840d7adc57d450e9417de985345f20c81434610015c73d574f31"
         );
         assert_eq!(
             transaction.hash().unwrap().to_string(),
-            "d5a09086b67552347a0d34a1729e87a8b75e992be95f1c5817e51484b208c3d0",
+            "a965b66cb5d79174baf49fabb09c62b2bd7734600a0dc63f402340c48aca7de5",
         );
-        assert_eq!(hex::encode(manifest_encode(&transaction).unwrap()), "4d2102210221022109070107f20a00000000000000000a64000000000000000a050000000000000022000120072102f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f901000940420f0008050021022022010900202000202202000121012007410080f917243985f64db4fe011a1758c223ed6866400853fede94bc6cef35ca98bb2bca3164e6b4b6f08fdf7d39e067b9c5b3eeb5fdff66e4e095c46baeba12061c000121012007410157551ffc806d3abf40d0be5673b9102fb83bbee9101ee4e674a6ffe1f6d8e85d17f2a41f1092067dd9a2af36c1cb5a46fb904f41e4b8d9698f833c1d6f806dd2220001210120074100f4859a1bd63b649548b0e3dd5c0c52e1446ddec18a40cab19acbd24c9e8bce1655f802e67e6ae8ad4cad34849b17c557d0f95ff660423564315da147ec7f37eb");
+        assert_eq!(hex::encode(manifest_encode(&transaction).unwrap()), "4d2102210321032109070107f20a00000000000000000a64000000000000000a050000000000000022000120072102f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f901000940420f000805002102202201090020200022000020220200012101200741014eeda89655ecabc493ff58855556587b3c7f47e46b0e1ca0fb2093491e45e51d21ac2d2508842707cb49e40acf33667f8f6957da1efe677e9a0113a8acc4b4330001210120074101551476a79069755c13fe7055a7932b8a50d86e8b8ea466d8b8b4be0d65be5cdb6e8bdf2c41f525c92bb2d3464a24bb026b2b5f8edc1b3958af76ab5b7a7ae58722000022000121012007410126cbce51891d55815396617f2512db8e2632c9ee3c836b9054cc5a202c2f7cb578cad85e3921a14b3b09f041bb295a4fc1e214eab5bad3db5206e76004cb5e25");
     }
 
     #[test]
@@ -285,6 +326,7 @@ mod tests {
         let signed_intent = SignedTransactionIntent {
             intent,
             intent_signatures: vec![signature1.into(), signature2.into()],
+            sponsor_signature: None,
         };
 
         // notarize
@@ -298,16 +340,16 @@ mod tests {
 
         assert_eq!(
             transaction.signed_intent.intent.hash().unwrap().to_string(),
-            "bc01cff3978ff43d881ad01f4a8be3ff21582de55a1631b3384d27b66dd28d8d"
+            "8fddf6028cbaa8a0c3f5e8295ce1fe5d476c054d763be2607e477b373b9475dc"
         );
         assert_eq!(
             transaction.signed_intent.hash().unwrap().to_string(),
-            "69076ac02d6958a1b07c49382b14e0e037951653774068fa424416138d206fda",
+            "c2384ec4e41bc90cb8b73783dafe3c46d8a856b5a502b5d5810465487341c4dc",
         );
         assert_eq!(
             transaction.hash().unwrap().to_string(),
-            "b4924b71bd03aa69e0f796ece997b7fbb9e501dd1298aeec57d802dcac8ef8d3"
+            "54fe7b2ff7beb71f1116c962e8d4279176e703f3a370ccf80907eb71a630676a"
         );
-        assert_eq!(  hex::encode(manifest_encode(&transaction).unwrap()), "4d2102210221022109070107f20a00000000000000000a64000000000000000a0500000000000000220101200720f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b01000940420f000805002102202201090020200020220201022007204cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba292101200740efca0f6a4e2a782eef27da5bce284e93ca3b993774b1866510a8888b7100162f9f30405ab6ded0cbe45262dc9b7c514d89d610e779e36659c53bcbf83182760501022007207422b9887598068e32c4448a949adb290d0f4e35b9e01b0ee5f1a1e600fe26742101200740de01a711a96684c9e705583680f44729cccab96f5dde370225003382e86e66660ea216d020d1b296ae8137ff072065e850c46fde287772139fabcebfd38cc10e2201012101200740622d0f7f90e7cc3d5550bcb763c3f52a14ee19e6dd9239673682b57c1cfb33e4cffce2b3a4b13d7a4ea987f214d539e2621bd5f9b0cca054d0581e06287d5b0e");
+        assert_eq!(  hex::encode(manifest_encode(&transaction).unwrap()), "4d2102210321032109070107f20a00000000000000000a64000000000000000a0500000000000000220101200720f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b01000940420f000805002102202201090020200022000020220201022007204cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba29210120074055352afdd1bd3a4864017d5805b2af594f75f8cb41d9d0601716a365036bca2b2eb66079dec5b8d6d6c0daa72f9b289795e93e074544d35b5c3d8f536479fd0601022007207422b9887598068e32c4448a949adb290d0f4e35b9e01b0ee5f1a1e600fe267421012007400bddcac6e45097ad6f467fb0552356b283e82b2b462bf84419c8a3971529b61191ebd0b478bac776233fee9e2ccd1b9b1820fde66692783dda8499544110d50f22000022010121012007403733f7e6c9cdfa4b776deaa87f5c5d9d502bbe614bd1f9d3071baa6790263cd2ec442982417dc5456fbf60e1125956592260f6b3108df43811b8f7a123170001");
     }
 }
@@ -35,6 +35,7 @@ impl SystemTransaction {
                 payload_size: 0,
                 auth_zone_params,
                 fee_payment: FeePayment::NoFee,
+                fee_sponsor: None,
                 runtime_validations: vec![],
                 pre_allocated_ids: self.pre_allocated_ids.clone(),
             },
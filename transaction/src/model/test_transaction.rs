@@ -44,6 +44,7 @@ impl TestTransaction {
                     cost_unit_limit: self.cost_unit_limit,
                     tip_percentage: 0,
                 },
+                fee_sponsor: None,
                 runtime_validations: vec![],
                 pre_allocated_ids: BTreeSet::new(),
             },
@@ -1,6 +1,6 @@
 use crate::{
-    ecdsa_secp256k1::EcdsaSecp256k1PrivateKey, eddsa_ed25519::EddsaEd25519PrivateKey,
-    model::SignatureWithPublicKey,
+    bls12381::Bls12381PrivateKey, ecdsa_secp256k1::EcdsaSecp256k1PrivateKey,
+    eddsa_ed25519::EddsaEd25519PrivateKey, model::SignatureWithPublicKey,
 };
 use radix_engine_interface::crypto::Hash;
 
@@ -19,3 +19,9 @@ impl Signer for EddsaEd25519PrivateKey {
         (self.public_key(), self.sign(&message_hash)).into()
     }
 }
+
+impl Signer for Bls12381PrivateKey {
+    fn sign(&self, message_hash: &Hash) -> SignatureWithPublicKey {
+        (self.public_key(), self.sign(&message_hash)).into()
+    }
+}
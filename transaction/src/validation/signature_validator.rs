@@ -1,10 +1,13 @@
 use radix_engine_interface::crypto::*;
 
 use crate::{
+    bls12381::{Bls12381G2Signature, BLS12381_CIPHERSUITE, BLS12381_POP_CIPHERSUITE},
     ecdsa_secp256k1::EcdsaSecp256k1Signature,
     eddsa_ed25519::EddsaEd25519Signature,
     model::{Signature, SignatureWithPublicKey},
 };
+use blst::BLST_ERROR;
+use sbor::rust::vec::Vec;
 
 pub fn recover(message: &[u8], signature: &SignatureWithPublicKey) -> Option<PublicKey> {
     let message_hash = hash(message);
@@ -14,6 +17,7 @@ pub fn recover(message: &[u8], signature: &SignatureWithPublicKey) -> Option<Pub
             recover_ecdsa_secp256k1(&message_hash, signature).map(Into::into)
         }
         SignatureWithPublicKey::EddsaEd25519 { public_key, .. } => Some(public_key.clone().into()),
+        SignatureWithPublicKey::Bls12381 { public_key, .. } => Some(public_key.clone().into()),
     }
 }
 
@@ -45,6 +49,9 @@ pub fn verify(message: &[u8], public_key: &PublicKey, signature: &Signature) ->
         (PublicKey::EddsaEd25519(pk), Signature::EddsaEd25519(sig)) => {
             verify_eddsa_ed25519(&message_hash, pk, sig)
         }
+        (PublicKey::Bls12381(pk), Signature::Bls12381(sig)) => {
+            verify_bls12381(&message_hash, pk, sig)
+        }
         _ => false,
     }
 }
@@ -82,3 +89,71 @@ pub fn verify_eddsa_ed25519(
 
     false
 }
+
+pub fn verify_bls12381(
+    message_hash: &Hash,
+    public_key: &Bls12381G1PublicKey,
+    signature: &Bls12381G2Signature,
+) -> bool {
+    if let Ok(sig) = blst::min_pk::Signature::from_bytes(&signature.0) {
+        if let Ok(pk) = blst::min_pk::PublicKey::from_bytes(&public_key.0) {
+            return sig.verify(true, &message_hash.0, BLS12381_CIPHERSUITE, &[], &pk, true)
+                == BLST_ERROR::BLST_SUCCESS;
+        }
+    }
+
+    false
+}
+
+/// Verifies a proof of possession of the given key (see `Bls12381PrivateKey::prove_possession()`).
+pub fn verify_bls12381_proof_of_possession(
+    public_key: &Bls12381G1PublicKey,
+    proof: &Bls12381G2Signature,
+) -> bool {
+    if let Ok(sig) = blst::min_pk::Signature::from_bytes(&proof.0) {
+        if let Ok(pk) = blst::min_pk::PublicKey::key_validate(&public_key.0) {
+            return sig.verify(
+                true,
+                &public_key.0,
+                BLS12381_POP_CIPHERSUITE,
+                &[],
+                &pk,
+                false,
+            ) == BLST_ERROR::BLST_SUCCESS;
+        }
+    }
+
+    false
+}
+
+/// Verifies an aggregate of the signatures of the same message hash by each of the given keys.
+///
+/// Every key comes with a proof of its possession, which is verified as well, since otherwise the
+/// aggregate could be forged with a rogue key (i.e. one derived from the other keys).
+pub fn aggregate_verify_bls12381(
+    message_hash: &Hash,
+    public_keys: &[(Bls12381G1PublicKey, Bls12381G2Signature)],
+    signature: &Bls12381G2Signature,
+) -> bool {
+    if public_keys.is_empty()
+        || !public_keys
+            .iter()
+            .all(|(public_key, proof)| verify_bls12381_proof_of_possession(public_key, proof))
+    {
+        return false;
+    }
+
+    if let Ok(sig) = blst::min_pk::Signature::from_bytes(&signature.0) {
+        if let Ok(pks) = public_keys
+            .iter()
+            .map(|(public_key, _)| blst::min_pk::PublicKey::key_validate(&public_key.0))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            let pks: Vec<&blst::min_pk::PublicKey> = pks.iter().collect();
+            return sig.fast_aggregate_verify(true, &message_hash.0, BLS12381_CIPHERSUITE, &pks)
+                == BLST_ERROR::BLST_SUCCESS;
+        }
+    }
+
+    false
+}
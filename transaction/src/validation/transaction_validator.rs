@@ -86,6 +86,15 @@ impl TransactionValidator<NotarizedTransaction> for NotarizedTransactionValidato
 
         let header = &intent.header;
 
+        let fee_sponsor = intent
+            .fee_sponsor
+            .as_ref()
+            .map(|fee_sponsor| FeeSponsorship {
+                account: fee_sponsor.account,
+                max_fee: fee_sponsor.max_fee,
+                initial_proofs: AuthAddresses::signer_set(&[fee_sponsor.public_key]),
+            });
+
         Ok(Executable::new(
             intent.manifest.instructions.clone(),
             &intent.manifest.blobs,
@@ -100,6 +109,7 @@ impl TransactionValidator<NotarizedTransaction> for NotarizedTransactionValidato
                     cost_unit_limit: header.cost_unit_limit,
                     tip_percentage: header.tip_percentage,
                 },
+                fee_sponsor,
                 runtime_validations: vec![
                     RuntimeValidation::IntentHashUniqueness { intent_hash }.enforced(),
                     RuntimeValidation::WithinEpochRange {
@@ -136,11 +146,22 @@ impl NotarizedTransactionValidator {
         if flags.assume_all_signature_proofs {
             virtual_resources.insert(ECDSA_SECP256K1_TOKEN);
             virtual_resources.insert(EDDSA_ED25519_TOKEN);
+            virtual_resources.insert(BLS12381_TOKEN);
         }
 
         let header = &intent.header;
         let manifest = &intent.manifest;
 
+        // The sponsor's signature is assumed, as for the signers'
+        let fee_sponsor = intent
+            .fee_sponsor
+            .as_ref()
+            .map(|fee_sponsor| FeeSponsorship {
+                account: fee_sponsor.account,
+                max_fee: fee_sponsor.max_fee,
+                initial_proofs: AuthAddresses::signer_set(&[fee_sponsor.public_key]),
+            });
+
         let fee_payment = if flags.unlimited_loan {
             FeePayment::NoFee
         } else {
@@ -161,6 +182,7 @@ impl NotarizedTransactionValidator {
                     virtual_resources,
                 },
                 fee_payment,
+                fee_sponsor,
                 runtime_validations: vec![
                     RuntimeValidation::IntentHashUniqueness { intent_hash }
                         .with_skipped_assertion_if(flags.permit_duplicate_intent_hash),
@@ -277,7 +299,7 @@ impl NotarizedTransactionValidator {
                     Self::validate_call_args(&args, &mut id_validator)
                         .map_err(TransactionValidationError::CallDataValidationError)?;
                 }
-                Instruction::PublishPackage { .. } => {}
+                Instruction::PublishPackage { .. } | Instruction::UpgradePackage { .. } => {}
                 Instruction::BurnResource { bucket_id } => {
                     id_validator
                         .drop_bucket(bucket_id)
@@ -336,6 +358,13 @@ impl NotarizedTransactionValidator {
             return Err(HeaderValidationError::InvalidTipBps);
         }
 
+        // fee sponsor
+        if let Some(fee_sponsor) = &intent.fee_sponsor {
+            if !fee_sponsor.max_fee.is_positive() {
+                return Err(HeaderValidationError::InvalidSponsorMaxFee);
+            }
+        }
+
         Ok(())
     }
 
@@ -364,6 +393,26 @@ impl NotarizedTransactionValidator {
             }
         }
 
+        // verify sponsor signature
+        match (
+            &transaction.signed_intent.intent.fee_sponsor,
+            &transaction.signed_intent.sponsor_signature,
+        ) {
+            (Some(fee_sponsor), Some(sig)) => {
+                let public_key = recover(&intent_payload, sig)
+                    .ok_or(SignatureValidationError::InvalidSponsorSignature)?;
+
+                if public_key != fee_sponsor.public_key
+                    || !verify(&intent_payload, &public_key, &sig.signature())
+                {
+                    return Err(SignatureValidationError::InvalidSponsorSignature);
+                }
+            }
+            (Some(_), None) => return Err(SignatureValidationError::MissingSponsorSignature),
+            (None, Some(_)) => return Err(SignatureValidationError::UnexpectedSponsorSignature),
+            (None, None) => {}
+        }
+
         if transaction.signed_intent.intent.header.notary_as_signatory {
             signers.insert(transaction.signed_intent.intent.header.notary_public_key);
         }